@@ -1,16 +1,20 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use kzg_bench::benches::lincomb::bench_g1_lincomb;
+use kzg_bench::benches::lincomb::{bench_g1_lincomb, bench_g1_lincomb_blob};
 use rust_kzg_arkworks::fft_g1::g1_linear_combination;
-use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine};
+use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG1ProjAddAffine};
 
 fn bench_g1_lincomb_(c: &mut Criterion) {
     bench_g1_lincomb::<ArkFr, ArkG1, ArkFp, ArkG1Affine>(c, &g1_linear_combination);
 }
 
+fn bench_g1_lincomb_blob_(c: &mut Criterion) {
+    bench_g1_lincomb_blob::<ArkFr, ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>(c);
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_g1_lincomb_
+    targets = bench_g1_lincomb_, bench_g1_lincomb_blob_
 }
 
 criterion_main!(benches);
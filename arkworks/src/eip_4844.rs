@@ -1,19 +1,22 @@
 extern crate alloc;
 
+use crate::fk20_proofs::KzgFK20MultiSettings;
 use crate::kzg_proofs::{FFTSettings, KZGSettings};
 use crate::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
+use crate::utils::PolyData;
 use blst::{blst_fr, blst_p1, blst_p2};
-use kzg::common_utils::reverse_bit_order;
+use kzg::backend::Backend;
+use kzg::c_api::{self, CKZGHandles};
 use kzg::eip_4844::{
-    blob_to_kzg_commitment_rust, compute_blob_kzg_proof_rust, compute_kzg_proof_rust,
-    load_trusted_setup_rust, verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_rust,
-    verify_kzg_proof_rust, Blob, Bytes32, Bytes48, CKZGSettings, KZGCommitment, KZGProof,
-    PrecomputationTableManager, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, C_KZG_RET,
-    C_KZG_RET_BADARGS, C_KZG_RET_OK, FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_NUM_G1_POINTS,
-    TRUSTED_SETUP_NUM_G2_POINTS,
+    kzg_to_versioned_hash_rust, Blob, Bytes32, Bytes48, CKZGSettings, KZGCommitment, KZGProof,
+    BYTES_PER_G1, BYTES_PER_G2, C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK,
+    FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_NUM_G2_POINTS,
 };
-use kzg::{cfg_into_iter, Fr, G1};
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 use std::ptr::null_mut;
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
 #[cfg(feature = "std")]
 use libc::FILE;
@@ -22,75 +25,90 @@ use std::fs::File;
 #[cfg(feature = "std")]
 use std::io::Read;
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
 #[cfg(feature = "std")]
 use kzg::eip_4844::load_trusted_setup_string;
+use kzg::eip_4844::load_trusted_setup_values_rust;
+#[cfg(feature = "std")]
+use kzg::msm::precompute::precompute_with_env_cache;
+use kzg::trusted_setup::load_trusted_setup_points;
+#[cfg(feature = "embedded-mainnet-setup")]
+use kzg::trusted_setup::MAINNET_TRUSTED_SETUP;
 
-static mut PRECOMPUTATION_TABLES: PrecomputationTableManager<ArkFr, ArkG1, ArkFp, ArkG1Affine> =
-    PrecomputationTableManager::new();
+static C_KZG_HANDLES: CKZGHandles<ArkBackend> = CKZGHandles::new();
 
 #[cfg(feature = "std")]
-pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<KZGSettings, String> {
-    let mut file = File::open(filepath).map_err(|_| "Unable to open file".to_string())?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .map_err(|_| "Unable to read file".to_string())?;
-
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents)?;
-    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice())
+pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<KZGSettings, Error> {
+    let mut file = File::open(filepath)
+        .map_err(|_| Error::InvalidTrustedSetup("Unable to open file".to_string()))?;
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents)
+        .map_err(|_| Error::InvalidTrustedSetup("Unable to read file".to_string()))?;
+
+    load_trusted_setup_contents_rust(&contents)
 }
 
-fn fft_settings_to_rust(c_settings: *const CKZGSettings) -> Result<FFTSettings, String> {
-    let settings = unsafe { &*c_settings };
-    let roots_of_unity = unsafe {
-        core::slice::from_raw_parts(settings.roots_of_unity, settings.max_width as usize)
-            .iter()
-            .map(|r| ArkFr::from_blst_fr(*r))
-            .collect::<Vec<ArkFr>>()
-    };
-    let mut expanded_roots_of_unity = roots_of_unity.clone();
-    reverse_bit_order(&mut expanded_roots_of_unity)?;
-    expanded_roots_of_unity.push(ArkFr::one());
-    let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
-    reverse_roots_of_unity.reverse();
-
-    let mut first_root = expanded_roots_of_unity[1];
-    let first_root_arr = [first_root; 1];
-    first_root = first_root_arr[0];
-
-    Ok(FFTSettings {
-        max_width: settings.max_width as usize,
-        root_of_unity: first_root,
-        expanded_roots_of_unity,
-        reverse_roots_of_unity,
-        roots_of_unity,
-    })
+/// Load mainnet trusted setup, which is embedded into the binary. Its precomputation table is built in memory,
+/// and never cached on disk.
+#[cfg(feature = "embedded-mainnet-setup")]
+pub fn load_mainnet_trusted_setup() -> Result<KZGSettings, Error> {
+    use kzg::KZGSettings as _;
+
+    let (g1_values, g2_values) =
+        load_trusted_setup_points::<ArkG1, ArkFp, ArkG2>(MAINNET_TRUSTED_SETUP)?;
+    let (secret_g1, secret_g2, max_scale, fs) =
+        load_trusted_setup_values_rust::<ArkFr, ArkG1, ArkG2, FFTSettings, ArkFp>(
+            g1_values, g2_values,
+        )?;
+    KZGSettings::new_from_lagrange(&secret_g1, &secret_g2, max_scale, &fs)
 }
 
-fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<KZGSettings, String> {
-    let secret_g1 = unsafe {
-        core::slice::from_raw_parts(c_settings.g1_values, TRUSTED_SETUP_NUM_G1_POINTS)
-            .iter()
-            .map(|r| ArkG1::from_blst_p1(*r))
-            .collect::<Vec<ArkG1>>()
-    };
-    let secret_g2 = unsafe {
-        core::slice::from_raw_parts(c_settings.g2_values, TRUSTED_SETUP_NUM_G2_POINTS)
-            .iter()
-            .map(|r| ArkG2::from_blst_p2(*r))
-            .collect::<Vec<ArkG2>>()
-    };
-    Ok(KZGSettings {
-        fs: fft_settings_to_rust(c_settings)?,
-        secret_g1,
-        secret_g2,
-        // TODO:
-        precomputation: None,
-    })
+/// Load trusted setup in any format supported by [`load_trusted_setup_points`]
+pub fn load_trusted_setup_contents_rust(contents: &[u8]) -> Result<KZGSettings, Error> {
+    let (g1_values, g2_values) = load_trusted_setup_points::<ArkG1, ArkFp, ArkG2>(contents)?;
+    let (secret_g1, secret_g2, _max_scale, fs) =
+        load_trusted_setup_values_rust::<ArkFr, ArkG1, ArkG2, FFTSettings, ArkFp>(
+            g1_values, g2_values,
+        )?;
+
+    #[cfg(not(feature = "std"))]
+    {
+        use kzg::KZGSettings as _;
+        KZGSettings::new_from_lagrange(&secret_g1, &secret_g2, _max_scale, &fs)
+    }
+
+    #[cfg(feature = "std")]
+    {
+        // Table is read from disk instead of being built, if RUST_KZG_PRECOMPUTATION_DIR enables caching
+        let precomputation = Some(Arc::new(precompute_with_env_cache(&secret_g1)?));
+
+        KZGSettings::from_lagrange_brp(secret_g1, secret_g2, fs, precomputation)
+    }
 }
 
+/// Types of arkworks backend, to be used with [`KzgContext`](kzg::backend::KzgContext)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArkBackend;
+
+impl Backend for ArkBackend {
+    const NAME: &'static str = "arkworks";
+
+    type Fr = ArkFr;
+    type G1 = ArkG1;
+    type G2 = ArkG2;
+    type G1Fp = ArkFp;
+    type G1Affine = ArkG1Affine;
+    type Poly = PolyData;
+    type FFTSettings = FFTSettings;
+    type KZGSettings = KZGSettings;
+    type FK20MultiSettings = KzgFK20MultiSettings;
+
+    fn load_trusted_setup(contents: &[u8]) -> Result<Self::KZGSettings, Error> {
+        load_trusted_setup_contents_rust(contents)
+    }
+}
+
+kzg::export_c_api!(ArkBackend);
+
 fn kzg_settings_to_c(rust_settings: &KZGSettings) -> CKZGSettings {
     let g1_val = rust_settings
         .secret_g1
@@ -124,22 +142,6 @@ fn kzg_settings_to_c(rust_settings: &KZGSettings) -> CKZGSettings {
     }
 }
 
-unsafe fn deserialize_blob(blob: *const Blob) -> Result<Vec<ArkFr>, C_KZG_RET> {
-    (*blob)
-        .bytes
-        .chunks(BYTES_PER_FIELD_ELEMENT)
-        .map(|chunk| {
-            let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
-            bytes.copy_from_slice(chunk);
-            if let Ok(result) = ArkFr::from_bytes(&bytes) {
-                Ok(result)
-            } else {
-                Err(C_KZG_RET_BADARGS)
-            }
-        })
-        .collect::<Result<Vec<ArkFr>, C_KZG_RET>>()
-}
-
 macro_rules! handle_ckzg_badargs {
     ($x: expr) => {
         match $x {
@@ -149,6 +151,24 @@ macro_rules! handle_ckzg_badargs {
     };
 }
 
+macro_rules! handle_ckzg_error {
+    ($x: expr) => {
+        match $x {
+            Ok(value) => value,
+            Err(err) => return err.c_kzg_ret(),
+        }
+    };
+}
+
+/// Loads settings into a new handle, and returns a copy of their points in the layout of c-kzg-4844
+fn load_ckzg_settings(g1_bytes: &[u8], g2_bytes: &[u8]) -> Result<CKZGSettings, Error> {
+    let handle = c_api::load_trusted_setup_handle::<ArkBackend>(g1_bytes, g2_bytes)?;
+    let c_settings = kzg_settings_to_c(handle.settings());
+    C_KZG_HANDLES.insert(c_settings.g1_values as usize, handle);
+
+    Ok(c_settings)
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn blob_to_kzg_commitment(
@@ -156,17 +176,9 @@ pub unsafe extern "C" fn blob_to_kzg_commitment(
     blob: *const Blob,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    if TRUSTED_SETUP_NUM_G1_POINTS == 0 {
-        // FIXME: load_trusted_setup should set this value, but if not, it fails
-        TRUSTED_SETUP_NUM_G1_POINTS = FIELD_ELEMENTS_PER_BLOB
-    };
-
-    let deserialized_blob = handle_ckzg_badargs!(deserialize_blob(blob));
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-    let tmp = handle_ckzg_badargs!(blob_to_kzg_commitment_rust(&deserialized_blob, &settings));
-
-    (*out).bytes = tmp.to_bytes();
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::blob_to_kzg_commitment(out, blob, handle)
+    })
 }
 
 /// # Safety
@@ -178,17 +190,18 @@ pub unsafe extern "C" fn load_trusted_setup(
     g2_bytes: *const u8,
     n2: usize,
 ) -> C_KZG_RET {
-    let g1_bytes = core::slice::from_raw_parts(g1_bytes, n1 * BYTES_PER_G1);
-    let g2_bytes = core::slice::from_raw_parts(g2_bytes, n2 * BYTES_PER_G2);
-    TRUSTED_SETUP_NUM_G1_POINTS = g1_bytes.len() / BYTES_PER_G1;
-    let mut settings = handle_ckzg_badargs!(load_trusted_setup_rust(g1_bytes, g2_bytes));
-
-    let c_settings = kzg_settings_to_c(&settings);
-
-    PRECOMPUTATION_TABLES.save_precomputation(settings.precomputation.take(), &c_settings);
+    if out.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
 
-    *out = c_settings;
+    let (Some(g1_bytes), Some(g2_bytes)) = (
+        c_api::points_from_raw(g1_bytes, n1, BYTES_PER_G1),
+        c_api::points_from_raw(g2_bytes, n2, BYTES_PER_G2),
+    ) else {
+        return C_KZG_RET_BADARGS;
+    };
 
+    *out = handle_ckzg_error!(load_ckzg_settings(g1_bytes, g2_bytes));
     C_KZG_RET_OK
 }
 
@@ -199,31 +212,38 @@ pub unsafe extern "C" fn load_trusted_setup_file(
     out: *mut CKZGSettings,
     in_: *mut FILE,
 ) -> C_KZG_RET {
+    if out.is_null() || in_.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
     let mut buf = vec![0u8; 1024 * 1024];
     let len: usize = libc::fread(buf.as_mut_ptr() as *mut libc::c_void, 1, buf.len(), in_);
     let s = handle_ckzg_badargs!(String::from_utf8(buf[..len].to_vec()));
-    let (g1_bytes, g2_bytes) = handle_ckzg_badargs!(load_trusted_setup_string(&s));
-    TRUSTED_SETUP_NUM_G1_POINTS = g1_bytes.len() / BYTES_PER_G1;
-    if TRUSTED_SETUP_NUM_G1_POINTS != FIELD_ELEMENTS_PER_BLOB {
+    let (g1_bytes, g2_bytes) = handle_ckzg_error!(load_trusted_setup_string(&s));
+    if g1_bytes.len() / BYTES_PER_G1 != FIELD_ELEMENTS_PER_BLOB {
         // Helps pass the Java test "shouldThrowExceptionOnIncorrectTrustedSetupFromFile",
         // as well as 5 others that pass only if this one passes (likely because Java doesn't
         // deallocate its KZGSettings pointer when no exception is thrown).
         return C_KZG_RET_BADARGS;
     }
-    let mut settings = handle_ckzg_badargs!(load_trusted_setup_rust(
-        g1_bytes.as_slice(),
-        g2_bytes.as_slice()
-    ));
-
-    let c_settings = kzg_settings_to_c(&settings);
-
-    PRECOMPUTATION_TABLES.save_precomputation(settings.precomputation.take(), &c_settings);
-
-    *out = c_settings;
 
+    *out = handle_ckzg_error!(load_ckzg_settings(&g1_bytes, &g2_bytes));
     C_KZG_RET_OK
 }
 
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn compute_blob_kzg_proof(
+    out: *mut KZGProof,
+    blob: *const Blob,
+    commitment_bytes: *const Bytes48,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::compute_blob_kzg_proof(out, blob, commitment_bytes, handle)
+    })
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
@@ -231,32 +251,61 @@ pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
         return;
     }
 
-    PRECOMPUTATION_TABLES.remove_precomputation(&*s);
-
-    let max_width = (*s).max_width as usize;
-    let roots = Box::from_raw(core::slice::from_raw_parts_mut(
-        (*s).roots_of_unity,
-        max_width,
-    ));
-    drop(roots);
-    (*s).roots_of_unity = null_mut();
-
-    let g1 = Box::from_raw(core::slice::from_raw_parts_mut(
-        (*s).g1_values,
-        TRUSTED_SETUP_NUM_G1_POINTS,
-    ));
-    drop(g1);
-    (*s).g1_values = null_mut();
-
-    let g2 = Box::from_raw(core::slice::from_raw_parts_mut(
-        (*s).g2_values,
-        TRUSTED_SETUP_NUM_G2_POINTS,
-    ));
-    drop(g2);
-    (*s).g2_values = null_mut();
+    C_KZG_HANDLES.remove((*s).g1_values as usize);
+
+    if !(*s).roots_of_unity.is_null() {
+        let max_width = (*s).max_width as usize;
+        let roots = Box::from_raw(core::slice::from_raw_parts_mut(
+            (*s).roots_of_unity,
+            max_width,
+        ));
+        drop(roots);
+        (*s).roots_of_unity = null_mut();
+    }
+
+    if !(*s).g1_values.is_null() {
+        let g1 = Box::from_raw(core::slice::from_raw_parts_mut(
+            (*s).g1_values,
+            (*s).max_width as usize,
+        ));
+        drop(g1);
+        (*s).g1_values = null_mut();
+    }
+
+    if !(*s).g2_values.is_null() {
+        let g2 = Box::from_raw(core::slice::from_raw_parts_mut(
+            (*s).g2_values,
+            TRUSTED_SETUP_NUM_G2_POINTS,
+        ));
+        drop(g2);
+        (*s).g2_values = null_mut();
+    }
+
     (*s).max_width = 0;
 }
 
+/// Sets number of threads, which functions called with `s` split their work between. Zero means the global
+/// thread pool. Only settings loaded by [`load_trusted_setup`] or [`load_trusted_setup_file`] are accepted.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn set_trusted_setup_num_threads(
+    s: *const CKZGSettings,
+    num_threads: usize,
+) -> C_KZG_RET {
+    if s.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
+    let thread_pool = handle_ckzg_badargs!(ThreadPoolConfig::with_num_threads(num_threads));
+    if !C_KZG_HANDLES.set_thread_pool((*s).g1_values as usize, thread_pool) {
+        // Settings were not loaded by this library, so there is no handle to keep the thread pool in
+        return C_KZG_RET_BADARGS;
+    }
+
+    C_KZG_RET_OK
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn verify_kzg_proof(
@@ -267,25 +316,37 @@ pub unsafe extern "C" fn verify_kzg_proof(
     proof_bytes: *const Bytes48,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let frz = handle_ckzg_badargs!(ArkFr::from_bytes(&(*z_bytes).bytes));
-    let fry = handle_ckzg_badargs!(ArkFr::from_bytes(&(*y_bytes).bytes));
-    let g1commitment = handle_ckzg_badargs!(ArkG1::from_bytes(&(*commitment_bytes).bytes));
-    let g1proof = handle_ckzg_badargs!(ArkG1::from_bytes(&(*proof_bytes).bytes));
-
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-
-    let result = handle_ckzg_badargs!(verify_kzg_proof_rust(
-        &g1commitment,
-        &frz,
-        &fry,
-        &g1proof,
-        &settings
-    ));
-
-    *ok = result;
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_kzg_proof(ok, commitment_bytes, z_bytes, y_bytes, proof_bytes, handle)
+    })
+}
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn kzg_to_versioned_hash(
+    out: *mut Bytes32,
+    commitment: *const KZGCommitment,
+) -> C_KZG_RET {
+    (*out).bytes = kzg_to_versioned_hash_rust(&(*commitment).bytes);
     C_KZG_RET_OK
 }
 
+/// Executes the point evaluation precompile. On success, writes
+/// `POINT_EVALUATION_OUTPUT_LENGTH` bytes to `out`
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn point_evaluation_precompile(
+    out: *mut u8,
+    input: *const u8,
+    input_len: usize,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::point_evaluation_precompile(out, input, input_len, handle)
+    })
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn verify_blob_kzg_proof(
@@ -295,22 +356,9 @@ pub unsafe extern "C" fn verify_blob_kzg_proof(
     proof_bytes: *const Bytes48,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = handle_ckzg_badargs!(deserialize_blob(blob));
-
-    let commitment_g1 = handle_ckzg_badargs!(ArkG1::from_bytes(&(*commitment_bytes).bytes));
-    let proof_g1 = handle_ckzg_badargs!(ArkG1::from_bytes(&(*proof_bytes).bytes));
-
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-
-    let result = handle_ckzg_badargs!(verify_blob_kzg_proof_rust(
-        &deserialized_blob,
-        &commitment_g1,
-        &proof_g1,
-        &settings,
-    ));
-
-    *ok = result;
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_blob_kzg_proof(ok, blob, commitment_bytes, proof_bytes, handle)
+    })
 }
 
 /// # Safety
@@ -323,70 +371,33 @@ pub unsafe extern "C" fn verify_blob_kzg_proof_batch(
     n: usize,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let raw_blobs = core::slice::from_raw_parts(blobs, n);
-    let raw_commitments = core::slice::from_raw_parts(commitments_bytes, n);
-    let raw_proofs = core::slice::from_raw_parts(proofs_bytes, n);
-
-    let deserialized_blobs: Result<Vec<Vec<ArkFr>>, C_KZG_RET> = cfg_into_iter!(raw_blobs)
-        .map(|raw_blob| deserialize_blob(raw_blob).map_err(|_| C_KZG_RET_BADARGS))
-        .collect();
-
-    let commitments_g1: Result<Vec<ArkG1>, C_KZG_RET> = cfg_into_iter!(raw_commitments)
-        .map(|raw_commitment| {
-            ArkG1::from_bytes(&raw_commitment.bytes).map_err(|_| C_KZG_RET_BADARGS)
-        })
-        .collect();
-
-    let proofs_g1: Result<Vec<ArkG1>, C_KZG_RET> = cfg_into_iter!(raw_proofs)
-        .map(|raw_proof| ArkG1::from_bytes(&raw_proof.bytes).map_err(|_| C_KZG_RET_BADARGS))
-        .collect();
-
-    if let (Ok(blobs), Ok(commitments), Ok(proofs)) =
-        (deserialized_blobs, commitments_g1, proofs_g1)
-    {
-        let settings = match kzg_settings_to_rust(s) {
-            Ok(value) => value,
-            Err(_) => return C_KZG_RET_BADARGS,
-        };
-
-        let result =
-            verify_blob_kzg_proof_batch_rust(blobs.as_slice(), &commitments, &proofs, &settings);
-
-        if let Ok(result) = result {
-            *ok = result;
-            C_KZG_RET_OK
-        } else {
-            C_KZG_RET_BADARGS
-        }
-    } else {
-        *ok = false;
-        C_KZG_RET_BADARGS
-    }
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_blob_kzg_proof_batch(ok, blobs, commitments_bytes, proofs_bytes, n, handle)
+    })
 }
 
+/// Verifies a batch of blob proofs, setting `results[i]` to whether proof `i` is valid
+///
 /// # Safety
 #[no_mangle]
-pub unsafe extern "C" fn compute_blob_kzg_proof(
-    out: *mut KZGProof,
-    blob: *const Blob,
-    commitment_bytes: *const Bytes48,
+pub unsafe extern "C" fn verify_blob_kzg_proof_batch_per_item(
+    results: *mut bool,
+    blobs: *const Blob,
+    commitments_bytes: *const Bytes48,
+    proofs_bytes: *const Bytes48,
+    n: usize,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = match deserialize_blob(blob) {
-        Ok(value) => value,
-        Err(err) => return err,
-    };
-
-    let commitment_g1 = handle_ckzg_badargs!(ArkG1::from_bytes(&(*commitment_bytes).bytes));
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-    let proof = handle_ckzg_badargs!(compute_blob_kzg_proof_rust(
-        &deserialized_blob,
-        &commitment_g1,
-        &settings
-    ));
-
-    (*out).bytes = proof.to_bytes();
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_blob_kzg_proof_batch_per_item(
+            results,
+            blobs,
+            commitments_bytes,
+            proofs_bytes,
+            n,
+            handle,
+        )
+    })
 }
 
 /// # Safety
@@ -398,28 +409,7 @@ pub unsafe extern "C" fn compute_kzg_proof(
     z_bytes: *const Bytes32,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = match deserialize_blob(blob) {
-        Ok(value) => value,
-        Err(err) => return err,
-    };
-
-    let frz = match ArkFr::from_bytes(&(*z_bytes).bytes) {
-        Ok(value) => value,
-        Err(_) => return C_KZG_RET_BADARGS,
-    };
-
-    let settings = match kzg_settings_to_rust(s) {
-        Ok(value) => value,
-        Err(_) => return C_KZG_RET_BADARGS,
-    };
-
-    let (proof_out_tmp, fry_tmp) = match compute_kzg_proof_rust(&deserialized_blob, &frz, &settings)
-    {
-        Ok(value) => value,
-        Err(_) => return C_KZG_RET_BADARGS,
-    };
-
-    (*proof_out).bytes = proof_out_tmp.to_bytes();
-    (*y_out).bytes = fry_tmp.to_bytes();
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::compute_kzg_proof(proof_out, y_out, blob, z_bytes, handle)
+    })
 }
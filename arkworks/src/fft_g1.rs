@@ -5,6 +5,7 @@ use crate::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine};
 use crate::kzg_types::ArkG1ProjAddAffine;
 
 use kzg::msm::msm_impls::msm;
+use kzg::Error;
 
 use kzg::msm::precompute::PrecomputationTable;
 use kzg::{Fr as KzgFr, G1Mul};
@@ -41,12 +42,16 @@ pub fn make_data(data: usize) -> Vec<ArkG1> {
 }
 
 impl FFTG1<ArkG1> for FFTSettings {
-    fn fft_g1(&self, data: &[ArkG1], inverse: bool) -> Result<Vec<ArkG1>, String> {
+    fn fft_g1(&self, data: &[ArkG1], inverse: bool) -> Result<Vec<ArkG1>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from("data length is longer than allowed max width"));
+            return Err(Error::BadArgs(String::from(
+                "data length is longer than allowed max width",
+            )));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("data length is not power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "data length is not power of 2",
+            )));
         }
 
         let stride: usize = self.max_width / data.len();
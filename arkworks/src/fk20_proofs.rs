@@ -3,7 +3,9 @@ use crate::kzg_proofs::{FFTSettings, KZGSettings};
 use crate::kzg_types::{ArkFp, ArkFr as BlstFr, ArkG1, ArkG1Affine, ArkG2};
 use crate::utils::PolyData;
 use kzg::common_utils::reverse_bit_order;
-use kzg::{FFTFr, FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, Poly, FFTG1, G1};
+use kzg::msm::fixed_base::FixedBaseMsm;
+use kzg::Error;
+use kzg::{FFTFr, FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, Poly, FFTG1};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -22,6 +24,8 @@ pub struct KzgFK20MultiSettings {
     pub ks: KZGSettings,
     pub chunk_len: usize,
     pub x_ext_fft_files: Vec<Vec<ArkG1>>,
+    /// `j`-th points of all `x_ext_fft_files`, multiplied together when computing `h_ext_fft[j]`
+    pub x_ext_fft_columns: Vec<FixedBaseMsm<BlstFr, ArkG1, ArkFp, ArkG1Affine>>,
     pub length: usize,
 }
 
@@ -29,19 +33,21 @@ impl
     FK20SingleSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings, ArkFp, ArkG1Affine>
     for KzgFK20SingleSettings
 {
-    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, Error> {
         let n = n2 / 2;
 
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be equal or greater than 2",
+            )));
         }
 
         let mut x = Vec::new();
@@ -62,17 +68,17 @@ impl
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
 
         let mut out = fk20_single_da_opt(p, self).unwrap();
@@ -80,7 +86,7 @@ impl
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         fk20_single_da_opt(p, self)
     }
 }
@@ -88,26 +94,32 @@ impl
 impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings, ArkFp, ArkG1Affine>
     for KzgFK20MultiSettings
 {
-    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, Error> {
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be equal or greater than 2",
+            )));
         }
         if chunk_len > n2 / 2 {
-            return Err(String::from("chunk_len must be equal or less than n2/2"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be equal or less than n2/2",
+            )));
         }
         if !chunk_len.is_power_of_two() {
-            return Err(String::from("chunk_len must be power of 2"));
+            return Err(Error::BadArgs(String::from("chunk_len must be power of 2")));
         }
         if chunk_len == 0 {
-            return Err(String::from("chunk_len must be greater than 0"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be greater than 0",
+            )));
         }
 
         let n = n2 / 2;
@@ -135,6 +147,16 @@ impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings,
             x_ext_fft_files.push(toeplitz_part_1(&x, &ks.fs).unwrap());
         }
 
+        let x_ext_fft_columns = (0..k * 2)
+            .map(|j| {
+                let column = x_ext_fft_files
+                    .iter()
+                    .map(|file| file[j])
+                    .collect::<Vec<_>>();
+                FixedBaseMsm::new(&column)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         let new_ks = KZGSettings {
             fs: ks.fs.clone(),
             ..KZGSettings::default()
@@ -143,22 +165,23 @@ impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings,
         Ok(KzgFK20MultiSettings {
             ks: new_ks,
             x_ext_fft_files,
+            x_ext_fft_columns,
             chunk_len,
             length: n, //unsure if this is right
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
 
         let mut out = fk20_multi_da_opt(p, self).unwrap();
@@ -166,22 +189,22 @@ impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings,
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         fk20_multi_da_opt(p, self)
     }
 }
 
-fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ArkG1>, String> {
+fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ArkG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "n2 must be equal or less than kzg settings max width",
-        ));
+        )));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::BadArgs(String::from("n2 must be power of 2")));
     }
 
     let outlen = 2 * p.len();
@@ -192,39 +215,50 @@ fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<Ar
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG1>, String> {
+fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "n2 must be equal or less than kzg settings max width",
-        ));
+        )));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::BadArgs(String::from("n2 must be power of 2")));
     }
 
     let n = n2 / 2;
     let k = n / fk.chunk_len;
     let k2 = k * 2;
 
-    let mut h_ext_fft = Vec::new();
-    for _i in 0..k2 {
-        h_ext_fft.push(G1_IDENTITY);
+    if k2 > fk.x_ext_fft_columns.len() {
+        return Err(Error::BadArgs(String::from(
+            "n2 must be equal or less than n2 of FK20 settings",
+        )));
     }
 
-    let mut toeplitz_coeffs = PolyData::new(n2 / fk.chunk_len);
+    let mut toeplitz_coeffs_fft = Vec::with_capacity(fk.chunk_len);
     for i in 0..fk.chunk_len {
-        toeplitz_coeffs =
-            toeplitz_coeffs_stride(p, i, fk.chunk_len, toeplitz_coeffs.len()).unwrap();
-        let h_ext_fft_file =
-            toeplitz_part_2(&toeplitz_coeffs, &fk.x_ext_fft_files[i], &fk.ks.fs).unwrap();
-        for j in 0..k2 {
-            h_ext_fft[j] = h_ext_fft[j].add_or_dbl(&h_ext_fft_file[j]);
-        }
+        let toeplitz_coeffs = toeplitz_coeffs_stride(p, i, fk.chunk_len, k2)?;
+        toeplitz_coeffs_fft.push(fk.ks.fs.fft_fr(&toeplitz_coeffs.coeffs, false)?);
     }
 
+    // `h_ext_fft[j]` is the sum of `j`-th points of all files, multiplied by `j`-th coefficients
+    let h_ext_fft = fk
+        .x_ext_fft_columns
+        .iter()
+        .take(k2)
+        .enumerate()
+        .map(|(j, column)| {
+            let scalars = toeplitz_coeffs_fft
+                .iter()
+                .map(|coeffs_fft| coeffs_fft[j])
+                .collect::<Vec<_>>();
+            column.msm(&scalars)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     // Calculate `h`
     let mut h = toeplitz_part_3(&h_ext_fft, &fk.ks.fs).unwrap();
 
@@ -236,7 +270,7 @@ fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, String> {
+fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, Error> {
     toeplitz_coeffs_stride(p, 0, 1, outlen)
 }
 
@@ -245,18 +279,22 @@ fn toeplitz_coeffs_stride(
     offset: usize,
     stride: usize,
     outlen: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     let n = poly.len();
 
     if stride == 0 {
-        return Err(String::from("stride must be greater than 0"));
+        return Err(Error::BadArgs(String::from(
+            "stride must be greater than 0",
+        )));
     }
 
     let k = n / stride;
     let k2 = k * 2;
 
     if outlen < k2 {
-        return Err(String::from("outlen must be equal or greater than k2"));
+        return Err(Error::BadArgs(String::from(
+            "outlen must be equal or greater than k2",
+        )));
     }
 
     let mut out = PolyData::new(outlen);
@@ -274,7 +312,7 @@ fn toeplitz_coeffs_stride(
     Ok(out)
 }
 
-fn toeplitz_part_1(x: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, String> {
+fn toeplitz_part_1(x: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, Error> {
     let n = x.len();
     let n2 = n * 2;
 
@@ -292,7 +330,7 @@ fn toeplitz_part_2(
     toeplitz_coeffs: &PolyData,
     x_ext_fft: &[ArkG1],
     fs: &FFTSettings,
-) -> Result<Vec<ArkG1>, String> {
+) -> Result<Vec<ArkG1>, Error> {
     let toeplitz_coeffs_fft = fs.fft_fr(&toeplitz_coeffs.coeffs, false).unwrap();
 
     #[cfg(feature = "parallel")]
@@ -314,7 +352,7 @@ fn toeplitz_part_2(
     }
 }
 
-fn toeplitz_part_3(h_ext_fft: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, String> {
+fn toeplitz_part_3(h_ext_fft: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, Error> {
     let n = h_ext_fft.len() / 2;
     let mut out = fs.fft_g1(h_ext_fft, true).unwrap();
 
@@ -13,9 +13,12 @@ use ark_poly::Polynomial;
 use ark_std::{vec, One};
 use kzg::eip_4844::hash_to_bls_field;
 use kzg::msm::precompute::PrecomputationTable;
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 use kzg::Fr as FrTrait;
 use kzg::{G1Mul, G2Mul};
 use std::ops::Neg;
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone)]
 pub struct FFTSettings {
@@ -26,30 +29,45 @@ pub struct FFTSettings {
     pub roots_of_unity: Vec<BlstFr>,
 }
 
-pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, String> {
+pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, Error> {
     let mut generated_powers = vec![BlstFr::one(), *root];
 
     while !(generated_powers.last().unwrap().is_one()) {
         if generated_powers.len() > width {
-            return Err(String::from("Root of unity multiplied for too long"));
+            return Err(Error::BadArgs(String::from(
+                "Root of unity multiplied for too long",
+            )));
         }
 
         generated_powers.push(generated_powers.last().unwrap().mul(root));
     }
 
     if generated_powers.len() != width + 1 {
-        return Err(String::from("Root of unity has invalid scale"));
+        return Err(Error::BadArgs(String::from(
+            "Root of unity has invalid scale",
+        )));
     }
 
     Ok(generated_powers)
 }
 
+/// Miller loop data of a G2 point, which allows reusing it in many pairings
+pub type ArkG2Prepared = <Bls12_381 as Pairing>::G2Prepared;
+
 #[derive(Debug, Clone, Default)]
 pub struct KZGSettings {
     pub fs: FFTSettings,
     pub secret_g1: Vec<ArkG1>,
     pub secret_g2: Vec<ArkG2>,
     pub precomputation: Option<Arc<PrecomputationTable<ArkFr, ArkG1, ArkFp, ArkG1Affine>>>,
+    /// G1 points in monomial form, used when `secret_g1` holds points in bit-reversed Lagrange form, as
+    /// loaded from a trusted setup. They are derived when settings are built. `None` means that `secret_g1`
+    /// already holds points in monomial form
+    pub secret_g1_monomial: Option<Vec<ArkG1>>,
+    /// Prepared G2 generator and `secret_g2[1]`, derived on first verification
+    pub g2_prepared: OnceLock<(ArkG2Prepared, ArkG2Prepared)>,
+    /// Thread pool, which parallel work is run on
+    pub thread_pool: ThreadPoolConfig,
 }
 
 pub fn generate_trusted_setup(len: usize, secret: [u8; 32usize]) -> (Vec<ArkG1>, Vec<ArkG2>) {
@@ -86,3 +104,29 @@ pub fn pairings_verify(a1: &ArkG1, a2: &ArkG2, b1: &ArkG1, b2: &ArkG2) -> bool {
         .0
         .is_one()
 }
+
+/// Same as [`pairings_verify`], but with prepared G2 points
+pub fn prepared_pairings_verify(
+    a1: &ArkG1,
+    a2: &ArkG2Prepared,
+    b1: &ArkG1,
+    b2: &ArkG2Prepared,
+) -> bool {
+    let ark_a1_neg = a1.0.neg().into_affine();
+    let ark_b1 = b1.0.into_affine();
+
+    Bls12_381::multi_pairing([ark_a1_neg, ark_b1], [a2.clone(), b2.clone()])
+        .0
+        .is_one()
+}
+
+pub fn multi_pairings_verify(ps: &[ArkG1], qs: &[ArkG2]) -> bool {
+    if ps.len() != qs.len() {
+        return false;
+    }
+
+    let ark_ps = ps.iter().map(|p| p.0.into_affine());
+    let ark_qs = qs.iter().map(|q| q.0.into_affine());
+
+    Bls12_381::multi_pairing(ark_ps, ark_qs).0.is_one()
+}
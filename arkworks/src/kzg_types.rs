@@ -4,7 +4,8 @@ use crate::consts::{
 };
 use crate::fft_g1::g1_linear_combination;
 use crate::kzg_proofs::{
-    eval_poly, expand_root_of_unity, pairings_verify, FFTSettings as LFFTSettings,
+    eval_poly, expand_root_of_unity, multi_pairings_verify, pairings_verify,
+    prepared_pairings_verify, ArkG2Prepared, FFTSettings as LFFTSettings,
     KZGSettings as LKZGSettings,
 };
 use crate::poly::{poly_fast_div, poly_inverse, poly_long_div, poly_mul_direct, poly_mul_fft};
@@ -26,13 +27,20 @@ use ark_std::UniformRand;
 
 use blst::{blst_fp, blst_fr, blst_p1};
 use kzg::common_utils::reverse_bit_order;
-use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::eip_4844::{
+    eval_form_to_coeff_form, g1_lagrange_brp_to_monomial, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1,
+    BYTES_PER_G2,
+};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 use kzg::{
     FFTFr, FFTSettings, FFTSettingsPoly, Fr as KzgFr, G1Affine as G1AffineTrait, G1Fp, G1GetFp,
-    G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, KZGSettings, PairingVerify, Poly, Scalar256, G1, G2,
+    G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, KZGSettings, MultiPairing, PairingVerify, Poly,
+    Scalar256, G1, G2,
 };
 use std::ops::{AddAssign, Mul, Neg, Sub};
+use std::sync::OnceLock;
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -100,15 +108,15 @@ impl KzgFr for ArkFr {
         }
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let storage: [u64; 4] = [
@@ -119,7 +127,7 @@ impl KzgFr for ArkFr {
                 ];
                 let big_int = BigInteger256::new(storage);
                 if !big_int.is_zero() && !bigint_check_mod_256(&big_int.0) {
-                    return Err("Invalid scalar".to_string());
+                    return Err(Error::NonCanonicalFieldElement);
                 }
                 Ok(Self {
                     fr: Fr::new(big_int),
@@ -127,15 +135,15 @@ impl KzgFr for ArkFr {
             })
     }
 
-    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .map(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let storage: [u64; 4] = [
@@ -151,7 +159,7 @@ impl KzgFr for ArkFr {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -229,7 +237,7 @@ impl KzgFr for ArkFr {
         }
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         let div = self.fr / b.fr;
         if div.0 .0.is_empty() {
             Ok(Self { fr: Fr::zero() })
@@ -287,26 +295,38 @@ impl G1 for ArkG1 {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G1,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
+                // Point at infinity must be encoded with all other bits unset, which
+                // arkworks does not check on its own
+                if bytes[0] & 0x40 != 0
+                    && (bytes[0] != 0xc0 || bytes[1..].iter().any(|byte| *byte != 0))
+                {
+                    return Err(Error::InvalidPoint(String::from(
+                        "Invalid encoding of point at infinity",
+                    )));
+                }
+
                 let affine = G1Affine::deserialize_compressed(bytes.as_slice());
                 match affine {
-                    Err(x) => Err("Failed to deserialize G1: ".to_owned() + &(x.to_string())),
+                    Err(x) => Err(Error::InvalidPoint(
+                        "Failed to deserialize G1: ".to_owned() + &(x.to_string()),
+                    )),
                     Ok(x) => Ok(Self(x.into_group())),
                 }
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -412,6 +432,12 @@ impl PairingVerify<ArkG1, ArkG2> for ArkG1 {
     }
 }
 
+impl MultiPairing<ArkG1, ArkG2> for ArkG1 {
+    fn multi_pairing_is_one(ps: &[ArkG1], qs: &[ArkG2]) -> bool {
+        multi_pairings_verify(ps, qs)
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct ArkG2(pub Projective<g2::Config>);
@@ -436,20 +462,22 @@ impl G2 for ArkG2 {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G2,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
                 let affine = G2Affine::deserialize_compressed(bytes.as_slice());
                 match affine {
-                    Err(x) => Err("Failed to deserialize G2: ".to_owned() + &(x.to_string())),
+                    Err(x) => Err(Error::InvalidPoint(
+                        "Failed to deserialize G2: ".to_owned() + &(x.to_string()),
+                    )),
                     Ok(x) => Ok(Self(x.into_group())),
                 }
             })
@@ -519,11 +547,11 @@ impl Poly<ArkFr> for PolyData {
         unscale_poly(self);
     }
 
-    fn inverse(&mut self, new_len: usize) -> Result<Self, String> {
+    fn inverse(&mut self, new_len: usize) -> Result<Self, Error> {
         poly_inverse(self, new_len)
     }
 
-    fn div(&mut self, x: &Self) -> Result<Self, String> {
+    fn div(&mut self, x: &Self) -> Result<Self, Error> {
         if x.len() >= self.len() || x.len() < 128 {
             poly_long_div(self, x)
         } else {
@@ -531,15 +559,15 @@ impl Poly<ArkFr> for PolyData {
         }
     }
 
-    fn long_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn long_div(&mut self, x: &Self) -> Result<Self, Error> {
         poly_long_div(self, x)
     }
 
-    fn fast_div(&mut self, x: &Self) -> Result<Self, String> {
+    fn fast_div(&mut self, x: &Self) -> Result<Self, Error> {
         poly_fast_div(self, x)
     }
 
-    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
+    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, Error> {
         poly_mul_direct(self, x, len)
     }
 }
@@ -550,7 +578,7 @@ impl FFTSettingsPoly<ArkFr, PolyData, LFFTSettings> for LFFTSettings {
         x: &PolyData,
         len: usize,
         fs: Option<&LFFTSettings>,
-    ) -> Result<PolyData, String> {
+    ) -> Result<PolyData, Error> {
         poly_mul_fft(a, x, fs, len)
     }
 }
@@ -568,11 +596,11 @@ impl Default for LFFTSettings {
 }
 
 impl FFTSettings<ArkFr> for LFFTSettings {
-    fn new(scale: usize) -> Result<LFFTSettings, String> {
+    fn new(scale: usize) -> Result<LFFTSettings, Error> {
         if scale >= SCALE2_ROOT_OF_UNITY.len() {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Scale is expected to be within root of unity matrix row size",
-            ));
+            )));
         }
 
         let max_width: usize = 1 << scale;
@@ -624,24 +652,68 @@ impl FFTSettings<ArkFr> for LFFTSettings {
     }
 }
 
+impl LKZGSettings {
+    /// Build settings from G1 points in bit-reversed Lagrange form, as loaded from a trusted setup, deriving
+    /// their monomial form. Fails with [`Error::InvalidTrustedSetup`] if it can't be derived
+    pub fn from_lagrange_brp(
+        secret_g1: Vec<ArkG1>,
+        secret_g2: Vec<ArkG2>,
+        fs: LFFTSettings,
+        precomputation: Option<Arc<PrecomputationTable<ArkFr, ArkG1, ArkFp, ArkG1Affine>>>,
+    ) -> Result<Self, Error> {
+        let secret_g1_monomial =
+            g1_lagrange_brp_to_monomial::<ArkFr, ArkG1, LFFTSettings>(&secret_g1, &fs)?;
+
+        Ok(Self {
+            fs,
+            secret_g1,
+            secret_g2,
+            precomputation,
+            secret_g1_monomial: Some(secret_g1_monomial),
+            g2_prepared: OnceLock::new(),
+            thread_pool: ThreadPoolConfig::default(),
+        })
+    }
+}
+
 impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine> for LKZGSettings {
     fn new(
         secret_g1: &[ArkG1],
         secret_g2: &[ArkG2],
         _length: usize,
         fft_settings: &LFFTSettings,
-    ) -> Result<LKZGSettings, String> {
+    ) -> Result<LKZGSettings, Error> {
         Ok(Self {
             secret_g1: secret_g1.to_vec(),
             secret_g2: secret_g2.to_vec(),
             fs: fft_settings.clone(),
-            precomputation: precompute(secret_g1).ok().flatten().map(Arc::new),
+            secret_g1_monomial: None,
+            g2_prepared: OnceLock::new(),
+            thread_pool: ThreadPoolConfig::default(),
+            precomputation: precompute(secret_g1).ok().map(Arc::new),
         })
     }
 
-    fn commit_to_poly(&self, p: &PolyData) -> Result<ArkG1, String> {
+    fn new_from_lagrange(
+        secret_g1: &[ArkG1],
+        secret_g2: &[ArkG2],
+        length: usize,
+        fft_settings: &LFFTSettings,
+    ) -> Result<Self, Error> {
+        let settings = Self::new(secret_g1, secret_g2, length, fft_settings)?;
+        Self::from_lagrange_brp(
+            settings.secret_g1,
+            settings.secret_g2,
+            settings.fs,
+            settings.precomputation,
+        )
+    }
+
+    fn commit_to_poly(&self, p: &PolyData) -> Result<ArkG1, Error> {
         if p.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
+            return Err(Error::BadArgs(String::from(
+                "Polynomial is longer than secret g1",
+            )));
         }
 
         let mut out = ArkG1::default();
@@ -656,9 +728,42 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         Ok(out)
     }
 
-    fn compute_proof_single(&self, p: &PolyData, x: &ArkFr) -> Result<ArkG1, String> {
+    fn commit_coeff_form(&self, p: &PolyData) -> Result<ArkG1, Error> {
+        if self.secret_g1_monomial.is_none() {
+            return self.commit_to_poly(p);
+        }
+
+        let g1_monomial = self.get_g1_monomial();
+        if p.coeffs.len() > g1_monomial.len() {
+            return Err(Error::BadArgs(String::from(
+                "Polynomial is longer than secret g1",
+            )));
+        }
+
+        let mut out = ArkG1::default();
+        g1_linear_combination(&mut out, g1_monomial, &p.coeffs, p.coeffs.len(), None);
+
+        Ok(out)
+    }
+
+    fn commit_eval_form(&self, p: &PolyData) -> Result<ArkG1, Error> {
+        if self.secret_g1_monomial.is_none() {
+            let coeffs = eval_form_to_coeff_form(&p.coeffs, &self.fs)?;
+            return self.commit_coeff_form(&PolyData { coeffs });
+        }
+
+        if p.coeffs.len() != self.secret_g1.len() {
+            return Err(Error::InvalidLength(String::from(
+                "Number of evaluations must match secret g1",
+            )));
+        }
+
+        self.commit_to_poly(p)
+    }
+
+    fn compute_proof_single(&self, p: &PolyData, x: &ArkFr) -> Result<ArkG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::BadArgs(String::from("Polynomial must not be empty")));
         }
 
         // `-(x0^n)`, where `n` is `1`
@@ -673,7 +778,7 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         }
 
         let q = PolyData { coeffs: out_coeffs };
-        let ret = self.commit_to_poly(&q)?;
+        let ret = self.commit_coeff_form(&q)?;
         Ok(ret)
         // Ok(compute_single(p, x, self))
     }
@@ -684,27 +789,23 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         proof: &ArkG1,
         x: &ArkFr,
         y: &ArkFr,
-    ) -> Result<bool, String> {
-        let x_g2: ArkG2 = G2_GENERATOR.mul(x);
-        let s_minus_x: ArkG2 = self.secret_g2[1].sub(&x_g2);
+    ) -> Result<bool, Error> {
+        // e(C - [y], G2) == e(proof, [s - x]G2) is checked as
+        // e(proof, [s]G2) == e(C - [y] + [x]proof, G2), which keeps both G2 points fixed
         let y_g1 = G1_GENERATOR.mul(y);
-        let commitment_minus_y: ArkG1 = com.sub(&y_g1);
+        let x_proof = proof.mul(x);
+        let commitment_minus_y_plus_x_proof = com.sub(&y_g1).add_or_dbl(&x_proof);
 
-        Ok(pairings_verify(
-            &commitment_minus_y,
-            &G2_GENERATOR,
-            proof,
-            &s_minus_x,
-        ))
+        Ok(self.verify_pairing_with_g2_secret(proof, &commitment_minus_y_plus_x_proof))
     }
 
-    fn compute_proof_multi(&self, p: &PolyData, x: &ArkFr, n: usize) -> Result<ArkG1, String> {
+    fn compute_proof_multi(&self, p: &PolyData, x: &ArkFr, n: usize) -> Result<ArkG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::BadArgs(String::from("Polynomial must not be empty")));
         }
 
         if !n.is_power_of_two() {
-            return Err(String::from("n must be a power of two"));
+            return Err(Error::BadArgs(String::from("n must be a power of two")));
         }
 
         // Construct x^n - x0^n = (x - x0.w^0)(x - x0.w^1)...(x - x0.w^(n-1))
@@ -730,7 +831,7 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         // Calculate q = p / (x^n - x0^n)
         // let q = p.div(&divisor).unwrap();
         let q = new_polina.div(&divisor)?;
-        let ret = self.commit_to_poly(&q)?;
+        let ret = self.commit_coeff_form(&q)?;
         Ok(ret)
     }
 
@@ -741,9 +842,9 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         x: &ArkFr,
         ys: &[ArkFr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         if !n.is_power_of_two() {
-            return Err(String::from("n is not a power of two"));
+            return Err(Error::BadArgs(String::from("n is not a power of two")));
         }
 
         // Interpolate at a coset.
@@ -767,7 +868,7 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         let xn_minus_yn = self.secret_g2[n].sub(&xn2);
 
         // [interpolation_polynomial(s)]_1
-        let is1 = self.commit_to_poly(&interp).unwrap();
+        let is1 = self.commit_coeff_form(&interp).unwrap();
 
         // [commitment - interpolation_polynomial(s)]_1 = [commit]_1 - [interpolation_polynomial(s)]_1
         let commit_minus_interp = com.sub(&is1);
@@ -793,13 +894,38 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         &self.secret_g1
     }
 
+    fn get_g1_monomial(&self) -> &[ArkG1] {
+        self.secret_g1_monomial
+            .as_deref()
+            .unwrap_or(&self.secret_g1)
+    }
+
     fn get_g2_secret(&self) -> &[ArkG2] {
         &self.secret_g2
     }
 
+    fn verify_pairing_with_g2_secret(&self, a: &ArkG1, b: &ArkG1) -> bool {
+        let (g2_generator, g2_secret) = self.g2_prepared.get_or_init(|| {
+            (
+                ArkG2Prepared::from(G2_GENERATOR.0),
+                ArkG2Prepared::from(self.secret_g2[1].0),
+            )
+        });
+
+        prepared_pairings_verify(a, g2_secret, b, g2_generator)
+    }
+
     fn get_precomputation(&self) -> Option<&PrecomputationTable<ArkFr, ArkG1, ArkFp, ArkG1Affine>> {
         self.precomputation.as_ref().map(|v| v.as_ref())
     }
+
+    fn get_thread_pool(&self) -> &ThreadPoolConfig {
+        &self.thread_pool
+    }
+
+    fn set_thread_pool(&mut self, thread_pool: ThreadPoolConfig) {
+        self.thread_pool = thread_pool;
+    }
 }
 
 type ArkFpInt = <ark_bls12_381::g1::Config as CurveConfig>::BaseField;
@@ -842,6 +968,10 @@ impl G1Fp for ArkFp {
         Self(default)
     }
 
+    fn to_underlying_arr(&self) -> [u64; 6] {
+        self.0 .0 .0
+    }
+
     fn neg_assign(&mut self) {
         self.0 = -self.0;
     }
@@ -8,16 +8,17 @@ use ark_poly::univariate::DensePolynomial;
 use ark_poly::DenseUVPolynomial;
 use ark_std::{log2, Zero};
 use kzg::common_utils::{log2_pow2, next_pow_of_2};
+use kzg::Error;
 use kzg::{FFTFr, FFTSettings as FFTSettingsT, Fr as FrTrait, Poly};
 use std::cmp::min;
 
-pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String> {
+pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, Error> {
     if b.coeffs.is_empty() {
-        return Err(String::from("b.coeffs is empty"));
+        return Err(Error::BadArgs(String::from("b.coeffs is empty")));
     }
 
     if BlstFr::is_zero(&b.coeffs[0]) {
-        return Err(String::from("b.coeffs[0] is zero"));
+        return Err(Error::BadArgs(String::from("b.coeffs[0] is zero")));
     }
 
     let mut output = PolyData {
@@ -68,12 +69,14 @@ pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String>
         }
     }
     if d + 1 != output_len {
-        return Err(String::from("d + 1 is not equals to output_len"));
+        return Err(Error::BadArgs(String::from(
+            "d + 1 is not equals to output_len",
+        )));
     }
     Ok(output)
 }
 
-pub fn poly_mul_direct(p1: &PolyData, p2: &PolyData, len: usize) -> Result<PolyData, String> {
+pub fn poly_mul_direct(p1: &PolyData, p2: &PolyData, len: usize) -> Result<PolyData, Error> {
     let p1 = blst_poly_into_pc_poly(&p1.coeffs);
     let p2 = blst_poly_into_pc_poly(&p2.coeffs);
     if p1.is_zero() || p2.is_zero() {
@@ -95,7 +98,7 @@ pub fn poly_mul_direct(p1: &PolyData, p2: &PolyData, len: usize) -> Result<PolyD
     }
 }
 
-pub fn poly_long_div(p1: &PolyData, p2: &PolyData) -> Result<PolyData, String> {
+pub fn poly_long_div(p1: &PolyData, p2: &PolyData) -> Result<PolyData, Error> {
     Ok(pc_poly_into_blst_poly(
         &blst_poly_into_pc_poly(&p1.coeffs) / &blst_poly_into_pc_poly(&p2.coeffs),
     ))
@@ -106,7 +109,7 @@ pub fn poly_mul(
     b: &PolyData,
     fs: Option<&FFTSettings>,
     len: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     if a.coeffs.len() < 64 || b.coeffs.len() < 64 || len < 128 {
         poly_mul_direct(a, b, len)
     } else {
@@ -119,7 +122,7 @@ pub fn poly_mul_fft(
     b: &PolyData,
     fs: Option<&FFTSettings>,
     len: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     // Truncate a and b so as not to do excess work for the number of coefficients required.
     let a_len = min(a.len(), len);
     let b_len = min(b.len(), len);
@@ -134,9 +137,9 @@ pub fn poly_mul_fft(
     };
 
     if length > fs_p.max_width {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "length should be equals or less than FFTSettings max width",
-        ));
+        )));
     }
 
     let a = PolyData {
@@ -199,13 +202,15 @@ pub fn poly_mul_fft(
     Ok(out)
 }
 
-pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData, String> {
+pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData, Error> {
     if divisor.coeffs.is_empty() {
-        return Err(String::from("divisor coeffs are empty"));
+        return Err(Error::BadArgs(String::from("divisor coeffs are empty")));
     }
 
     if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-        return Err(String::from("divisor coeffs last member is zero"));
+        return Err(Error::BadArgs(String::from(
+            "divisor coeffs last member is zero",
+        )));
     }
 
     let m = dividend.coeffs.len() - 1;
@@ -216,7 +221,9 @@ pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData
     }
 
     if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-        return Err(String::from("divisor coeffs last member is zero"));
+        return Err(Error::BadArgs(String::from(
+            "divisor coeffs last member is zero",
+        )));
     }
 
     let mut out = PolyData::new(0);
@@ -242,7 +249,7 @@ pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData
     })
 }
 
-pub fn poly_flip(input: &PolyData) -> Result<PolyData, String> {
+pub fn poly_flip(input: &PolyData) -> Result<PolyData, Error> {
     let mut output = PolyData::new(0);
     for i in 0..input.len() {
         output.coeffs.push(input.coeffs[input.coeffs.len() - i - 1]);
@@ -2,11 +2,12 @@ use super::kzg_proofs::FFTSettings;
 use super::utils::{blst_poly_into_pc_poly, pc_poly_into_blst_poly, PolyData};
 use crate::kzg_types::ArkFr as BlstFr;
 use kzg::common_utils::next_pow_of_2;
+use kzg::Error;
 use kzg::{FFTFr, Fr as FrTrait, ZeroPoly};
 use std::cmp::{min, Ordering};
 use std::ops::Neg;
 
-pub(crate) fn pad_poly(poly: &PolyData, new_length: usize) -> Result<Vec<BlstFr>, String> {
+pub(crate) fn pad_poly(poly: &PolyData, new_length: usize) -> Result<Vec<BlstFr>, Error> {
     if new_length <= poly.coeffs.len() {
         return Ok(poly.coeffs.clone());
     }
@@ -25,9 +26,9 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         &self,
         indices: &[usize],
         stride: usize,
-    ) -> Result<PolyData, String> {
+    ) -> Result<PolyData, Error> {
         if indices.is_empty() {
-            return Err(String::from("idx array must be non-zero"));
+            return Err(Error::BadArgs(String::from("idx array must be non-zero")));
         }
         let blstpoly = PolyData {
             coeffs: vec![BlstFr::one(); indices.len() + 1],
@@ -53,14 +54,16 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         Ok(pc_poly_into_blst_poly(poly))
     }
 
-    fn reduce_partials(&self, len_out: usize, partials: &[PolyData]) -> Result<PolyData, String> {
+    fn reduce_partials(&self, len_out: usize, partials: &[PolyData]) -> Result<PolyData, Error> {
         let mut out_degree: usize = 0;
         for partial in partials {
             out_degree += partial.coeffs.len() - 1;
         }
 
         if out_degree + 1 > len_out {
-            return Err(String::from("Expected domain size to be a power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "Expected domain size to be a power of 2",
+            )));
         }
 
         let mut p_partial = pad_poly(&partials[0], len_out).unwrap();
@@ -88,7 +91,7 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         &self,
         length: usize,
         missing_indices: &[usize],
-    ) -> Result<(Vec<BlstFr>, PolyData), String> {
+    ) -> Result<(Vec<BlstFr>, PolyData), Error> {
         let zero_eval: Vec<BlstFr>;
         let mut zero_poly: PolyData;
 
@@ -99,13 +102,17 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         }
 
         if missing_indices.len() >= length {
-            return Err(String::from("Missing idxs greater than domain size"));
+            return Err(Error::BadArgs(String::from(
+                "Missing idxs greater than domain size",
+            )));
         } else if length > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Domain size greater than fft_settings.max_width",
-            ));
+            )));
         } else if !length.is_power_of_two() {
-            return Err(String::from("Domain size must be a power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "Domain size must be a power of 2",
+            )));
         }
 
         let degree_of_partial = 256;
@@ -3,7 +3,7 @@ mod tests {
     use kzg::common_utils::log_2_byte;
     use kzg_bench::tests::bls12_381::*;
     use rust_kzg_arkworks::fft_g1::g1_linear_combination;
-    use rust_kzg_arkworks::kzg_proofs::pairings_verify;
+    use rust_kzg_arkworks::kzg_proofs::{multi_pairings_verify, pairings_verify};
     use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
 
     #[test]
@@ -107,6 +107,11 @@ mod tests {
         pairings_work::<ArkFr, ArkG1, ArkG2>(&pairings_verify);
     }
 
+    #[test]
+    pub fn multi_pairings_work_() {
+        multi_pairings_work::<ArkFr, ArkG1, ArkG2>(&multi_pairings_verify);
+    }
+
     #[test]
     pub fn fr_is_null_works_() {
         fr_is_null_works::<ArkFr>();
@@ -1,9 +1,18 @@
 #[cfg(test)]
 mod tests {
+    use kzg_bench::tests::msm::buckets::{
+        test_bucket_accumulation_edge_cases, test_bucket_accumulations_agree,
+    };
+    use kzg_bench::tests::msm::fixed_base::{
+        test_fixed_base_msm, test_fixed_base_msm_short_inputs,
+    };
     use kzg_bench::tests::msm::msm_slice::{
         test_msm_slice_window_size_1, test_msm_slice_window_size_16, test_msm_slice_window_size_2,
         test_msm_slice_window_size_3,
     };
+    use kzg_bench::tests::msm::msm_strategy::{test_msm_auto_tuning, test_msm_strategies_agree};
+    use kzg_bench::tests::msm::sparse::{test_sparse_msm, test_tiling_pippenger_with_bits};
+    use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG1ProjAddAffine};
 
     #[test]
     pub fn test_msm_slice_window_size_1_() {
@@ -24,4 +33,44 @@ mod tests {
     fn test_msm_slice_window_size_16_() {
         test_msm_slice_window_size_16();
     }
+
+    #[test]
+    fn test_msm_strategies_agree_() {
+        test_msm_strategies_agree::<ArkFr, ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>();
+    }
+
+    #[test]
+    fn test_msm_auto_tuning_() {
+        test_msm_auto_tuning::<ArkFr, ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>();
+    }
+
+    #[test]
+    fn test_fixed_base_msm_() {
+        test_fixed_base_msm::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    fn test_fixed_base_msm_short_inputs_() {
+        test_fixed_base_msm_short_inputs::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    fn test_bucket_accumulations_agree_() {
+        test_bucket_accumulations_agree::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    fn test_bucket_accumulation_edge_cases_() {
+        test_bucket_accumulation_edge_cases::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    fn test_tiling_pippenger_with_bits_() {
+        test_tiling_pippenger_with_bits::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    fn test_sparse_msm_() {
+        test_sparse_msm::<ArkFr, ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>();
+    }
 }
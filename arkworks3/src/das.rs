@@ -1,5 +1,6 @@
 use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::ArkFr as BlstFr;
+use kzg::Error;
 use kzg::{Fr, DAS};
 use std::cmp::Ordering;
 
@@ -57,17 +58,19 @@ impl FFTSettings {
 }
 
 impl DAS<BlstFr> for FFTSettings {
-    fn das_fft_extension(&self, vals: &[BlstFr]) -> Result<Vec<BlstFr>, String> {
+    fn das_fft_extension(&self, vals: &[BlstFr]) -> Result<Vec<BlstFr>, Error> {
         if vals.is_empty() {
-            return Err(String::from("vals can not be empty"));
+            return Err(Error::BadArgs(String::from("vals can not be empty")));
         }
         if !vals.len().is_power_of_two() {
-            return Err(String::from("vals lenght has to be power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "vals lenght has to be power of 2",
+            )));
         }
         if vals.len() * 2 > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "vals lenght * 2 has to equal or less than FFTSetings max width",
-            ));
+            )));
         }
 
         let mut vals = vals.to_vec();
@@ -2,6 +2,7 @@ use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine};
 
 use kzg::cfg_into_iter;
+use kzg::Error;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
@@ -42,11 +43,8 @@ pub fn g1_linear_combination(
         let scalars =
             unsafe { alloc::slice::from_raw_parts(scalars.as_ptr() as *const BigInteger256, len) };
 
-        let point = if let Some(precomputation) = precomputation {
-            rust_kzg_arkworks3_sppark::multi_scalar_mult_prepared::<G1Affine>(
-                precomputation.table,
-                scalars,
-            )
+        let point = if let Some(table) = precomputation.and_then(|p| p.accelerator_table()) {
+            rust_kzg_arkworks3_sppark::multi_scalar_mult_prepared::<G1Affine>(table, scalars)
         } else {
             let affines = kzg::msm::msm_impls::batch_convert::<ArkG1, ArkFp, ArkG1Affine>(&points);
             let affines =
@@ -78,8 +76,8 @@ pub fn g1_linear_combination(
             .map(|scalar| scalar.fr.into_repr())
             .collect::<Vec<_>>();
 
-        let mut context = if let Some(context) = precomputation {
-            let table = context.table;
+        let table = precomputation.and_then(|p| p.accelerator_table());
+        let mut context = if let Some(table) = table {
             MultiScalarMultContext { context: table }
         } else {
             let affines = kzg::msm::msm_impls::batch_convert::<ArkG1, ArkFp, ArkG1Affine>(&points);
@@ -97,7 +95,7 @@ pub fn g1_linear_combination(
 
         *out = ArkG1(msm_results[0]);
 
-        if precomputation.is_none() {
+        if table.is_none() {
             rust_kzg_arkworks3_sppark_wlc::multi_scalar_mult_free(&mut context);
         }
     }
@@ -131,12 +129,16 @@ pub fn make_data(data: usize) -> Vec<ArkG1> {
 }
 
 impl FFTG1<ArkG1> for FFTSettings {
-    fn fft_g1(&self, data: &[ArkG1], inverse: bool) -> Result<Vec<ArkG1>, String> {
+    fn fft_g1(&self, data: &[ArkG1], inverse: bool) -> Result<Vec<ArkG1>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from("data length is longer than allowed max width"));
+            return Err(Error::BadArgs(String::from(
+                "data length is longer than allowed max width",
+            )));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("data length is not power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "data length is not power of 2",
+            )));
         }
 
         let stride: usize = self.max_width / data.len();
@@ -2,6 +2,7 @@ use crate::kzg_proofs::{FFTSettings, KZGSettings};
 use crate::kzg_types::{ArkFp, ArkFr as BlstFr, ArkG1, ArkG1Affine, ArkG2};
 use crate::utils::PolyData;
 use kzg::common_utils::reverse_bit_order;
+use kzg::Error;
 use kzg::{FFTFr, FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, Poly, FFTG1, G1};
 
 #[cfg(feature = "parallel")]
@@ -28,19 +29,21 @@ impl
     FK20SingleSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings, ArkFp, ArkG1Affine>
     for KzgFK20SingleSettings
 {
-    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, Error> {
         let n = n2 / 2;
 
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be equal or greater than 2",
+            )));
         }
 
         let mut x = Vec::new();
@@ -61,17 +64,17 @@ impl
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
 
         let mut out = fk20_single_da_opt(p, self).unwrap();
@@ -79,7 +82,7 @@ impl
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         fk20_single_da_opt(p, self)
     }
 }
@@ -87,26 +90,32 @@ impl
 impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings, ArkFp, ArkG1Affine>
     for KzgFK20MultiSettings
 {
-    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, Error> {
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be equal or greater than 2",
+            )));
         }
         if chunk_len > n2 / 2 {
-            return Err(String::from("chunk_len must be equal or less than n2/2"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be equal or less than n2/2",
+            )));
         }
         if !chunk_len.is_power_of_two() {
-            return Err(String::from("chunk_len must be power of 2"));
+            return Err(Error::BadArgs(String::from("chunk_len must be power of 2")));
         }
         if chunk_len == 0 {
-            return Err(String::from("chunk_len must be greater than 0"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be greater than 0",
+            )));
         }
 
         let n = n2 / 2;
@@ -147,17 +156,17 @@ impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings,
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
 
         let mut out = fk20_multi_da_opt(p, self).unwrap();
@@ -165,22 +174,22 @@ impl FK20MultiSettings<BlstFr, ArkG1, ArkG2, FFTSettings, PolyData, KZGSettings,
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ArkG1>, Error> {
         fk20_multi_da_opt(p, self)
     }
 }
 
-fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ArkG1>, String> {
+fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ArkG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "n2 must be equal or less than kzg settings max width",
-        ));
+        )));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::BadArgs(String::from("n2 must be power of 2")));
     }
 
     let outlen = 2 * p.len();
@@ -191,17 +200,17 @@ fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<Ar
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG1>, String> {
+fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "n2 must be equal or less than kzg settings max width",
-        ));
+        )));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::BadArgs(String::from("n2 must be power of 2")));
     }
 
     let n = n2 / 2;
@@ -235,7 +244,7 @@ fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ArkG
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, String> {
+fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, Error> {
     toeplitz_coeffs_stride(p, 0, 1, outlen)
 }
 
@@ -244,18 +253,22 @@ fn toeplitz_coeffs_stride(
     offset: usize,
     stride: usize,
     outlen: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     let n = poly.len();
 
     if stride == 0 {
-        return Err(String::from("stride must be greater than 0"));
+        return Err(Error::BadArgs(String::from(
+            "stride must be greater than 0",
+        )));
     }
 
     let k = n / stride;
     let k2 = k * 2;
 
     if outlen < k2 {
-        return Err(String::from("outlen must be equal or greater than k2"));
+        return Err(Error::BadArgs(String::from(
+            "outlen must be equal or greater than k2",
+        )));
     }
 
     let mut out = PolyData::new(outlen);
@@ -273,7 +286,7 @@ fn toeplitz_coeffs_stride(
     Ok(out)
 }
 
-fn toeplitz_part_1(x: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, String> {
+fn toeplitz_part_1(x: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, Error> {
     let n = x.len();
     let n2 = n * 2;
 
@@ -291,7 +304,7 @@ fn toeplitz_part_2(
     toeplitz_coeffs: &PolyData,
     x_ext_fft: &[ArkG1],
     fs: &FFTSettings,
-) -> Result<Vec<ArkG1>, String> {
+) -> Result<Vec<ArkG1>, Error> {
     let toeplitz_coeffs_fft = fs.fft_fr(&toeplitz_coeffs.coeffs, false).unwrap();
 
     #[cfg(feature = "parallel")]
@@ -313,7 +326,7 @@ fn toeplitz_part_2(
     }
 }
 
-fn toeplitz_part_3(h_ext_fft: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, String> {
+fn toeplitz_part_3(h_ext_fft: &[ArkG1], fs: &FFTSettings) -> Result<Vec<ArkG1>, Error> {
     let n = h_ext_fft.len() / 2;
     let mut out = fs.fft_g1(h_ext_fft, true).unwrap();
 
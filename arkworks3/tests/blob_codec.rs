@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::blob_codec::{
+        blob_codec_multiple_blobs_test, blob_codec_rejects_invalid_blob_sequences_test,
+        blob_codec_rejects_invalid_blobs_test, blob_codec_roundtrip_test,
+    };
+    use rust_kzg_arkworks3::kzg_types::ArkFr;
+
+    #[test]
+    pub fn blob_codec_roundtrip_test_() {
+        blob_codec_roundtrip_test::<ArkFr>();
+    }
+
+    #[test]
+    pub fn blob_codec_multiple_blobs_test_() {
+        blob_codec_multiple_blobs_test::<ArkFr>();
+    }
+
+    #[test]
+    pub fn blob_codec_rejects_invalid_blob_sequences_test_() {
+        blob_codec_rejects_invalid_blob_sequences_test();
+    }
+
+    #[test]
+    pub fn blob_codec_rejects_invalid_blobs_test_() {
+        blob_codec_rejects_invalid_blobs_test();
+    }
+}
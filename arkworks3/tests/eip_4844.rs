@@ -3,25 +3,40 @@ mod tests {
     use kzg::eip_4844::{
         blob_to_kzg_commitment_rust, blob_to_polynomial, bytes_to_blob,
         compute_blob_kzg_proof_rust, compute_kzg_proof_rust, compute_powers,
-        evaluate_polynomial_in_evaluation_form, verify_blob_kzg_proof_batch_rust,
+        evaluate_polynomial_in_evaluation_form, kzg_to_versioned_hash_rust,
+        load_trusted_setup_rust, point_evaluation_precompile_rust,
+        verify_blob_kzg_proof_batch_failures_rust, verify_blob_kzg_proof_batch_rust,
         verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
     };
+    use kzg::eip_7594::{
+        compute_cells_and_kzg_proofs_rust, load_cell_kzg_settings_rust,
+        recover_cells_and_kzg_proofs_rust, verify_cell_kzg_proof_batch_rust,
+    };
     use kzg::Fr;
     use kzg_bench::tests::eip_4844::{
-        blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        blob_committer_test, blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        commit_coeff_and_eval_form_test, compute_and_verify_blob_kzg_proof_custom_width_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_kzg_proof_round_trip_test,
         compute_and_verify_kzg_proof_within_domain_test, compute_kzg_proof_test,
-        compute_powers_test, test_vectors_blob_to_kzg_commitment,
-        test_vectors_compute_blob_kzg_proof, test_vectors_compute_kzg_proof,
-        test_vectors_verify_blob_kzg_proof, test_vectors_verify_blob_kzg_proof_batch,
-        test_vectors_verify_kzg_proof, verify_kzg_proof_batch_fails_with_incorrect_proof_test,
-        verify_kzg_proof_batch_test,
+        compute_powers_test, dyn_kzg_test, kzg_context_test, kzg_to_versioned_hash_test,
+        load_trusted_setup_formats_test, load_trusted_setup_malformed_json_test,
+        pairing_accumulator_test, point_evaluation_precompile_test,
+        test_vectors_blob_to_kzg_commitment, test_vectors_compute_blob_kzg_proof,
+        test_vectors_compute_kzg_proof, test_vectors_verify_blob_kzg_proof,
+        test_vectors_verify_blob_kzg_proof_batch, test_vectors_verify_kzg_proof,
+        thread_pool_config_test, verify_blob_kzg_proof_batch_failures_test,
+        verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
     };
     use rust_kzg_arkworks3::consts::SCALE2_ROOT_OF_UNITY;
+    #[cfg(feature = "embedded-mainnet-setup")]
+    use rust_kzg_arkworks3::eip_4844::load_mainnet_trusted_setup;
     use rust_kzg_arkworks3::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_arkworks3::eip_4844::ArkBackend;
+    use rust_kzg_arkworks3::fk20_proofs::KzgFK20MultiSettings;
+    use rust_kzg_arkworks3::kzg_proofs::generate_trusted_setup;
     use rust_kzg_arkworks3::kzg_proofs::{expand_root_of_unity, FFTSettings, KZGSettings};
     use rust_kzg_arkworks3::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
     use rust_kzg_arkworks3::utils::PolyData;
@@ -72,6 +87,41 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn dyn_kzg_test_() {
+        dyn_kzg_test::<ArkBackend>(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn kzg_context_test_() {
+        kzg_context_test::<ArkBackend>();
+    }
+
+    #[test]
+    pub fn kzg_to_versioned_hash_test_() {
+        kzg_to_versioned_hash_test(&kzg_to_versioned_hash_rust);
+    }
+
+    #[test]
+    pub fn point_evaluation_precompile_test_() {
+        point_evaluation_precompile_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &point_evaluation_precompile_rust,
+        );
+    }
+
     #[test]
     pub fn compute_and_verify_kzg_proof_round_trip_test_() {
         compute_and_verify_kzg_proof_round_trip_test::<
@@ -178,6 +228,106 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_and_verify_blob_kzg_proof_custom_width_test_() {
+        compute_and_verify_blob_kzg_proof_custom_width_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            KzgFK20MultiSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &generate_trusted_setup,
+            &load_trusted_setup_rust,
+            &blob_to_kzg_commitment_rust,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_rust,
+            &load_cell_kzg_settings_rust,
+            &compute_cells_and_kzg_proofs_rust,
+            &recover_cells_and_kzg_proofs_rust,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_formats_test_() {
+        load_trusted_setup_formats_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_malformed_json_test_() {
+        load_trusted_setup_malformed_json_test();
+    }
+
+    #[test]
+    pub fn blob_committer_test_() {
+        blob_committer_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    pub fn commit_coeff_and_eval_form_test_() {
+        commit_coeff_and_eval_form_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[cfg(feature = "embedded-mainnet-setup")]
+    #[test]
+    pub fn load_mainnet_trusted_setup_test_() {
+        kzg_bench::tests::eip_4844::load_mainnet_trusted_setup_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &load_mainnet_trusted_setup,
+        );
+    }
+
     #[test]
     pub fn verify_kzg_proof_batch_test_() {
         verify_kzg_proof_batch_test::<
@@ -218,6 +368,66 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn verify_blob_kzg_proof_batch_failures_test_() {
+        verify_blob_kzg_proof_batch_failures_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_failures_rust,
+        );
+    }
+
+    #[test]
+    pub fn thread_pool_config_test_() {
+        thread_pool_config_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn pairing_accumulator_test_() {
+        pairing_accumulator_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &compute_kzg_proof_rust,
+        );
+    }
+
     #[test]
     pub fn test_vectors_blob_to_kzg_commitment_() {
         test_vectors_blob_to_kzg_commitment::<
@@ -1,18 +1,22 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use kzg_bench::benches::lincomb::bench_g1_lincomb;
+use kzg_bench::benches::lincomb::{bench_g1_lincomb, bench_g1_lincomb_blob};
 use rust_kzg_blst::kzg_proofs::g1_linear_combination;
 use rust_kzg_blst::types::fp::FsFp;
 use rust_kzg_blst::types::fr::FsFr;
-use rust_kzg_blst::types::g1::{FsG1, FsG1Affine};
+use rust_kzg_blst::types::g1::{FsG1, FsG1Affine, FsG1ProjAddAffine};
 
 fn bench_g1_lincomb_(c: &mut Criterion) {
     bench_g1_lincomb::<FsFr, FsG1, FsFp, FsG1Affine>(c, &g1_linear_combination);
 }
 
+fn bench_g1_lincomb_blob_(c: &mut Criterion) {
+    bench_g1_lincomb_blob::<FsFr, FsG1, FsFp, FsG1Affine, FsG1ProjAddAffine>(c);
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(100);
-    targets = bench_g1_lincomb_
+    targets = bench_g1_lincomb_, bench_g1_lincomb_blob_
 }
 
 criterion_main!(benches);
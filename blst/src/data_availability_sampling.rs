@@ -4,6 +4,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 
+use kzg::Error;
 use kzg::{Fr, DAS};
 
 use crate::types::fft_settings::FsFFTSettings;
@@ -75,15 +76,17 @@ impl DAS<FsFr> for FsFFTSettings {
     /// Polynomial extension for data availability sampling. Given values of even indices, produce values of odd indices.
     /// FFTSettings must hold at least 2 times the roots of provided evens.
     /// The resulting odd indices make the right half of the coefficients of the inverse FFT of the combined indices zero.
-    fn das_fft_extension(&self, evens: &[FsFr]) -> Result<Vec<FsFr>, String> {
+    fn das_fft_extension(&self, evens: &[FsFr]) -> Result<Vec<FsFr>, Error> {
         if evens.is_empty() {
-            return Err(String::from("A non-zero list ab expected"));
+            return Err(Error::BadArgs(String::from("A non-zero list ab expected")));
         } else if !evens.len().is_power_of_two() {
-            return Err(String::from("A list with power-of-two length expected"));
+            return Err(Error::BadArgs(String::from(
+                "A list with power-of-two length expected",
+            )));
         } else if evens.len() * 2 > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Supplied list is longer than the available max width",
-            ));
+            )));
         }
 
         // In case more roots are provided with fft_settings, use a larger stride
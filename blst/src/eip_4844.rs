@@ -1,16 +1,17 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
+#[cfg(feature = "std")]
 use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::ptr::null_mut;
-use kzg::common_utils::reverse_bit_order;
-use kzg::eip_4844::{
-    blob_to_kzg_commitment_rust, compute_blob_kzg_proof_rust, compute_kzg_proof_rust,
-    load_trusted_setup_rust, verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_rust,
-    verify_kzg_proof_rust, PrecomputationTableManager,
-};
-use kzg::{cfg_into_iter, Fr, G1};
+use kzg::backend::Backend;
+use kzg::c_api::{self, CKZGHandles};
+use kzg::eip_4844::kzg_to_versioned_hash_rust;
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 #[cfg(feature = "std")]
 use libc::FILE;
 #[cfg(feature = "std")]
@@ -20,88 +21,105 @@ use std::io::Read;
 
 use blst::{blst_fr, blst_p1, blst_p2};
 
+use kzg::eip_4844::load_trusted_setup_values_rust;
 #[cfg(feature = "std")]
-use kzg::eip_4844::load_trusted_setup_string;
+use kzg::eip_4844::{load_trusted_setup_string, FIELD_ELEMENTS_PER_BLOB};
+#[cfg(feature = "std")]
+use kzg::msm::precompute::precompute_with_env_cache;
+use kzg::trusted_setup::load_trusted_setup_points;
+#[cfg(feature = "embedded-mainnet-setup")]
+use kzg::trusted_setup::MAINNET_TRUSTED_SETUP;
 
 use kzg::eip_4844::{
-    Blob, Bytes32, Bytes48, CKZGSettings, KZGCommitment, KZGProof, BYTES_PER_FIELD_ELEMENT,
-    BYTES_PER_G1, BYTES_PER_G2, C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK,
-    FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_NUM_G1_POINTS, TRUSTED_SETUP_NUM_G2_POINTS,
+    Blob, Bytes32, Bytes48, CKZGSettings, KZGCommitment, KZGProof, BYTES_PER_G1, BYTES_PER_G2,
+    C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK, TRUSTED_SETUP_NUM_G2_POINTS,
 };
 
 use crate::types::fft_settings::FsFFTSettings;
+use crate::types::fk20_multi_settings::FsFK20MultiSettings;
 use crate::types::fp::FsFp;
 use crate::types::fr::FsFr;
 use crate::types::g1::{FsG1, FsG1Affine};
 
 use crate::types::g2::FsG2;
 use crate::types::kzg_settings::FsKZGSettings;
+use crate::types::poly::FsPoly;
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
-static mut PRECOMPUTATION_TABLES: PrecomputationTableManager<FsFr, FsG1, FsFp, FsG1Affine> =
-    PrecomputationTableManager::new();
+static C_KZG_HANDLES: CKZGHandles<BlstBackend> = CKZGHandles::new();
 
 #[cfg(feature = "std")]
-pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<FsKZGSettings, String> {
-    let mut file = File::open(filepath).map_err(|_| "Unable to open file".to_string())?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)
-        .map_err(|_| "Unable to read file".to_string())?;
-
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents)?;
-    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice())
+pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<FsKZGSettings, Error> {
+    let mut file = File::open(filepath)
+        .map_err(|_| Error::InvalidTrustedSetup("Unable to open file".to_string()))?;
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents)
+        .map_err(|_| Error::InvalidTrustedSetup("Unable to read file".to_string()))?;
+
+    load_trusted_setup_contents_rust(&contents)
 }
 
-fn fft_settings_to_rust(c_settings: *const CKZGSettings) -> Result<FsFFTSettings, String> {
-    let settings = unsafe { &*c_settings };
+/// Load mainnet trusted setup, which is embedded into the binary. Its precomputation table is built in memory,
+/// and never cached on disk.
+#[cfg(feature = "embedded-mainnet-setup")]
+pub fn load_mainnet_trusted_setup() -> Result<FsKZGSettings, Error> {
+    use kzg::KZGSettings as _;
+
+    let (g1_values, g2_values) =
+        load_trusted_setup_points::<FsG1, FsFp, FsG2>(MAINNET_TRUSTED_SETUP)?;
+    let (secret_g1, secret_g2, max_scale, fs) =
+        load_trusted_setup_values_rust::<FsFr, FsG1, FsG2, FsFFTSettings, FsFp>(
+            g1_values, g2_values,
+        )?;
+    FsKZGSettings::new_from_lagrange(&secret_g1, &secret_g2, max_scale, &fs)
+}
 
-    let roots_of_unity = unsafe {
-        core::slice::from_raw_parts(settings.roots_of_unity, settings.max_width as usize)
-            .iter()
-            .map(|r| FsFr(*r))
-            .collect::<Vec<FsFr>>()
-    };
-    let mut expanded_roots_of_unity = roots_of_unity.clone();
-    reverse_bit_order(&mut expanded_roots_of_unity)?;
-    expanded_roots_of_unity.push(FsFr::one());
-    let mut reverse_roots_of_unity = expanded_roots_of_unity.clone();
-    reverse_roots_of_unity.reverse();
-
-    let mut first_root = expanded_roots_of_unity[1];
-    let first_root_arr = [first_root; 1];
-    first_root = first_root_arr[0];
-
-    Ok(FsFFTSettings {
-        max_width: settings.max_width as usize,
-        root_of_unity: first_root,
-        expanded_roots_of_unity,
-        reverse_roots_of_unity,
-        roots_of_unity,
-    })
+/// Load trusted setup in any format supported by [`load_trusted_setup_points`]
+pub fn load_trusted_setup_contents_rust(contents: &[u8]) -> Result<FsKZGSettings, Error> {
+    let (g1_values, g2_values) = load_trusted_setup_points::<FsG1, FsFp, FsG2>(contents)?;
+    let (secret_g1, secret_g2, _max_scale, fs) =
+        load_trusted_setup_values_rust::<FsFr, FsG1, FsG2, FsFFTSettings, FsFp>(
+            g1_values, g2_values,
+        )?;
+
+    #[cfg(any(feature = "sppark", not(feature = "std")))]
+    {
+        use kzg::KZGSettings as _;
+        FsKZGSettings::new_from_lagrange(&secret_g1, &secret_g2, _max_scale, &fs)
+    }
+
+    #[cfg(all(not(feature = "sppark"), feature = "std"))]
+    {
+        // Table is read from disk instead of being built, if RUST_KZG_PRECOMPUTATION_DIR enables caching
+        let precomputation = Some(Arc::new(precompute_with_env_cache(&secret_g1)?));
+
+        FsKZGSettings::from_lagrange_brp(secret_g1, secret_g2, fs, precomputation)
+    }
 }
 
-fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<FsKZGSettings, String> {
-    let secret_g1 = unsafe {
-        core::slice::from_raw_parts(c_settings.g1_values, TRUSTED_SETUP_NUM_G1_POINTS)
-            .iter()
-            .map(|r| FsG1(*r))
-            .collect::<Vec<FsG1>>()
-    };
-    Ok(FsKZGSettings {
-        fs: fft_settings_to_rust(c_settings)?,
-        secret_g1,
-        secret_g2: unsafe {
-            core::slice::from_raw_parts(c_settings.g2_values, TRUSTED_SETUP_NUM_G2_POINTS)
-                .iter()
-                .map(|r| FsG2(*r))
-                .collect::<Vec<FsG2>>()
-        },
-        precomputation: unsafe { PRECOMPUTATION_TABLES.get_precomputation(c_settings) },
-    })
+/// Types of blst backend, to be used with [`KzgContext`](kzg::backend::KzgContext)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlstBackend;
+
+impl Backend for BlstBackend {
+    const NAME: &'static str = "blst";
+
+    type Fr = FsFr;
+    type G1 = FsG1;
+    type G2 = FsG2;
+    type G1Fp = FsFp;
+    type G1Affine = FsG1Affine;
+    type Poly = FsPoly;
+    type FFTSettings = FsFFTSettings;
+    type KZGSettings = FsKZGSettings;
+    type FK20MultiSettings = FsFK20MultiSettings;
+
+    fn load_trusted_setup(contents: &[u8]) -> Result<Self::KZGSettings, Error> {
+        load_trusted_setup_contents_rust(contents)
+    }
 }
 
+kzg::export_c_api!(BlstBackend);
+
 fn kzg_settings_to_c(rust_settings: &FsKZGSettings) -> CKZGSettings {
     let g1_val = rust_settings
         .secret_g1
@@ -135,22 +153,6 @@ fn kzg_settings_to_c(rust_settings: &FsKZGSettings) -> CKZGSettings {
     }
 }
 
-unsafe fn deserialize_blob(blob: *const Blob) -> Result<Vec<FsFr>, C_KZG_RET> {
-    (*blob)
-        .bytes
-        .chunks(BYTES_PER_FIELD_ELEMENT)
-        .map(|chunk| {
-            let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
-            bytes.copy_from_slice(chunk);
-            if let Ok(result) = FsFr::from_bytes(&bytes) {
-                Ok(result)
-            } else {
-                Err(C_KZG_RET_BADARGS)
-            }
-        })
-        .collect::<Result<Vec<FsFr>, C_KZG_RET>>()
-}
-
 macro_rules! handle_ckzg_badargs {
     ($x: expr) => {
         match $x {
@@ -160,6 +162,24 @@ macro_rules! handle_ckzg_badargs {
     };
 }
 
+macro_rules! handle_ckzg_error {
+    ($x: expr) => {
+        match $x {
+            Ok(value) => value,
+            Err(err) => return err.c_kzg_ret(),
+        }
+    };
+}
+
+/// Loads settings into a new handle, and returns a copy of their points in the layout of c-kzg-4844
+fn load_ckzg_settings(g1_bytes: &[u8], g2_bytes: &[u8]) -> Result<CKZGSettings, Error> {
+    let handle = c_api::load_trusted_setup_handle::<BlstBackend>(g1_bytes, g2_bytes)?;
+    let c_settings = kzg_settings_to_c(handle.settings());
+    C_KZG_HANDLES.insert(c_settings.g1_values as usize, handle);
+
+    Ok(c_settings)
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn blob_to_kzg_commitment(
@@ -167,17 +187,9 @@ pub unsafe extern "C" fn blob_to_kzg_commitment(
     blob: *const Blob,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    if TRUSTED_SETUP_NUM_G1_POINTS == 0 {
-        // FIXME: load_trusted_setup should set this value, but if not, it fails
-        TRUSTED_SETUP_NUM_G1_POINTS = FIELD_ELEMENTS_PER_BLOB
-    };
-
-    let deserialized_blob = handle_ckzg_badargs!(deserialize_blob(blob));
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-    let tmp = handle_ckzg_badargs!(blob_to_kzg_commitment_rust(&deserialized_blob, &settings));
-
-    (*out).bytes = tmp.to_bytes();
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::blob_to_kzg_commitment(out, blob, handle)
+    })
 }
 
 /// # Safety
@@ -189,16 +201,18 @@ pub unsafe extern "C" fn load_trusted_setup(
     g2_bytes: *const u8,
     n2: usize,
 ) -> C_KZG_RET {
-    let g1_bytes = core::slice::from_raw_parts(g1_bytes, n1 * BYTES_PER_G1);
-    let g2_bytes = core::slice::from_raw_parts(g2_bytes, n2 * BYTES_PER_G2);
-    TRUSTED_SETUP_NUM_G1_POINTS = g1_bytes.len() / BYTES_PER_G1;
-    let mut settings = handle_ckzg_badargs!(load_trusted_setup_rust(g1_bytes, g2_bytes));
-
-    let c_settings = kzg_settings_to_c(&settings);
+    if out.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
 
-    PRECOMPUTATION_TABLES.save_precomputation(settings.precomputation.take(), &c_settings);
+    let (Some(g1_bytes), Some(g2_bytes)) = (
+        c_api::points_from_raw(g1_bytes, n1, BYTES_PER_G1),
+        c_api::points_from_raw(g2_bytes, n2, BYTES_PER_G2),
+    ) else {
+        return C_KZG_RET_BADARGS;
+    };
 
-    *out = c_settings;
+    *out = handle_ckzg_error!(load_ckzg_settings(g1_bytes, g2_bytes));
     C_KZG_RET_OK
 }
 
@@ -209,28 +223,22 @@ pub unsafe extern "C" fn load_trusted_setup_file(
     out: *mut CKZGSettings,
     in_: *mut FILE,
 ) -> C_KZG_RET {
+    if out.is_null() || in_.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
     let mut buf = vec![0u8; 1024 * 1024];
     let len: usize = libc::fread(buf.as_mut_ptr() as *mut libc::c_void, 1, buf.len(), in_);
     let s = handle_ckzg_badargs!(String::from_utf8(buf[..len].to_vec()));
-    let (g1_bytes, g2_bytes) = handle_ckzg_badargs!(load_trusted_setup_string(&s));
-    TRUSTED_SETUP_NUM_G1_POINTS = g1_bytes.len() / BYTES_PER_G1;
-    if TRUSTED_SETUP_NUM_G1_POINTS != FIELD_ELEMENTS_PER_BLOB {
+    let (g1_bytes, g2_bytes) = handle_ckzg_error!(load_trusted_setup_string(&s));
+    if g1_bytes.len() / BYTES_PER_G1 != FIELD_ELEMENTS_PER_BLOB {
         // Helps pass the Java test "shouldThrowExceptionOnIncorrectTrustedSetupFromFile",
         // as well as 5 others that pass only if this one passes (likely because Java doesn't
         // deallocate its KZGSettings pointer when no exception is thrown).
         return C_KZG_RET_BADARGS;
     }
-    let mut settings = handle_ckzg_badargs!(load_trusted_setup_rust(
-        g1_bytes.as_slice(),
-        g2_bytes.as_slice()
-    ));
-
-    let c_settings = kzg_settings_to_c(&settings);
-
-    PRECOMPUTATION_TABLES.save_precomputation(settings.precomputation.take(), &c_settings);
-
-    *out = c_settings;
 
+    *out = handle_ckzg_error!(load_ckzg_settings(&g1_bytes, &g2_bytes));
     C_KZG_RET_OK
 }
 
@@ -242,21 +250,9 @@ pub unsafe extern "C" fn compute_blob_kzg_proof(
     commitment_bytes: *const Bytes48,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = match deserialize_blob(blob) {
-        Ok(value) => value,
-        Err(err) => return err,
-    };
-
-    let commitment_g1 = handle_ckzg_badargs!(FsG1::from_bytes(&(*commitment_bytes).bytes));
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-    let proof = handle_ckzg_badargs!(compute_blob_kzg_proof_rust(
-        &deserialized_blob,
-        &commitment_g1,
-        &settings
-    ));
-
-    (*out).bytes = proof.to_bytes();
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::compute_blob_kzg_proof(out, blob, commitment_bytes, handle)
+    })
 }
 
 /// # Safety
@@ -266,7 +262,7 @@ pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
         return;
     }
 
-    PRECOMPUTATION_TABLES.remove_precomputation(&*s);
+    C_KZG_HANDLES.remove((*s).g1_values as usize);
 
     if !(*s).roots_of_unity.is_null() {
         let max_width = (*s).max_width as usize;
@@ -281,7 +277,7 @@ pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
     if !(*s).g1_values.is_null() {
         let g1 = Box::from_raw(core::slice::from_raw_parts_mut(
             (*s).g1_values,
-            TRUSTED_SETUP_NUM_G1_POINTS,
+            (*s).max_width as usize,
         ));
         drop(g1);
         (*s).g1_values = null_mut();
@@ -299,6 +295,28 @@ pub unsafe extern "C" fn free_trusted_setup(s: *mut CKZGSettings) {
     (*s).max_width = 0;
 }
 
+/// Sets number of threads, which functions called with `s` split their work between. Zero means the global
+/// thread pool. Only settings loaded by [`load_trusted_setup`] or [`load_trusted_setup_file`] are accepted.
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn set_trusted_setup_num_threads(
+    s: *const CKZGSettings,
+    num_threads: usize,
+) -> C_KZG_RET {
+    if s.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
+    let thread_pool = handle_ckzg_badargs!(ThreadPoolConfig::with_num_threads(num_threads));
+    if !C_KZG_HANDLES.set_thread_pool((*s).g1_values as usize, thread_pool) {
+        // Settings were not loaded by this library, so there is no handle to keep the thread pool in
+        return C_KZG_RET_BADARGS;
+    }
+
+    C_KZG_RET_OK
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn verify_kzg_proof(
@@ -309,25 +327,37 @@ pub unsafe extern "C" fn verify_kzg_proof(
     proof_bytes: *const Bytes48,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let frz = handle_ckzg_badargs!(FsFr::from_bytes(&(*z_bytes).bytes));
-    let fry = handle_ckzg_badargs!(FsFr::from_bytes(&(*y_bytes).bytes));
-    let g1commitment = handle_ckzg_badargs!(FsG1::from_bytes(&(*commitment_bytes).bytes));
-    let g1proof = handle_ckzg_badargs!(FsG1::from_bytes(&(*proof_bytes).bytes));
-
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-
-    let result = handle_ckzg_badargs!(verify_kzg_proof_rust(
-        &g1commitment,
-        &frz,
-        &fry,
-        &g1proof,
-        &settings
-    ));
-
-    *ok = result;
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_kzg_proof(ok, commitment_bytes, z_bytes, y_bytes, proof_bytes, handle)
+    })
+}
+
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn kzg_to_versioned_hash(
+    out: *mut Bytes32,
+    commitment: *const KZGCommitment,
+) -> C_KZG_RET {
+    (*out).bytes = kzg_to_versioned_hash_rust(&(*commitment).bytes);
     C_KZG_RET_OK
 }
 
+/// Executes the point evaluation precompile. On success, writes
+/// `POINT_EVALUATION_OUTPUT_LENGTH` bytes to `out`
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn point_evaluation_precompile(
+    out: *mut u8,
+    input: *const u8,
+    input_len: usize,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::point_evaluation_precompile(out, input, input_len, handle)
+    })
+}
+
 /// # Safety
 #[no_mangle]
 pub unsafe extern "C" fn verify_blob_kzg_proof(
@@ -337,20 +367,9 @@ pub unsafe extern "C" fn verify_blob_kzg_proof(
     proof_bytes: *const Bytes48,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = handle_ckzg_badargs!(deserialize_blob(blob));
-    let commitment_g1 = handle_ckzg_badargs!(FsG1::from_bytes(&(*commitment_bytes).bytes));
-    let proof_g1 = handle_ckzg_badargs!(FsG1::from_bytes(&(*proof_bytes).bytes));
-    let settings = handle_ckzg_badargs!(kzg_settings_to_rust(s));
-
-    let result = handle_ckzg_badargs!(verify_blob_kzg_proof_rust(
-        &deserialized_blob,
-        &commitment_g1,
-        &proof_g1,
-        &settings,
-    ));
-
-    *ok = result;
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_blob_kzg_proof(ok, blob, commitment_bytes, proof_bytes, handle)
+    })
 }
 
 /// # Safety
@@ -363,45 +382,33 @@ pub unsafe extern "C" fn verify_blob_kzg_proof_batch(
     n: usize,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let raw_blobs = core::slice::from_raw_parts(blobs, n);
-    let raw_commitments = core::slice::from_raw_parts(commitments_bytes, n);
-    let raw_proofs = core::slice::from_raw_parts(proofs_bytes, n);
-
-    let deserialized_blobs: Result<Vec<Vec<FsFr>>, C_KZG_RET> = cfg_into_iter!(raw_blobs)
-        .map(|raw_blob| deserialize_blob(raw_blob).map_err(|_| C_KZG_RET_BADARGS))
-        .collect();
-
-    let commitments_g1: Result<Vec<FsG1>, C_KZG_RET> = cfg_into_iter!(raw_commitments)
-        .map(|raw_commitment| {
-            FsG1::from_bytes(&raw_commitment.bytes).map_err(|_| C_KZG_RET_BADARGS)
-        })
-        .collect();
-
-    let proofs_g1: Result<Vec<FsG1>, C_KZG_RET> = cfg_into_iter!(raw_proofs)
-        .map(|raw_proof| FsG1::from_bytes(&raw_proof.bytes).map_err(|_| C_KZG_RET_BADARGS))
-        .collect();
-
-    if let (Ok(blobs), Ok(commitments), Ok(proofs)) =
-        (deserialized_blobs, commitments_g1, proofs_g1)
-    {
-        let settings = match kzg_settings_to_rust(s) {
-            Ok(value) => value,
-            Err(_) => return C_KZG_RET_BADARGS,
-        };
-
-        let result =
-            verify_blob_kzg_proof_batch_rust(blobs.as_slice(), &commitments, &proofs, &settings);
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_blob_kzg_proof_batch(ok, blobs, commitments_bytes, proofs_bytes, n, handle)
+    })
+}
 
-        if let Ok(result) = result {
-            *ok = result;
-            C_KZG_RET_OK
-        } else {
-            C_KZG_RET_BADARGS
-        }
-    } else {
-        *ok = false;
-        C_KZG_RET_BADARGS
-    }
+/// Verifies a batch of blob proofs, setting `results[i]` to whether proof `i` is valid
+///
+/// # Safety
+#[no_mangle]
+pub unsafe extern "C" fn verify_blob_kzg_proof_batch_per_item(
+    results: *mut bool,
+    blobs: *const Blob,
+    commitments_bytes: *const Bytes48,
+    proofs_bytes: *const Bytes48,
+    n: usize,
+    s: &CKZGSettings,
+) -> C_KZG_RET {
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::verify_blob_kzg_proof_batch_per_item(
+            results,
+            blobs,
+            commitments_bytes,
+            proofs_bytes,
+            n,
+            handle,
+        )
+    })
 }
 
 /// # Safety
@@ -413,65 +420,65 @@ pub unsafe extern "C" fn compute_kzg_proof(
     z_bytes: *const Bytes32,
     s: &CKZGSettings,
 ) -> C_KZG_RET {
-    let deserialized_blob = match deserialize_blob(blob) {
-        Ok(value) => value,
-        Err(err) => return err,
-    };
-
-    let frz = match FsFr::from_bytes(&(*z_bytes).bytes) {
-        Ok(value) => value,
-        Err(_) => return C_KZG_RET_BADARGS,
-    };
-
-    let settings = match kzg_settings_to_rust(s) {
-        Ok(value) => value,
-        Err(_) => return C_KZG_RET_BADARGS,
-    };
-
-    let (proof_out_tmp, fry_tmp) = match compute_kzg_proof_rust(&deserialized_blob, &frz, &settings)
-    {
-        Ok(value) => value,
-        Err(_) => return C_KZG_RET_BADARGS,
-    };
-
-    (*proof_out).bytes = proof_out_tmp.to_bytes();
-    (*y_out).bytes = fry_tmp.to_bytes();
-    C_KZG_RET_OK
+    C_KZG_HANDLES.call(s.g1_values as usize, |handle| {
+        c_api::compute_kzg_proof(proof_out, y_out, blob, z_bytes, handle)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::utils::get_trusted_setup_path;
 
-    use crate::eip_4844::{kzg_settings_to_c, kzg_settings_to_rust};
+    use kzg::eip_4844::{Blob, KZGCommitment, C_KZG_RET_BADARGS, C_KZG_RET_OK};
+    use kzg::trusted_setup::read_trusted_setup;
+
+    use crate::eip_4844::{
+        blob_to_kzg_commitment, free_trusted_setup, kzg_settings_to_c, load_ckzg_settings,
+        set_trusted_setup_num_threads, C_KZG_HANDLES,
+    };
 
     use super::load_trusted_setup_filename_rust;
 
     #[test]
-    fn kzg_settings_to_rust_check_conversion() {
-        let settings = load_trusted_setup_filename_rust(get_trusted_setup_path().as_str());
-
-        assert!(settings.is_ok());
-
-        let settings = settings.unwrap();
-
-        let converted_settings = kzg_settings_to_rust(&kzg_settings_to_c(&settings)).unwrap();
-
-        assert_eq!(
-            settings.fs.root_of_unity,
-            converted_settings.fs.root_of_unity
-        );
-        assert_eq!(
-            settings.fs.roots_of_unity,
-            converted_settings.fs.roots_of_unity
-        );
-        assert_eq!(
-            settings.fs.expanded_roots_of_unity,
-            converted_settings.fs.expanded_roots_of_unity
-        );
-        assert_eq!(
-            settings.fs.reverse_roots_of_unity,
-            converted_settings.fs.reverse_roots_of_unity
-        );
+    fn foreign_settings_are_rejected() {
+        let contents = std::fs::read(get_trusted_setup_path()).unwrap();
+        let (g1_bytes, g2_bytes) = read_trusted_setup(&contents).unwrap();
+        let mut loaded = load_ckzg_settings(&g1_bytes, &g2_bytes).unwrap();
+
+        // Settings filled in by other code have no handle, and must not get one
+        let settings = load_trusted_setup_filename_rust(get_trusted_setup_path().as_str()).unwrap();
+        let mut foreign = kzg_settings_to_c(&settings);
+
+        let blob = Blob {
+            bytes: [0; kzg::eip_4844::BYTES_PER_BLOB],
+        };
+        let mut commitment = KZGCommitment { bytes: [0; 48] };
+        let mut commitment_with_pool = KZGCommitment { bytes: [1; 48] };
+
+        unsafe {
+            assert_eq!(
+                blob_to_kzg_commitment(&mut commitment, &blob, &loaded),
+                C_KZG_RET_OK
+            );
+            assert_eq!(set_trusted_setup_num_threads(&loaded, 1), C_KZG_RET_OK);
+            assert_eq!(
+                blob_to_kzg_commitment(&mut commitment_with_pool, &blob, &loaded),
+                C_KZG_RET_OK
+            );
+            assert_eq!(commitment.bytes, commitment_with_pool.bytes);
+
+            assert_eq!(
+                blob_to_kzg_commitment(&mut commitment, &blob, &foreign),
+                C_KZG_RET_BADARGS
+            );
+            assert_eq!(
+                set_trusted_setup_num_threads(&foreign, 1),
+                C_KZG_RET_BADARGS
+            );
+            assert!(!C_KZG_HANDLES.contains(foreign.g1_values as usize));
+
+            free_trusted_setup(&mut loaded);
+            free_trusted_setup(&mut foreign);
+        }
     }
 }
@@ -5,6 +5,7 @@ use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
+use kzg::Error;
 use kzg::{FFTFr, Fr};
 
 use crate::types::fft_settings::FsFFTSettings;
@@ -73,21 +74,23 @@ impl FsFFTSettings {
         data: &[FsFr],
         inverse: bool,
         output: &mut [FsFr],
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         if data.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Supplied list is longer than the available max width",
-            ));
+            )));
         }
         if data.len() != output.len() {
-            return Err(format!(
+            return Err(Error::InvalidLength(format!(
                 "Output length {} doesn't match data length {}",
                 data.len(),
                 output.len()
-            ));
+            )));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("A list with power-of-two length expected"));
+            return Err(Error::BadArgs(String::from(
+                "A list with power-of-two length expected",
+            )));
         }
 
         // In case more roots are provided with fft_settings, use a larger stride
@@ -114,7 +117,7 @@ impl FsFFTSettings {
 
 impl FFTFr<FsFr> for FsFFTSettings {
     /// Fast Fourier Transform for finite field elements
-    fn fft_fr(&self, data: &[FsFr], inverse: bool) -> Result<Vec<FsFr>, String> {
+    fn fft_fr(&self, data: &[FsFr], inverse: bool) -> Result<Vec<FsFr>, Error> {
         let mut ret = vec![FsFr::default(); data.len()];
 
         self.fft_fr_output(data, inverse, &mut ret)?;
@@ -3,6 +3,8 @@ extern crate alloc;
 use crate::types::fp::FsFp;
 use crate::types::g1::FsG1;
 use crate::types::{fr::FsFr, g1::FsG1Affine};
+use alloc::vec;
+use alloc::vec::Vec;
 
 use crate::types::g1::FsG1ProjAddAffine;
 
@@ -10,11 +12,12 @@ use kzg::msm::{msm_impls::msm, precompute::PrecomputationTable};
 
 use crate::types::g2::FsG2;
 use blst::{
-    blst_fp12_is_one, blst_p1_affine, blst_p1_cneg, blst_p1_to_affine, blst_p2_affine,
-    blst_p2_to_affine, Pairing,
+    blst_fp12, blst_fp12_finalverify, blst_fp12_is_one, blst_fp6, blst_miller_loop_lines,
+    blst_p1_affine, blst_p1_cneg, blst_p1_is_inf, blst_p1_to_affine, blst_p2_affine,
+    blst_p2_is_inf, blst_p2_to_affine, blst_precompute_lines, Pairing,
 };
 
-use kzg::PairingVerify;
+use kzg::{MultiPairing, PairingVerify};
 
 impl PairingVerify<FsG1, FsG2> for FsG1 {
     fn verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
@@ -22,6 +25,12 @@ impl PairingVerify<FsG1, FsG2> for FsG1 {
     }
 }
 
+impl MultiPairing<FsG1, FsG2> for FsG1 {
+    fn multi_pairing_is_one(ps: &[FsG1], qs: &[FsG2]) -> bool {
+        multi_pairings_verify(ps, qs)
+    }
+}
+
 pub fn g1_linear_combination(
     out: &mut FsG1,
     points: &[FsG1],
@@ -32,6 +41,7 @@ pub fn g1_linear_combination(
     #[cfg(feature = "sppark")]
     {
         use blst::{blst_fr, blst_scalar, blst_scalar_from_fr};
+        use kzg::msm::{msm_impls::msm_with_strategy, strategy::MsmStrategy};
         use kzg::{G1Mul, G1};
 
         if len < 8 {
@@ -44,12 +54,32 @@ pub fn g1_linear_combination(
             return;
         }
 
+        if let Some(precomputation) = precomputation {
+            *out = precomputation.run_msm(len, |strategy| match strategy {
+                MsmStrategy::Accelerator => {
+                    let scalars = unsafe {
+                        alloc::slice::from_raw_parts(scalars.as_ptr() as *const blst_fr, len)
+                    };
+                    FsG1(rust_kzg_blst_sppark::multi_scalar_mult_prepared(
+                        precomputation.accelerator_table().unwrap(),
+                        scalars,
+                    ))
+                }
+                strategy => msm_with_strategy::<FsG1, FsFp, FsG1Affine, FsG1ProjAddAffine, FsFr>(
+                    strategy,
+                    &points[0..len],
+                    &scalars[0..len],
+                    Some(precomputation),
+                ),
+            });
+
+            return;
+        }
+
         let scalars =
             unsafe { alloc::slice::from_raw_parts(scalars.as_ptr() as *const blst_fr, len) };
 
-        let point = if let Some(precomputation) = precomputation {
-            rust_kzg_blst_sppark::multi_scalar_mult_prepared(precomputation.table, scalars)
-        } else {
+        let point = {
             let affines = kzg::msm::msm_impls::batch_convert::<FsG1, FsFp, FsG1Affine>(&points);
             let affines = unsafe {
                 alloc::slice::from_raw_parts(affines.as_ptr() as *const blst_p1_affine, len)
@@ -98,3 +128,86 @@ pub fn pairings_verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
         blst_fp12_is_one(&gt_point)
     }
 }
+
+/// Number of line coefficients blst computes for a single G2 point
+const G2_PREPARED_LINES: usize = 68;
+
+/// Miller loop line coefficients of a G2 point, which allow reusing it in many pairings
+#[derive(Debug, Clone)]
+pub struct FsG2Prepared(Vec<blst_fp6>);
+
+impl FsG2Prepared {
+    pub fn new(q: &FsG2) -> Self {
+        let mut q_affine = blst_p2_affine::default();
+        let mut lines = vec![blst_fp6::default(); G2_PREPARED_LINES];
+        unsafe {
+            blst_p2_to_affine(&mut q_affine, &q.0);
+            blst_precompute_lines(lines.as_mut_ptr(), &q_affine);
+        }
+
+        Self(lines)
+    }
+}
+
+/// Same as [`pairings_verify`], but with prepared G2 points, which must not be at infinity
+pub fn prepared_pairings_verify(
+    a1: &FsG1,
+    a2: &FsG2Prepared,
+    b1: &FsG1,
+    b2: &FsG2Prepared,
+) -> bool {
+    unsafe {
+        // Pairings with the point at infinity are one, and blst's Miller loop expects
+        // both points to be finite
+        let a1_inf = blst_p1_is_inf(&a1.0);
+        let b1_inf = blst_p1_is_inf(&b1.0);
+        if a1_inf || b1_inf {
+            return a1_inf && b1_inf;
+        }
+
+        let mut aa1 = blst_p1_affine::default();
+        let mut bb1 = blst_p1_affine::default();
+        blst_p1_to_affine(&mut aa1, &a1.0);
+        blst_p1_to_affine(&mut bb1, &b1.0);
+
+        let mut gt_a = blst_fp12::default();
+        let mut gt_b = blst_fp12::default();
+        blst_miller_loop_lines(&mut gt_a, a2.0.as_ptr(), &aa1);
+        blst_miller_loop_lines(&mut gt_b, b2.0.as_ptr(), &bb1);
+
+        blst_fp12_finalverify(&gt_a, &gt_b)
+    }
+}
+
+pub fn multi_pairings_verify(ps: &[FsG1], qs: &[FsG2]) -> bool {
+    if ps.len() != qs.len() {
+        return false;
+    }
+
+    let dst = [0u8; 3];
+    let mut pairing_blst = Pairing::new(false, &dst);
+    let mut aggregated = false;
+    for (p, q) in ps.iter().zip(qs) {
+        unsafe {
+            // Pairings with the point at infinity are one, and blst's Miller loop expects
+            // both points to be finite
+            if blst_p1_is_inf(&p.0) || blst_p2_is_inf(&q.0) {
+                continue;
+            }
+
+            let mut p_affine = blst_p1_affine::default();
+            let mut q_affine = blst_p2_affine::default();
+            blst_p1_to_affine(&mut p_affine, &p.0);
+            blst_p2_to_affine(&mut q_affine, &q.0);
+            pairing_blst.raw_aggregate(&q_affine, &p_affine);
+        }
+        aggregated = true;
+    }
+
+    if !aggregated {
+        return true;
+    }
+
+    let gt_point = pairing_blst.as_fp12().final_exp();
+    unsafe { blst_fp12_is_one(&gt_point) }
+}
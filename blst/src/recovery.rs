@@ -3,6 +3,7 @@ extern crate alloc;
 use alloc::string::String;
 use alloc::vec::Vec;
 
+use kzg::Error;
 use kzg::{FFTFr, Fr, PolyRecover, ZeroPoly};
 
 use crate::types::fft_settings::FsFFTSettings;
@@ -62,13 +63,13 @@ impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
     fn recover_poly_coeffs_from_samples(
         samples: &[Option<FsFr>],
         fs: &FsFFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         let len_samples = samples.len();
 
         if !len_samples.is_power_of_two() {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Samples must have a length that is a power of two",
-            ));
+            )));
         }
 
         let mut missing = Vec::with_capacity(len_samples / 2);
@@ -80,9 +81,9 @@ impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
         }
 
         if missing.len() > len_samples / 2 {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Impossible to recover, too many shards are missing",
-            ));
+            )));
         }
 
         // Calculate `Z_r,I`
@@ -174,7 +175,7 @@ impl PolyRecover<FsFr, FsPoly, FsFFTSettings> for FsPoly {
     fn recover_poly_from_samples(
         samples: &[Option<FsFr>],
         fs: &FsFFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         let reconstructed_poly = Self::recover_poly_coeffs_from_samples(samples, fs)?;
 
         // The evaluation polynomial for D(x) is the reconstructed data:
@@ -63,6 +63,10 @@ impl G1Fp for FsFp {
         Self(blst_fp { l: *arr })
     }
 
+    fn to_underlying_arr(&self) -> [u64; 6] {
+        self.0.l
+    }
+
     fn neg_assign(&mut self) {
         unsafe {
             blst::blst_fp_cneg(&mut self.0, &self.0, true);
@@ -1,8 +1,6 @@
 extern crate alloc;
 
 use alloc::format;
-use alloc::string::String;
-use alloc::string::ToString;
 
 use blst::{
     blst_bendian_from_scalar, blst_fr, blst_fr_add, blst_fr_cneg, blst_fr_eucl_inverse,
@@ -11,6 +9,7 @@ use blst::{
     blst_uint64_from_fr,
 };
 use kzg::eip_4844::BYTES_PER_FIELD_ELEMENT;
+use kzg::Error;
 use kzg::Fr;
 use kzg::Scalar256;
 
@@ -47,15 +46,15 @@ impl Fr for FsFr {
         ret
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut bls_scalar = blst_scalar::default();
@@ -63,7 +62,7 @@ impl Fr for FsFr {
                 unsafe {
                     blst_scalar_from_bendian(&mut bls_scalar, bytes.as_ptr());
                     if !blst_scalar_fr_check(&bls_scalar) {
-                        return Err("Invalid scalar".to_string());
+                        return Err(Error::NonCanonicalFieldElement);
                     }
                     blst_fr_from_scalar(&mut fr, &bls_scalar);
                 }
@@ -71,15 +70,15 @@ impl Fr for FsFr {
             })
     }
 
-    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .map(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut bls_scalar = blst_scalar::default();
@@ -92,7 +91,7 @@ impl Fr for FsFr {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -235,7 +234,7 @@ impl Fr for FsFr {
         out
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         let tmp = b.eucl_inverse();
         let out = self.mul(&tmp);
 
@@ -3,7 +3,6 @@ extern crate alloc;
 use core::ptr;
 
 use alloc::format;
-use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
@@ -16,6 +15,7 @@ use blst::{
 use kzg::common_utils::log_2_byte;
 use kzg::eip_4844::BYTES_PER_G1;
 use kzg::msm::precompute::PrecomputationTable;
+use kzg::Error;
 use kzg::G1Affine;
 use kzg::G1GetFp;
 use kzg::G1LinComb;
@@ -57,15 +57,15 @@ impl G1 for FsG1 {
         result.mul(&kzg::Fr::rand())
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G1,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
                 let mut tmp = blst_p1_affine::default();
@@ -73,7 +73,7 @@ impl G1 for FsG1 {
                 unsafe {
                     // The uncompress routine also checks that the point is on the curve
                     if blst_p1_uncompress(&mut tmp, bytes.as_ptr()) != BLST_ERROR::BLST_SUCCESS {
-                        return Err("Failed to uncompress".to_string());
+                        return Err(Error::InvalidPoint("Failed to uncompress".to_string()));
                     }
                     blst_p1_from_affine(&mut g1, &tmp);
                 }
@@ -81,7 +81,7 @@ impl G1 for FsG1 {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
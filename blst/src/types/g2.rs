@@ -1,7 +1,6 @@
 extern crate alloc;
 
 use alloc::format;
-use alloc::string::String;
 use alloc::string::ToString;
 
 use blst::{
@@ -10,6 +9,7 @@ use blst::{
     blst_scalar, blst_scalar_from_fr, BLST_ERROR,
 };
 use kzg::eip_4844::BYTES_PER_G2;
+use kzg::Error;
 #[cfg(feature = "rand")]
 use kzg::Fr;
 use kzg::{G2Mul, G2};
@@ -47,15 +47,15 @@ impl G2 for FsG2 {
         G2_NEGATIVE_GENERATOR
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G2,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
                 let mut tmp = blst_p2_affine::default();
@@ -63,7 +63,7 @@ impl G2 for FsG2 {
                 unsafe {
                     // The uncompress routine also checks that the point is on the curve
                     if blst_p2_uncompress(&mut tmp, bytes.as_ptr()) != BLST_ERROR::BLST_SUCCESS {
-                        return Err("Failed to uncompress".to_string());
+                        return Err(Error::InvalidPoint("Failed to uncompress".to_string()));
                     }
                     blst_p2_from_affine(&mut g2, &tmp);
                 }
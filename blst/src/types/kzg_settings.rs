@@ -4,11 +4,17 @@ use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
+use kzg::eip_4844::{eval_form_to_coeff_form, g1_lagrange_brp_to_monomial};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 use kzg::{FFTFr, FFTSettings, Fr, G1Mul, G2Mul, KZGSettings, Poly, G1, G2};
+use once_cell::sync::OnceCell;
 
 use crate::consts::{G1_GENERATOR, G2_GENERATOR};
-use crate::kzg_proofs::{g1_linear_combination, pairings_verify};
+use crate::kzg_proofs::{
+    g1_linear_combination, pairings_verify, prepared_pairings_verify, FsG2Prepared,
+};
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
 use crate::types::g1::FsG1;
@@ -24,6 +30,38 @@ pub struct FsKZGSettings {
     pub secret_g1: Vec<FsG1>,
     pub secret_g2: Vec<FsG2>,
     pub precomputation: Option<Arc<PrecomputationTable<FsFr, FsG1, FsFp, FsG1Affine>>>,
+    /// G1 points in monomial form, used when `secret_g1` holds points in bit-reversed Lagrange form, as
+    /// loaded from a trusted setup. They are derived when settings are built. `None` means that `secret_g1`
+    /// already holds points in monomial form
+    pub secret_g1_monomial: Option<Vec<FsG1>>,
+    /// Prepared G2 generator and `secret_g2[1]`, derived on first verification
+    pub g2_prepared: OnceCell<(FsG2Prepared, FsG2Prepared)>,
+    /// Thread pool, which parallel work is run on
+    pub thread_pool: ThreadPoolConfig,
+}
+
+impl FsKZGSettings {
+    /// Build settings from G1 points in bit-reversed Lagrange form, as loaded from a trusted setup, deriving
+    /// their monomial form. Fails with [`Error::InvalidTrustedSetup`] if it can't be derived
+    pub fn from_lagrange_brp(
+        secret_g1: Vec<FsG1>,
+        secret_g2: Vec<FsG2>,
+        fs: FsFFTSettings,
+        precomputation: Option<Arc<PrecomputationTable<FsFr, FsG1, FsFp, FsG1Affine>>>,
+    ) -> Result<Self, Error> {
+        let secret_g1_monomial =
+            g1_lagrange_brp_to_monomial::<FsFr, FsG1, FsFFTSettings>(&secret_g1, &fs)?;
+
+        Ok(Self {
+            fs,
+            secret_g1,
+            secret_g2,
+            precomputation,
+            secret_g1_monomial: Some(secret_g1_monomial),
+            g2_prepared: OnceCell::new(),
+            thread_pool: ThreadPoolConfig::default(),
+        })
+    }
 }
 
 impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for FsKZGSettings {
@@ -32,11 +70,14 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         secret_g2: &[FsG2],
         _length: usize,
         fft_settings: &FsFFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         Ok(Self {
             secret_g1: secret_g1.to_vec(),
             secret_g2: secret_g2.to_vec(),
             fs: fft_settings.clone(),
+            secret_g1_monomial: None,
+            g2_prepared: OnceCell::new(),
+            thread_pool: ThreadPoolConfig::default(),
             precomputation: {
                 #[cfg(feature = "sppark")]
                 {
@@ -55,15 +96,32 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
 
                 #[cfg(not(feature = "sppark"))]
                 {
-                    precompute(secret_g1).ok().flatten().map(Arc::new)
+                    precompute(secret_g1).ok().map(Arc::new)
                 }
             },
         })
     }
 
-    fn commit_to_poly(&self, poly: &FsPoly) -> Result<FsG1, String> {
+    fn new_from_lagrange(
+        secret_g1: &[FsG1],
+        secret_g2: &[FsG2],
+        length: usize,
+        fft_settings: &FsFFTSettings,
+    ) -> Result<Self, Error> {
+        let settings = Self::new(secret_g1, secret_g2, length, fft_settings)?;
+        Self::from_lagrange_brp(
+            settings.secret_g1,
+            settings.secret_g2,
+            settings.fs,
+            settings.precomputation,
+        )
+    }
+
+    fn commit_to_poly(&self, poly: &FsPoly) -> Result<FsG1, Error> {
         if poly.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
+            return Err(Error::BadArgs(String::from(
+                "Polynomial is longer than secret g1",
+            )));
         }
 
         let mut out = FsG1::default();
@@ -78,9 +136,42 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         Ok(out)
     }
 
-    fn compute_proof_single(&self, p: &FsPoly, x: &FsFr) -> Result<FsG1, String> {
+    fn commit_coeff_form(&self, p: &FsPoly) -> Result<FsG1, Error> {
+        if self.secret_g1_monomial.is_none() {
+            return self.commit_to_poly(p);
+        }
+
+        let g1_monomial = self.get_g1_monomial();
+        if p.coeffs.len() > g1_monomial.len() {
+            return Err(Error::BadArgs(String::from(
+                "Polynomial is longer than secret g1",
+            )));
+        }
+
+        let mut out = FsG1::default();
+        g1_linear_combination(&mut out, g1_monomial, &p.coeffs, p.coeffs.len(), None);
+
+        Ok(out)
+    }
+
+    fn commit_eval_form(&self, p: &FsPoly) -> Result<FsG1, Error> {
+        if self.secret_g1_monomial.is_none() {
+            let coeffs = eval_form_to_coeff_form(&p.coeffs, &self.fs)?;
+            return self.commit_coeff_form(&FsPoly { coeffs });
+        }
+
+        if p.coeffs.len() != self.secret_g1.len() {
+            return Err(Error::InvalidLength(String::from(
+                "Number of evaluations must match secret g1",
+            )));
+        }
+
+        self.commit_to_poly(p)
+    }
+
+    fn compute_proof_single(&self, p: &FsPoly, x: &FsFr) -> Result<FsG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::BadArgs(String::from("Polynomial must not be empty")));
         }
 
         // `-(x0^n)`, where `n` is `1`
@@ -96,7 +187,7 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
 
         let q = FsPoly { coeffs: out_coeffs };
 
-        let ret = self.commit_to_poly(&q)?;
+        let ret = self.commit_coeff_form(&q)?;
 
         Ok(ret)
     }
@@ -107,27 +198,23 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         proof: &FsG1,
         x: &FsFr,
         y: &FsFr,
-    ) -> Result<bool, String> {
-        let x_g2: FsG2 = G2_GENERATOR.mul(x);
-        let s_minus_x: FsG2 = self.secret_g2[1].sub(&x_g2);
+    ) -> Result<bool, Error> {
+        // e(C - [y], G2) == e(proof, [s - x]G2) is checked as
+        // e(proof, [s]G2) == e(C - [y] + [x]proof, G2), which keeps both G2 points fixed
         let y_g1 = G1_GENERATOR.mul(y);
-        let commitment_minus_y: FsG1 = com.sub(&y_g1);
+        let x_proof = proof.mul(x);
+        let commitment_minus_y_plus_x_proof = com.sub(&y_g1).add_or_dbl(&x_proof);
 
-        Ok(pairings_verify(
-            &commitment_minus_y,
-            &G2_GENERATOR,
-            proof,
-            &s_minus_x,
-        ))
+        Ok(self.verify_pairing_with_g2_secret(proof, &commitment_minus_y_plus_x_proof))
     }
 
-    fn compute_proof_multi(&self, p: &FsPoly, x0: &FsFr, n: usize) -> Result<FsG1, String> {
+    fn compute_proof_multi(&self, p: &FsPoly, x0: &FsFr, n: usize) -> Result<FsG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::BadArgs(String::from("Polynomial must not be empty")));
         }
 
         if !n.is_power_of_two() {
-            return Err(String::from("n must be a power of two"));
+            return Err(Error::BadArgs(String::from("n must be a power of two")));
         }
 
         // Construct x^n - x0^n = (x - x0.w^0)(x - x0.w^1)...(x - x0.w^(n-1))
@@ -154,7 +241,7 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         // let q = p.div(&divisor).unwrap();
         let q = new_polina.div(&divisor)?;
 
-        let ret = self.commit_to_poly(&q)?;
+        let ret = self.commit_coeff_form(&q)?;
 
         Ok(ret)
     }
@@ -166,9 +253,9 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         x: &FsFr,
         ys: &[FsFr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         if !n.is_power_of_two() {
-            return Err(String::from("n is not a power of two"));
+            return Err(Error::BadArgs(String::from("n is not a power of two")));
         }
 
         // Interpolate at a coset.
@@ -192,7 +279,7 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         let xn_minus_yn = self.secret_g2[n].sub(&xn2);
 
         // [interpolation_polynomial(s)]_1
-        let is1 = self.commit_to_poly(&interp).unwrap();
+        let is1 = self.commit_coeff_form(&interp).unwrap();
 
         // [commitment - interpolation_polynomial(s)]_1 = [commit]_1 - [interpolation_polynomial(s)]_1
         let commit_minus_interp = com.sub(&is1);
@@ -218,11 +305,36 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine> for
         &self.secret_g1
     }
 
+    fn get_g1_monomial(&self) -> &[FsG1] {
+        self.secret_g1_monomial
+            .as_deref()
+            .unwrap_or(&self.secret_g1)
+    }
+
     fn get_g2_secret(&self) -> &[FsG2] {
         &self.secret_g2
     }
 
+    fn verify_pairing_with_g2_secret(&self, a: &FsG1, b: &FsG1) -> bool {
+        let (g2_generator, g2_secret) = self.g2_prepared.get_or_init(|| {
+            (
+                FsG2Prepared::new(&G2_GENERATOR),
+                FsG2Prepared::new(&self.secret_g2[1]),
+            )
+        });
+
+        prepared_pairings_verify(a, g2_secret, b, g2_generator)
+    }
+
     fn get_precomputation(&self) -> Option<&PrecomputationTable<FsFr, FsG1, FsFp, FsG1Affine>> {
         self.precomputation.as_ref().map(|v| v.as_ref())
     }
+
+    fn get_thread_pool(&self) -> &ThreadPoolConfig {
+        &self.thread_pool
+    }
+
+    fn set_thread_pool(&mut self, thread_pool: ThreadPoolConfig) {
+        self.thread_pool = thread_pool;
+    }
 }
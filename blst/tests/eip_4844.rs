@@ -3,13 +3,20 @@ mod tests {
     use kzg::eip_4844::{
         blob_to_kzg_commitment_rust, blob_to_polynomial, bytes_to_blob,
         compute_blob_kzg_proof_rust, compute_kzg_proof_rust, compute_powers,
-        evaluate_polynomial_in_evaluation_form, verify_blob_kzg_proof_batch_rust,
+        evaluate_polynomial_in_evaluation_form, kzg_to_versioned_hash_rust,
+        load_trusted_setup_rust, point_evaluation_precompile_rust,
+        verify_blob_kzg_proof_batch_failures_rust, verify_blob_kzg_proof_batch_rust,
         verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
     };
+    use kzg::eip_7594::{
+        compute_cells_and_kzg_proofs_rust, load_cell_kzg_settings_rust,
+        recover_cells_and_kzg_proofs_rust, verify_cell_kzg_proof_batch_rust,
+    };
     use kzg::Fr;
 
     use kzg_bench::tests::eip_4844::{
-        blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        blob_committer_test, blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        commit_coeff_and_eval_form_test, compute_and_verify_blob_kzg_proof_custom_width_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
@@ -18,21 +25,30 @@ mod tests {
         compute_kzg_proof_incorrect_blob_length_test,
         compute_kzg_proof_incorrect_commitments_len_test,
         compute_kzg_proof_incorrect_poly_length_test, compute_kzg_proof_incorrect_proofs_len_test,
-        compute_kzg_proof_test, compute_powers_test, test_vectors_blob_to_kzg_commitment,
+        compute_kzg_proof_test, compute_powers_test, dyn_kzg_test, kzg_context_test,
+        kzg_to_versioned_hash_test, load_trusted_setup_formats_test,
+        load_trusted_setup_malformed_json_test, pairing_accumulator_test,
+        point_evaluation_precompile_test, test_vectors_blob_to_kzg_commitment,
         test_vectors_compute_blob_kzg_proof, test_vectors_compute_kzg_proof,
         test_vectors_verify_blob_kzg_proof, test_vectors_verify_blob_kzg_proof_batch,
-        test_vectors_verify_kzg_proof, validate_batched_input_test,
+        test_vectors_verify_kzg_proof, thread_pool_config_test, validate_batched_input_test,
+        verify_blob_kzg_proof_batch_failures_test,
         verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
     };
     use rust_kzg_blst::consts::SCALE2_ROOT_OF_UNITY;
+    #[cfg(feature = "embedded-mainnet-setup")]
+    use rust_kzg_blst::eip_4844::load_mainnet_trusted_setup;
     use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_blst::eip_4844::BlstBackend;
     use rust_kzg_blst::types::fft_settings::expand_root_of_unity;
+    use rust_kzg_blst::types::fk20_multi_settings::FsFK20MultiSettings;
     use rust_kzg_blst::types::fp::FsFp;
     use rust_kzg_blst::types::g1::FsG1Affine;
     use rust_kzg_blst::types::{
         fft_settings::FsFFTSettings, fr::FsFr, g1::FsG1, g2::FsG2, kzg_settings::FsKZGSettings,
         poly::FsPoly,
     };
+    use rust_kzg_blst::utils::generate_trusted_setup;
 
     #[test]
     pub fn bytes_to_bls_field_test_() {
@@ -80,6 +96,41 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn dyn_kzg_test_() {
+        dyn_kzg_test::<BlstBackend>(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn kzg_context_test_() {
+        kzg_context_test::<BlstBackend>();
+    }
+
+    #[test]
+    pub fn kzg_to_versioned_hash_test_() {
+        kzg_to_versioned_hash_test(&kzg_to_versioned_hash_rust);
+    }
+
+    #[test]
+    pub fn point_evaluation_precompile_test_() {
+        point_evaluation_precompile_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &point_evaluation_precompile_rust,
+        );
+    }
+
     #[test]
     pub fn compute_and_verify_kzg_proof_round_trip_test_() {
         compute_and_verify_kzg_proof_round_trip_test::<
@@ -186,6 +237,106 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_and_verify_blob_kzg_proof_custom_width_test_() {
+        compute_and_verify_blob_kzg_proof_custom_width_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFK20MultiSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &generate_trusted_setup,
+            &load_trusted_setup_rust,
+            &blob_to_kzg_commitment_rust,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_rust,
+            &load_cell_kzg_settings_rust,
+            &compute_cells_and_kzg_proofs_rust,
+            &recover_cells_and_kzg_proofs_rust,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_formats_test_() {
+        load_trusted_setup_formats_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_malformed_json_test_() {
+        load_trusted_setup_malformed_json_test();
+    }
+
+    #[test]
+    pub fn blob_committer_test_() {
+        blob_committer_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    pub fn commit_coeff_and_eval_form_test_() {
+        commit_coeff_and_eval_form_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[cfg(feature = "embedded-mainnet-setup")]
+    #[test]
+    pub fn load_mainnet_trusted_setup_test_() {
+        kzg_bench::tests::eip_4844::load_mainnet_trusted_setup_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &load_mainnet_trusted_setup,
+        );
+    }
+
     #[test]
     pub fn verify_kzg_proof_batch_test_() {
         verify_kzg_proof_batch_test::<
@@ -226,6 +377,66 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn verify_blob_kzg_proof_batch_failures_test_() {
+        verify_blob_kzg_proof_batch_failures_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_failures_rust,
+        );
+    }
+
+    #[test]
+    pub fn thread_pool_config_test_() {
+        thread_pool_config_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn pairing_accumulator_test_() {
+        pairing_accumulator_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &compute_kzg_proof_rust,
+        );
+    }
+
     #[test]
     pub fn test_vectors_blob_to_kzg_commitment_() {
         test_vectors_blob_to_kzg_commitment::<
@@ -7,6 +7,7 @@ mod tests {
     use kzg::G1;
     use kzg_bench::tests::kzg_proofs::{
         commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        verify_pairing_with_g2_secret_works,
     };
 
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
@@ -25,6 +26,20 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_verify_pairing_with_g2_secret() {
+        verify_pairing_with_g2_secret_works::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+
     #[test]
     pub fn test_commit_to_nil_poly() {
         commit_to_nil_poly::<
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::msm::buckets::{
+        test_bucket_accumulation_edge_cases, test_bucket_accumulations_agree,
+    };
+    use kzg_bench::tests::msm::fixed_base::{
+        test_fixed_base_msm, test_fixed_base_msm_short_inputs,
+    };
+    use kzg_bench::tests::msm::msm_strategy::{test_msm_auto_tuning, test_msm_strategies_agree};
+    use kzg_bench::tests::msm::sparse::{test_sparse_msm, test_tiling_pippenger_with_bits};
+    use rust_kzg_blst::types::{
+        fp::FsFp,
+        fr::FsFr,
+        g1::{FsG1, FsG1Affine, FsG1ProjAddAffine},
+    };
+
+    #[test]
+    fn test_msm_strategies_agree_() {
+        test_msm_strategies_agree::<FsFr, FsG1, FsFp, FsG1Affine, FsG1ProjAddAffine>();
+    }
+
+    #[test]
+    fn test_msm_auto_tuning_() {
+        test_msm_auto_tuning::<FsFr, FsG1, FsFp, FsG1Affine, FsG1ProjAddAffine>();
+    }
+
+    #[test]
+    fn test_fixed_base_msm_() {
+        test_fixed_base_msm::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
+    #[test]
+    fn test_fixed_base_msm_short_inputs_() {
+        test_fixed_base_msm_short_inputs::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
+    #[test]
+    fn test_bucket_accumulations_agree_() {
+        test_bucket_accumulations_agree::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
+    #[test]
+    fn test_bucket_accumulation_edge_cases_() {
+        test_bucket_accumulation_edge_cases::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
+    #[test]
+    fn test_tiling_pippenger_with_bits_() {
+        test_tiling_pippenger_with_bits::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
+    #[test]
+    fn test_sparse_msm_() {
+        test_sparse_msm::<FsFr, FsG1, FsFp, FsG1Affine, FsG1ProjAddAffine>();
+    }
+}
@@ -0,0 +1,66 @@
+// Kept apart from other tests, because the cache is enabled through environment variable, which is shared by the
+// whole test process
+#[cfg(all(test, feature = "bgmw", not(feature = "sppark")))]
+mod tests {
+    use kzg::eip_4844::blob_to_kzg_commitment_rust;
+    #[cfg(feature = "embedded-mainnet-setup")]
+    use kzg_bench::tests::msm::precompute_cache::test_mainnet_setup_skips_precomputation_cache;
+    use kzg_bench::tests::msm::precompute_cache::{
+        test_precomputation_cache_rejects_invalid_tables, test_precomputation_env_cache,
+        test_precomputation_table_round_trip,
+    };
+    #[cfg(feature = "embedded-mainnet-setup")]
+    use rust_kzg_blst::eip_4844::load_mainnet_trusted_setup;
+    use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_blst::types::{
+        fft_settings::FsFFTSettings,
+        fp::FsFp,
+        fr::FsFr,
+        g1::{FsG1, FsG1Affine},
+        g2::FsG2,
+        kzg_settings::FsKZGSettings,
+        poly::FsPoly,
+    };
+
+    #[test]
+    fn test_precomputation_env_cache_() {
+        test_precomputation_env_cache::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-mainnet-setup")]
+    fn test_mainnet_setup_skips_precomputation_cache_() {
+        test_mainnet_setup_skips_precomputation_cache::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&load_mainnet_trusted_setup);
+    }
+
+    #[test]
+    fn test_precomputation_table_round_trip_() {
+        test_precomputation_table_round_trip::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+
+    #[test]
+    fn test_precomputation_cache_rejects_invalid_tables_() {
+        test_precomputation_cache_rejects_invalid_tables::<FsFr, FsG1, FsFp, FsG1Affine>();
+    }
+}
@@ -5,6 +5,7 @@ use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
+use kzg::Error;
 use kzg::{FFTFr, Fr};
 
 use crate::types::fft_settings::CtFFTSettings;
@@ -73,21 +74,23 @@ impl CtFFTSettings {
         data: &[CtFr],
         inverse: bool,
         output: &mut [CtFr],
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         if data.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Supplied list is longer than the available max width",
-            ));
+            )));
         }
         if data.len() != output.len() {
-            return Err(format!(
+            return Err(Error::InvalidLength(format!(
                 "Output length {} doesn't match data length {}",
                 data.len(),
                 output.len()
-            ));
+            )));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("A list with power-of-two length expected"));
+            return Err(Error::BadArgs(String::from(
+                "A list with power-of-two length expected",
+            )));
         }
 
         // In case more roots are provided with fft_settings, use a larger stride
@@ -114,7 +117,7 @@ impl CtFFTSettings {
 
 impl FFTFr<CtFr> for CtFFTSettings {
     /// Fast Fourier Transform for finite field elements
-    fn fft_fr(&self, data: &[CtFr], inverse: bool) -> Result<Vec<CtFr>, String> {
+    fn fft_fr(&self, data: &[CtFr], inverse: bool) -> Result<Vec<CtFr>, Error> {
         let mut ret = vec![CtFr::default(); data.len()];
 
         self.fft_fr_output(data, inverse, &mut ret)?;
@@ -4,6 +4,7 @@ use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
+use kzg::Error;
 use kzg::{Fr, G1Mul, FFTG1, G1};
 
 use crate::types::fft_settings::CtFFTSettings;
@@ -51,13 +52,15 @@ pub fn fft_g1_fast(
 }
 
 impl FFTG1<CtG1> for CtFFTSettings {
-    fn fft_g1(&self, data: &[CtG1], inverse: bool) -> Result<Vec<CtG1>, String> {
+    fn fft_g1(&self, data: &[CtG1], inverse: bool) -> Result<Vec<CtG1>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Supplied list is longer than the available max width",
-            ));
+            )));
         } else if !data.len().is_power_of_two() {
-            return Err(String::from("A list with power-of-two length expected"));
+            return Err(Error::BadArgs(String::from(
+                "A list with power-of-two length expected",
+            )));
         }
 
         let stride = self.max_width / data.len();
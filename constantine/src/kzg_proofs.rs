@@ -3,6 +3,8 @@ extern crate alloc;
 use crate::types::fp::CtFp;
 use crate::types::g1::CtG1;
 use crate::types::{fr::CtFr, g1::CtG1Affine};
+use alloc::vec;
+use alloc::vec::Vec;
 
 use crate::utils::{ptr_transmute, ptr_transmute_mut};
 
@@ -20,9 +22,13 @@ use crate::types::g1::CtG1ProjAddAffine;
 use kzg::msm::precompute::PrecomputationTable;
 
 use crate::types::g2::CtG2;
-use blst::{blst_p1_affine, blst_p1_cneg, blst_p1_to_affine, blst_p2_affine, blst_p2_to_affine};
+use blst::{
+    blst_fp12, blst_fp12_finalverify, blst_fp6, blst_miller_loop_lines, blst_p1_affine,
+    blst_p1_cneg, blst_p1_is_inf, blst_p1_to_affine, blst_p2_affine, blst_p2_is_inf,
+    blst_p2_to_affine, blst_precompute_lines,
+};
 
-use kzg::PairingVerify;
+use kzg::{MultiPairing, PairingVerify};
 
 impl PairingVerify<CtG1, CtG2> for CtG1 {
     fn verify(a1: &CtG1, a2: &CtG2, b1: &CtG1, b2: &CtG2) -> bool {
@@ -30,6 +36,12 @@ impl PairingVerify<CtG1, CtG2> for CtG1 {
     }
 }
 
+impl MultiPairing<CtG1, CtG2> for CtG1 {
+    fn multi_pairing_is_one(ps: &[CtG1], qs: &[CtG2]) -> bool {
+        multi_pairings_verify(ps, qs)
+    }
+}
+
 pub fn g1_linear_combination(
     out: &mut CtG1,
     points: &[CtG1],
@@ -111,3 +123,86 @@ pub fn pairings_verify(a1: &CtG1, a2: &CtG2, b1: &CtG1, b2: &CtG2) -> bool {
         blst::blst_fp12_is_one(&gt_point)
     }
 }
+
+/// Number of line coefficients blst computes for a single G2 point
+const G2_PREPARED_LINES: usize = 68;
+
+/// Miller loop line coefficients of a G2 point, which allow reusing it in many pairings
+#[derive(Debug, Clone)]
+pub struct CtG2Prepared(Vec<blst_fp6>);
+
+impl CtG2Prepared {
+    pub fn new(q: &CtG2) -> Self {
+        let mut q_affine = blst_p2_affine::default();
+        let mut lines = vec![blst_fp6::default(); G2_PREPARED_LINES];
+        unsafe {
+            blst_p2_to_affine(&mut q_affine, ptr_transmute(&q.0));
+            blst_precompute_lines(lines.as_mut_ptr(), &q_affine);
+        }
+
+        Self(lines)
+    }
+}
+
+/// Same as [`pairings_verify`], but with prepared G2 points, which must not be at infinity
+pub fn prepared_pairings_verify(
+    a1: &CtG1,
+    a2: &CtG2Prepared,
+    b1: &CtG1,
+    b2: &CtG2Prepared,
+) -> bool {
+    unsafe {
+        // Pairings with the point at infinity are one, and blst's Miller loop expects
+        // both points to be finite
+        let a1_inf = blst_p1_is_inf(ptr_transmute(&a1.0));
+        let b1_inf = blst_p1_is_inf(ptr_transmute(&b1.0));
+        if a1_inf || b1_inf {
+            return a1_inf && b1_inf;
+        }
+
+        let mut aa1 = blst_p1_affine::default();
+        let mut bb1 = blst_p1_affine::default();
+        blst_p1_to_affine(&mut aa1, ptr_transmute(&a1.0));
+        blst_p1_to_affine(&mut bb1, ptr_transmute(&b1.0));
+
+        let mut gt_a = blst_fp12::default();
+        let mut gt_b = blst_fp12::default();
+        blst_miller_loop_lines(&mut gt_a, a2.0.as_ptr(), &aa1);
+        blst_miller_loop_lines(&mut gt_b, b2.0.as_ptr(), &bb1);
+
+        blst_fp12_finalverify(&gt_a, &gt_b)
+    }
+}
+
+pub fn multi_pairings_verify(ps: &[CtG1], qs: &[CtG2]) -> bool {
+    if ps.len() != qs.len() {
+        return false;
+    }
+
+    let dst = [0u8; 3];
+    let mut pairing_blst = blst::Pairing::new(false, &dst);
+    let mut aggregated = false;
+    for (p, q) in ps.iter().zip(qs) {
+        unsafe {
+            // Pairings with the point at infinity are one, and blst's Miller loop expects
+            // both points to be finite
+            if blst_p1_is_inf(ptr_transmute(&p.0)) || blst_p2_is_inf(ptr_transmute(&q.0)) {
+                continue;
+            }
+
+            let mut p_affine = blst_p1_affine::default();
+            let mut q_affine = blst_p2_affine::default();
+            blst_p1_to_affine(&mut p_affine, ptr_transmute(&p.0));
+            blst_p2_to_affine(&mut q_affine, ptr_transmute(&q.0));
+            pairing_blst.raw_aggregate(&q_affine, &p_affine);
+        }
+        aggregated = true;
+    }
+
+    if !aggregated {
+        return true;
+    }
+
+    let gt_point = pairing_blst.as_fp12().final_exp();
+    unsafe { blst::blst_fp12_is_one(&gt_point) }
+}
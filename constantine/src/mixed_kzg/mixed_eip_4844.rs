@@ -10,13 +10,16 @@ use kzg::eip_4844::{
     verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
     BYTES_PER_BLOB, FIELD_ELEMENTS_PER_BLOB,
 };
+use kzg::Error;
 use kzg::{Fr, G1};
 
 use super::mixed_kzg_settings::LocalToStr;
 
-fn blob_fr_to_byte_inplace(blob: &[CtFr], inplace: &mut [u8; BYTES_PER_BLOB]) -> Option<String> {
+fn blob_fr_to_byte_inplace(blob: &[CtFr], inplace: &mut [u8; BYTES_PER_BLOB]) -> Option<Error> {
     if blob.len() != FIELD_ELEMENTS_PER_BLOB {
-        return Some("blob length is not equal to FIELD_ELEMENTS_PER_BLOB".to_string());
+        return Some(Error::InvalidLength(
+            "blob length is not equal to FIELD_ELEMENTS_PER_BLOB".to_string(),
+        ));
     }
 
     for i in 0..FIELD_ELEMENTS_PER_BLOB {
@@ -26,9 +29,11 @@ fn blob_fr_to_byte_inplace(blob: &[CtFr], inplace: &mut [u8; BYTES_PER_BLOB]) ->
     None
 }
 
-fn blob_fr_to_byte(blob: &[CtFr]) -> Result<[u8; BYTES_PER_BLOB], String> {
+fn blob_fr_to_byte(blob: &[CtFr]) -> Result<[u8; BYTES_PER_BLOB], Error> {
     if blob.len() != FIELD_ELEMENTS_PER_BLOB {
-        return Err("blob length is not equal to FIELD_ELEMENTS_PER_BLOB".to_string());
+        return Err(Error::InvalidLength(
+            "blob length is not equal to FIELD_ELEMENTS_PER_BLOB".to_string(),
+        ));
     }
 
     let mut blob_bytes = [0u8; BYTES_PER_BLOB];
@@ -40,14 +45,14 @@ fn blob_fr_to_byte(blob: &[CtFr]) -> Result<[u8; BYTES_PER_BLOB], String> {
     // unsafe { Ok(std::mem::transmute(blob.as_ptr() as *const [u8; BYTES_PER_BLOB])) }
 }
 
-pub fn load_trusted_setup_filename_mixed(filepath: &str) -> Result<MixedKzgSettings, String> {
+pub fn load_trusted_setup_filename_mixed(filepath: &str) -> Result<MixedKzgSettings, Error> {
     MixedKzgSettings::new_from_path(Path::new(filepath))
 }
 
 pub fn blob_to_kzg_commitment_mixed(
     blob: &[CtFr],
     settings: &MixedKzgSettings,
-) -> Result<CtG1, String> {
+) -> Result<CtG1, Error> {
     match settings {
         MixedKzgSettings::Constantine(ctt_context) => {
             let blob_bytes = blob_fr_to_byte(blob)?;
@@ -62,7 +67,7 @@ pub fn blob_to_kzg_commitment_mixed(
 
             match res {
                 Ok(commitment) => CtG1::from_bytes(&commitment),
-                Err(x) => Err(x.to_string()),
+                Err(x) => Err(Error::BadArgs(x.to_string())),
             }
             // return blob_to_kzg_commitment_rust(blob, ctt_context);
         }
@@ -76,7 +81,7 @@ pub fn compute_kzg_proof_mixed(
     blob: &[CtFr],
     z: &CtFr,
     s: &MixedKzgSettings,
-) -> Result<(CtG1, CtFr), String> {
+) -> Result<(CtG1, CtFr), Error> {
     match s {
         MixedKzgSettings::Constantine(ctt_context) => {
             let blob_bytes = blob_fr_to_byte(blob)?;
@@ -95,7 +100,7 @@ pub fn compute_kzg_proof_mixed(
 
             match res {
                 Ok((proof, y)) => Ok((CtG1::from_bytes(&proof)?, CtFr::from_bytes(&y)?)),
-                Err(x) => Err(x.to_string()),
+                Err(x) => Err(Error::BadArgs(x.to_string())),
             }
         }
         MixedKzgSettings::Generic(generic_context) => {
@@ -108,7 +113,7 @@ pub fn compute_blob_kzg_proof_mixed(
     blob: &[CtFr],
     commitment: &CtG1,
     ts: &MixedKzgSettings,
-) -> Result<CtG1, String> {
+) -> Result<CtG1, Error> {
     match ts {
         MixedKzgSettings::Constantine(ctt_context) => {
             let blob_bytes = blob_fr_to_byte(blob)?;
@@ -127,7 +132,7 @@ pub fn compute_blob_kzg_proof_mixed(
 
             match res {
                 Ok(proof) => CtG1::from_bytes(&proof),
-                Err(x) => Err(x.to_string()),
+                Err(x) => Err(Error::BadArgs(x.to_string())),
             }
         }
         MixedKzgSettings::Generic(generic_context) => {
@@ -142,7 +147,7 @@ pub fn verify_kzg_proof_mixed(
     y: &CtFr,
     proof: &CtG1,
     s: &MixedKzgSettings,
-) -> Result<bool, String> {
+) -> Result<bool, Error> {
     match s {
         MixedKzgSettings::Constantine(ctt_context) => {
             let res = ctt_context.ctx.verify_kzg_proof(
@@ -153,7 +158,7 @@ pub fn verify_kzg_proof_mixed(
             );
             match res {
                 Ok(x) => Ok(x),
-                Err(x) => Err(x.to_string()),
+                Err(x) => Err(Error::BadArgs(x.to_string())),
             }
         }
         MixedKzgSettings::Generic(generic_context) => {
@@ -167,7 +172,7 @@ pub fn verify_blob_kzg_proof_mixed(
     commitment_g1: &CtG1,
     proof_g1: &CtG1,
     ts: &MixedKzgSettings,
-) -> Result<bool, String> {
+) -> Result<bool, Error> {
     match ts {
         MixedKzgSettings::Constantine(ctt_context) => {
             let blob_bytes = blob_fr_to_byte(blob)?;
@@ -189,7 +194,7 @@ pub fn verify_blob_kzg_proof_mixed(
 
             match res {
                 Ok(x) => Ok(x),
-                Err(x) => Err(x.to_string()),
+                Err(x) => Err(Error::BadArgs(x.to_string())),
             }
         }
         MixedKzgSettings::Generic(generic_context) => {
@@ -203,7 +208,7 @@ pub fn verify_blob_kzg_proof_batch_mixed(
     commitments_g1: &[CtG1],
     proofs_g1: &[CtG1],
     ts: &MixedKzgSettings,
-) -> Result<bool, String> {
+) -> Result<bool, Error> {
     match ts {
         MixedKzgSettings::Constantine(ctt_context) => {
             let mut blobs_storage = vec![[0u8; BYTES_PER_BLOB]; blobs.len()];
@@ -241,7 +246,7 @@ pub fn verify_blob_kzg_proof_batch_mixed(
 
             match res {
                 Ok(x) => Ok(x),
-                Err(x) => Err(x.to_string()),
+                Err(x) => Err(Error::BadArgs(x.to_string())),
             }
         }
         MixedKzgSettings::Generic(generic_context) => {
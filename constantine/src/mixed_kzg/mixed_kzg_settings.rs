@@ -12,6 +12,9 @@ use crate::types::{
 use constantine_core::Threadpool as CttThreadpool;
 use constantine_ethereum_kzg::EthKzgContext as CttEthKzgContext;
 use constantine_sys::{ctt_eth_kzg_status, ctt_eth_trusted_setup_status};
+use kzg::eip_4844::FIELD_ELEMENTS_PER_BLOB;
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 use kzg::KZGSettings;
 
 use super::mixed_eip_4844::verify_kzg_proof_mixed;
@@ -22,14 +25,14 @@ pub struct CttContext {
 }
 
 impl CttContext {
-    pub fn new(path: &Path) -> Result<Self, String> {
+    pub fn new(path: &Path) -> Result<Self, Error> {
         let res = CttEthKzgContext::load_trusted_setup(path);
         match res {
             Ok(constantine_context) => Ok(Self {
                 ctx: constantine_context,
                 pool: CttThreadpool::new(get_thr_count()),
             }),
-            Err(x) => Err(x.to_string()),
+            Err(x) => Err(Error::InvalidTrustedSetup(x.to_string())),
         }
     }
 }
@@ -77,7 +80,7 @@ impl MixedKzgSettings {
         secret_g2: &[CtG2],
         length: usize,
         fft_settings: &CtFFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         let res = GenericContext::new(secret_g1, secret_g2, length, fft_settings);
         match res {
             Ok(generic_context) => Ok(Self::Generic(generic_context)),
@@ -85,14 +88,14 @@ impl MixedKzgSettings {
         }
     }
 
-    pub fn new_from_path(path: &Path) -> Result<Self, String> {
+    pub fn new_from_path(path: &Path) -> Result<Self, Error> {
         let res = CttEthKzgContext::load_trusted_setup(path);
         match res {
             Ok(constantine_context) => Ok(Self::Constantine(CttContext {
                 ctx: constantine_context,
                 pool: CttThreadpool::new(get_thr_count()),
             })),
-            Err(x) => Err(x.to_string()),
+            Err(x) => Err(Error::InvalidTrustedSetup(x.to_string())),
         }
     }
 }
@@ -119,20 +122,51 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         secret_g2: &[CtG2],
         length: usize,
         fs: &CtFFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         MixedKzgSettings::new(secret_g1, secret_g2, length, fs)
     }
 
-    fn commit_to_poly(&self, p: &CtPoly) -> Result<CtG1, String> {
+    fn new_from_lagrange(
+        secret_g1: &[CtG1],
+        secret_g2: &[CtG2],
+        length: usize,
+        fs: &CtFFTSettings,
+    ) -> Result<Self, Error> {
+        GenericContext::new_from_lagrange(secret_g1, secret_g2, length, fs).map(Self::Generic)
+    }
+
+    fn commit_to_poly(&self, p: &CtPoly) -> Result<CtG1, Error> {
         match self {
-            MixedKzgSettings::Constantine(_) => Err("Context not in generic format".to_string()),
+            MixedKzgSettings::Constantine(_) => {
+                Err(Error::BadArgs("Context not in generic format".to_string()))
+            }
             MixedKzgSettings::Generic(generic_context) => generic_context.commit_to_poly(p),
         }
     }
 
-    fn compute_proof_single(&self, p: &CtPoly, x: &CtFr) -> Result<CtG1, String> {
+    fn commit_coeff_form(&self, p: &CtPoly) -> Result<CtG1, Error> {
+        match self {
+            MixedKzgSettings::Constantine(_) => {
+                Err(Error::BadArgs("Context not in generic format".to_string()))
+            }
+            MixedKzgSettings::Generic(generic_context) => generic_context.commit_coeff_form(p),
+        }
+    }
+
+    fn commit_eval_form(&self, p: &CtPoly) -> Result<CtG1, Error> {
+        match self {
+            MixedKzgSettings::Constantine(_) => {
+                Err(Error::BadArgs("Context not in generic format".to_string()))
+            }
+            MixedKzgSettings::Generic(generic_context) => generic_context.commit_eval_form(p),
+        }
+    }
+
+    fn compute_proof_single(&self, p: &CtPoly, x: &CtFr) -> Result<CtG1, Error> {
         match self {
-            MixedKzgSettings::Constantine(_) => Err("Context not in generic format".to_string()),
+            MixedKzgSettings::Constantine(_) => {
+                Err(Error::BadArgs("Context not in generic format".to_string()))
+            }
             MixedKzgSettings::Generic(generic_context) => {
                 generic_context.compute_proof_single(p, x)
             }
@@ -145,13 +179,15 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         proof: &CtG1,
         x: &CtFr,
         value: &CtFr,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         verify_kzg_proof_mixed(com, x, value, proof, self)
     }
 
-    fn compute_proof_multi(&self, p: &CtPoly, x: &CtFr, n: usize) -> Result<CtG1, String> {
+    fn compute_proof_multi(&self, p: &CtPoly, x: &CtFr, n: usize) -> Result<CtG1, Error> {
         match self {
-            MixedKzgSettings::Constantine(_) => Err("Context not in generic format".to_string()),
+            MixedKzgSettings::Constantine(_) => {
+                Err(Error::BadArgs("Context not in generic format".to_string()))
+            }
             MixedKzgSettings::Generic(generic_context) => {
                 generic_context.compute_proof_multi(p, x, n)
             }
@@ -165,9 +201,11 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         x: &CtFr,
         values: &[CtFr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         match self {
-            MixedKzgSettings::Constantine(_) => Err("Context not in generic format".to_string()),
+            MixedKzgSettings::Constantine(_) => {
+                Err(Error::BadArgs("Context not in generic format".to_string()))
+            }
             MixedKzgSettings::Generic(generic_context) => {
                 generic_context.check_proof_multi(com, proof, x, values, n)
             }
@@ -212,6 +250,15 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         }
     }
 
+    fn get_g1_monomial(&self) -> &[CtG1] {
+        match self {
+            MixedKzgSettings::Constantine(_) => {
+                panic!("Context not in generic format")
+            }
+            MixedKzgSettings::Generic(generic_context) => generic_context.get_g1_monomial(),
+        }
+    }
+
     fn get_g2_secret(&self) -> &[CtG2] {
         match self {
             MixedKzgSettings::Constantine(_) => {
@@ -221,6 +268,17 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         }
     }
 
+    fn verify_pairing_with_g2_secret(&self, a: &CtG1, b: &CtG1) -> bool {
+        match self {
+            MixedKzgSettings::Constantine(_) => {
+                panic!("Context not in generic format")
+            }
+            MixedKzgSettings::Generic(generic_context) => {
+                generic_context.verify_pairing_with_g2_secret(a, b)
+            }
+        }
+    }
+
     fn get_precomputation(
         &self,
     ) -> Option<&kzg::msm::precompute::PrecomputationTable<CtFr, CtG1, CtFp, CtG1Affine>> {
@@ -231,4 +289,33 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
             MixedKzgSettings::Generic(generic_context) => generic_context.get_precomputation(),
         }
     }
+
+    fn get_thread_pool(&self) -> &ThreadPoolConfig {
+        match self {
+            MixedKzgSettings::Constantine(_) => {
+                panic!("Context not in generic format")
+            }
+            MixedKzgSettings::Generic(generic_context) => generic_context.get_thread_pool(),
+        }
+    }
+
+    fn set_thread_pool(&mut self, thread_pool: ThreadPoolConfig) {
+        match self {
+            MixedKzgSettings::Constantine(_) => {
+                panic!("Context not in generic format")
+            }
+            MixedKzgSettings::Generic(generic_context) => {
+                generic_context.set_thread_pool(thread_pool)
+            }
+        }
+    }
+
+    fn get_field_elements_per_blob(&self) -> usize {
+        match self {
+            MixedKzgSettings::Constantine(_) => FIELD_ELEMENTS_PER_BLOB,
+            MixedKzgSettings::Generic(generic_context) => {
+                generic_context.get_field_elements_per_blob()
+            }
+        }
+    }
 }
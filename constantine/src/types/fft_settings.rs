@@ -5,6 +5,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use kzg::common_utils::reverse_bit_order;
+use kzg::Error;
 use kzg::{FFTSettings, Fr};
 
 use crate::consts::SCALE2_ROOT_OF_UNITY;
@@ -27,11 +28,11 @@ impl Default for CtFFTSettings {
 
 impl FFTSettings<CtFr> for CtFFTSettings {
     /// Create FFTSettings with roots of unity for a selected scale. Resulting roots will have a magnitude of 2 ^ max_scale.
-    fn new(scale: usize) -> Result<CtFFTSettings, String> {
+    fn new(scale: usize) -> Result<CtFFTSettings, Error> {
         if scale >= SCALE2_ROOT_OF_UNITY.len() {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Scale is expected to be within root of unity matrix row size",
-            ));
+            )));
         }
 
         // max_width = 2 ^ max_scale
@@ -87,19 +88,23 @@ impl FFTSettings<CtFr> for CtFFTSettings {
 }
 
 /// Multiply a given root of unity by itself until it results in a 1 and result all multiplication values in a vector
-pub fn expand_root_of_unity(root: &CtFr, width: usize) -> Result<Vec<CtFr>, String> {
+pub fn expand_root_of_unity(root: &CtFr, width: usize) -> Result<Vec<CtFr>, Error> {
     let mut generated_powers = vec![CtFr::one(), *root];
 
     while !(generated_powers.last().unwrap().is_one()) {
         if generated_powers.len() > width {
-            return Err(String::from("Root of unity multiplied for too long"));
+            return Err(Error::BadArgs(String::from(
+                "Root of unity multiplied for too long",
+            )));
         }
 
         generated_powers.push(generated_powers.last().unwrap().mul(root));
     }
 
     if generated_powers.len() != width + 1 {
-        return Err(String::from("Root of unity has invalid scale"));
+        return Err(Error::BadArgs(String::from(
+            "Root of unity has invalid scale",
+        )));
     }
 
     Ok(generated_powers)
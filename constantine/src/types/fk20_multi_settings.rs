@@ -5,7 +5,9 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use kzg::common_utils::reverse_bit_order;
-use kzg::{FK20MultiSettings, Poly, FFTG1, G1};
+use kzg::msm::fixed_base::FixedBaseMsm;
+use kzg::Error;
+use kzg::{FFTFr, FK20MultiSettings, Poly, FFTG1, G1};
 
 use crate::types::fft_settings::CtFFTSettings;
 use crate::types::fr::CtFr;
@@ -21,6 +23,8 @@ pub struct CtFK20MultiSettings {
     pub kzg_settings: CtKZGSettings,
     pub chunk_len: usize,
     pub x_ext_fft_files: Vec<Vec<CtG1>>,
+    /// `j`-th points of all `x_ext_fft_files`, multiplied together when computing `h_ext_fft[j]`
+    pub x_ext_fft_columns: Vec<FixedBaseMsm<CtFr, CtG1, CtFp, CtG1Affine>>,
 }
 
 impl Clone for CtFK20MultiSettings {
@@ -29,6 +33,7 @@ impl Clone for CtFK20MultiSettings {
             kzg_settings: self.kzg_settings.clone(),
             chunk_len: self.chunk_len,
             x_ext_fft_files: self.x_ext_fft_files.clone(),
+            x_ext_fft_columns: self.x_ext_fft_columns.clone(),
         }
     }
 }
@@ -39,6 +44,7 @@ impl Default for CtFK20MultiSettings {
             kzg_settings: CtKZGSettings::default(),
             chunk_len: 1,
             x_ext_fft_files: vec![],
+            x_ext_fft_columns: vec![],
         }
     }
 }
@@ -47,19 +53,25 @@ impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, C
     for CtFK20MultiSettings
 {
     #[allow(clippy::many_single_char_names)]
-    fn new(ks: &CtKZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    fn new(ks: &CtKZGSettings, n2: usize, chunk_len: usize) -> Result<Self, Error> {
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be less than or equal to kzg settings max width",
-            ));
+            )));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::BadArgs(String::from("n2 must be a power of two")));
         } else if n2 < 2 {
-            return Err(String::from("n2 must be greater than or equal to 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be greater than or equal to 2",
+            )));
         } else if chunk_len > n2 / 2 {
-            return Err(String::from("chunk_len must be greater or equal to n2 / 2"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be greater or equal to n2 / 2",
+            )));
         } else if !chunk_len.is_power_of_two() {
-            return Err(String::from("chunk_len must be a power of two"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be a power of two",
+            )));
         }
 
         let n = n2 / 2;
@@ -96,27 +108,35 @@ impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, C
             }
         }
 
+        let x_ext_fft_columns = (0..k * 2)
+            .map(|j| {
+                let column = ext_fft_files.iter().map(|file| file[j]).collect::<Vec<_>>();
+                FixedBaseMsm::new(&column)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         let ret = Self {
             kzg_settings: ks.clone(),
             chunk_len,
             x_ext_fft_files: ext_fft_files,
+            x_ext_fft_columns,
         };
 
         Ok(ret)
     }
 
-    fn data_availability(&self, p: &CtPoly) -> Result<Vec<CtG1>, String> {
+    fn data_availability(&self, p: &CtPoly) -> Result<Vec<CtG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be less than or equal to kzg settings max width",
-            ));
+            )));
         }
 
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::BadArgs(String::from("n2 must be a power of two")));
         }
 
         let mut ret = self.data_availability_optimized(p).unwrap();
@@ -125,36 +145,53 @@ impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, C
         Ok(ret)
     }
 
-    fn data_availability_optimized(&self, p: &CtPoly) -> Result<Vec<CtG1>, String> {
+    fn data_availability_optimized(&self, p: &CtPoly) -> Result<Vec<CtG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be less than or equal to kzg settings max width",
-            ));
+            )));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::BadArgs(String::from("n2 must be a power of two")));
         }
 
         let n = n2 / 2;
         let k = n / self.chunk_len;
         let k2 = k * 2;
 
-        let mut h_ext_fft = vec![CtG1::identity(); k2];
+        if k2 > self.x_ext_fft_columns.len() {
+            return Err(Error::BadArgs(String::from(
+                "n2 must be less than or equal to n2 of FK20 settings",
+            )));
+        }
 
+        let mut toeplitz_coeffs_fft = Vec::with_capacity(self.chunk_len);
         for i in 0..self.chunk_len {
             let toeplitz_coeffs = p.toeplitz_coeffs_stride(i, self.chunk_len);
-            let h_ext_fft_file = self
-                .kzg_settings
-                .fs
-                .toeplitz_part_2(&toeplitz_coeffs, &self.x_ext_fft_files[i]);
-
-            for j in 0..k2 {
-                h_ext_fft[j] = h_ext_fft[j].add_or_dbl(&h_ext_fft_file[j]);
-            }
+            toeplitz_coeffs_fft.push(
+                self.kzg_settings
+                    .fs
+                    .fft_fr(&toeplitz_coeffs.coeffs, false)?,
+            );
         }
 
+        // `h_ext_fft[j]` is the sum of `j`-th points of all files, multiplied by `j`-th coefficients
+        let h_ext_fft = self
+            .x_ext_fft_columns
+            .iter()
+            .take(k2)
+            .enumerate()
+            .map(|(j, column)| {
+                let scalars = toeplitz_coeffs_fft
+                    .iter()
+                    .map(|coeffs_fft| coeffs_fft[j])
+                    .collect::<Vec<_>>();
+                column.msm(&scalars)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         let mut h = self.kzg_settings.fs.toeplitz_part_3(&h_ext_fft);
 
         h[k..k2].copy_from_slice(&vec![CtG1::identity(); k2 - k]);
@@ -4,6 +4,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use kzg::common_utils::reverse_bit_order;
+use kzg::Error;
 use kzg::{FK20SingleSettings, Poly, FFTG1, G1};
 
 use crate::types::fft_settings::CtFFTSettings;
@@ -25,17 +26,19 @@ pub struct CtFK20SingleSettings {
 impl FK20SingleSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, CtFp, CtG1Affine>
     for CtFK20SingleSettings
 {
-    fn new(kzg_settings: &CtKZGSettings, n2: usize) -> Result<Self, String> {
+    fn new(kzg_settings: &CtKZGSettings, n2: usize) -> Result<Self, Error> {
         let n = n2 / 2;
 
         if n2 > kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be less than or equal to kzg settings max width",
-            ));
+            )));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::BadArgs(String::from("n2 must be a power of two")));
         } else if n2 < 2 {
-            return Err(String::from("n2 must be greater than or equal to 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be greater than or equal to 2",
+            )));
         }
 
         let mut x = Vec::with_capacity(n);
@@ -56,16 +59,16 @@ impl FK20SingleSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings,
         Ok(ret)
     }
 
-    fn data_availability(&self, p: &CtPoly) -> Result<Vec<CtG1>, String> {
+    fn data_availability(&self, p: &CtPoly) -> Result<Vec<CtG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be less than or equal to kzg settings max width",
-            ));
+            )));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::BadArgs(String::from("n2 must be a power of two")));
         }
 
         let mut ret = self.data_availability_optimized(p).unwrap();
@@ -74,16 +77,16 @@ impl FK20SingleSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings,
         Ok(ret)
     }
 
-    fn data_availability_optimized(&self, p: &CtPoly) -> Result<Vec<CtG1>, String> {
+    fn data_availability_optimized(&self, p: &CtPoly) -> Result<Vec<CtG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.kzg_settings.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be less than or equal to kzg settings max width",
-            ));
+            )));
         } else if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be a power of two"));
+            return Err(Error::BadArgs(String::from("n2 must be a power of two")));
         }
 
         let toeplitz_coeffs = p.toeplitz_coeffs_step();
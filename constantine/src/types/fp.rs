@@ -84,6 +84,10 @@ impl G1Fp for CtFp {
         }
     }
 
+    fn to_underlying_arr(&self) -> [u64; 6] {
+        unsafe { core::mem::transmute::<[usize; 6], [u64; 6]>(self.0.limbs) }
+    }
+
     fn neg_assign(&mut self) {
         unsafe {
             constantine::ctt_bls12_381_fp_neg_in_place(&mut self.0);
@@ -9,6 +9,7 @@ use blst::blst_fr;
 use constantine::ctt_codec_scalar_status;
 use core::fmt::{Debug, Formatter};
 use kzg::eip_4844::BYTES_PER_FIELD_ELEMENT;
+use kzg::Error;
 use kzg::Fr;
 use kzg::Scalar256;
 
@@ -85,15 +86,15 @@ impl Fr for CtFr {
         ret
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut ret: Self = Self::default();
@@ -103,7 +104,7 @@ impl Fr for CtFr {
                         constantine::ctt_bls12_381_deserialize_scalar(&mut scalar, bytes.as_ptr());
                     if status == ctt_codec_scalar_status::cttCodecScalar_ScalarLargerThanCurveOrder
                     {
-                        return Err("Invalid scalar".to_string());
+                        return Err(Error::NonCanonicalFieldElement);
                     }
                     constantine::ctt_bls12_381_fr_from_big255(&mut ret.0, &scalar);
                 }
@@ -111,15 +112,15 @@ impl Fr for CtFr {
             })
     }
 
-    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .map(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut ret = Self::default();
@@ -136,7 +137,7 @@ impl Fr for CtFr {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -267,7 +268,7 @@ impl Fr for CtFr {
         out
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         let tmp = b.eucl_inverse();
         let out = self.mul(&tmp);
 
@@ -5,6 +5,7 @@ use alloc::string::String;
 use alloc::string::ToString;
 use constantine::ctt_codec_ecc_status;
 use kzg::msm::precompute::PrecomputationTable;
+use kzg::Error;
 use kzg::G1LinComb;
 
 use core::fmt::{Debug, Formatter};
@@ -105,15 +106,15 @@ impl G1 for CtG1 {
         result.mul(&kzg::Fr::rand())
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G1,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
                 let mut tmp = bls12_381_g1_aff::default();
@@ -127,7 +128,7 @@ impl G1 for CtG1 {
                     if res != ctt_codec_ecc_status::cttCodecEcc_Success
                         && res != ctt_codec_ecc_status::cttCodecEcc_PointAtInfinity
                     {
-                        return Err("Failed to uncompress".to_string());
+                        return Err(Error::InvalidPoint("Failed to uncompress".to_string()));
                     }
                     ctt_bls12_381_g1_jac_from_affine(&mut g1, &tmp);
                 }
@@ -135,7 +136,7 @@ impl G1 for CtG1 {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -6,6 +6,7 @@ use alloc::string::ToString;
 
 use constantine::ctt_codec_ecc_status;
 use kzg::eip_4844::BYTES_PER_G2;
+use kzg::Error;
 #[cfg(feature = "rand")]
 use kzg::Fr;
 use kzg::{G2Mul, G2};
@@ -118,15 +119,15 @@ impl G2 for CtG2 {
         G2_NEGATIVE_GENERATOR
     }
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G2,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
                 let mut tmp = bls12_381_g2_aff::default();
@@ -140,7 +141,7 @@ impl G2 for CtG2 {
                     if res != ctt_codec_ecc_status::cttCodecEcc_Success
                         && res != ctt_codec_ecc_status::cttCodecEcc_PointAtInfinity
                     {
-                        return Err("Failed to uncompress".to_string());
+                        return Err(Error::InvalidPoint("Failed to uncompress".to_string()));
                     }
                     ctt_bls12_381_g2_jac_from_affine(&mut g2, &tmp);
                 }
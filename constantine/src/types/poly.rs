@@ -5,6 +5,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use kzg::common_utils::{log2_pow2, log2_u64, next_pow_of_2};
+use kzg::Error;
 use kzg::{FFTFr, FFTSettings, FFTSettingsPoly, Fr, Poly};
 
 use crate::consts::SCALE_FACTOR;
@@ -83,15 +84,19 @@ impl Poly<CtFr> for CtPoly {
     }
 
     // TODO: analyze how algo works
-    fn inverse(&mut self, output_len: usize) -> Result<Self, String> {
+    fn inverse(&mut self, output_len: usize) -> Result<Self, Error> {
         if output_len == 0 {
-            return Err(String::from("Can't produce a zero-length result"));
+            return Err(Error::BadArgs(String::from(
+                "Can't produce a zero-length result",
+            )));
         } else if self.coeffs.is_empty() {
-            return Err(String::from("Can't inverse a zero-length poly"));
+            return Err(Error::BadArgs(String::from(
+                "Can't inverse a zero-length poly",
+            )));
         } else if self.coeffs[0].is_zero() {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "First coefficient of polynomial mustn't be zero",
-            ));
+            )));
         }
 
         let mut ret = CtPoly {
@@ -142,13 +147,15 @@ impl Poly<CtFr> for CtPoly {
         }
 
         if d + 1 != output_len {
-            return Err(String::from("D + 1 must be equal to output_len"));
+            return Err(Error::BadArgs(String::from(
+                "D + 1 must be equal to output_len",
+            )));
         }
 
         Ok(ret)
     }
 
-    fn div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.len() >= self.len() || divisor.len() < 128 {
             // Tunable parameter
             self.long_div(divisor)
@@ -157,11 +164,13 @@ impl Poly<CtFr> for CtPoly {
         }
     }
 
-    fn long_div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn long_div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.coeffs.is_empty() {
-            return Err(String::from("Can't divide by zero"));
+            return Err(Error::BadArgs(String::from("Can't divide by zero")));
         } else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-            return Err(String::from("Highest coefficient must be non-zero"));
+            return Err(Error::BadArgs(String::from(
+                "Highest coefficient must be non-zero",
+            )));
         }
 
         let out_length = self.poly_quotient_length(divisor);
@@ -213,11 +222,13 @@ impl Poly<CtFr> for CtPoly {
         }
     }
 
-    fn fast_div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn fast_div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.coeffs.is_empty() {
-            return Err(String::from("Cant divide by zero"));
+            return Err(Error::BadArgs(String::from("Cant divide by zero")));
         } else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-            return Err(String::from("Highest coefficient must be non-zero"));
+            return Err(Error::BadArgs(String::from(
+                "Highest coefficient must be non-zero",
+            )));
         }
 
         let m: usize = self.len() - 1;
@@ -249,7 +260,7 @@ impl Poly<CtFr> for CtPoly {
         Ok(out)
     }
 
-    fn mul_direct(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    fn mul_direct(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         if self.len() == 0 || multiplier.len() == 0 {
             return Ok(CtPoly::new(0));
         }
@@ -283,7 +294,7 @@ impl FFTSettingsPoly<CtFr, CtPoly, CtFFTSettings> for CtFFTSettings {
         b: &CtPoly,
         len: usize,
         _fs: Option<&CtFFTSettings>,
-    ) -> Result<CtPoly, String> {
+    ) -> Result<CtPoly, Error> {
         b.mul_fft(a, len)
     }
 }
@@ -326,7 +337,7 @@ impl CtPoly {
         ret
     }
 
-    pub fn flip(&self) -> Result<CtPoly, String> {
+    pub fn flip(&self) -> Result<CtPoly, Error> {
         let mut ret = CtPoly {
             coeffs: vec![CtFr::default(); self.len()],
         };
@@ -337,7 +348,7 @@ impl CtPoly {
         Ok(ret)
     }
 
-    pub fn mul_fft(&self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    pub fn mul_fft(&self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         let length = next_pow_of_2(self.len() + multiplier.len() - 1);
 
         let scale = log2_pow2(length);
@@ -395,7 +406,7 @@ impl CtPoly {
         Ok(ret)
     }
 
-    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
             // Tunable parameter
             self.mul_direct(multiplier, output_len)
@@ -5,6 +5,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::{min, Ordering};
 
+use kzg::Error;
 use kzg::{common_utils::next_pow_of_2, FFTFr, Fr, ZeroPoly};
 
 use crate::types::fft_settings::CtFFTSettings;
@@ -21,11 +22,11 @@ const DEGREE_OF_PARTIAL: usize = 256;
 const REDUCTION_FACTOR: usize = 4;
 
 /// Pad given poly it with zeros to new length
-pub fn pad_poly(mut poly: Vec<CtFr>, new_length: usize) -> Result<Vec<CtFr>, String> {
+pub fn pad_poly(mut poly: Vec<CtFr>, new_length: usize) -> Result<Vec<CtFr>, Error> {
     if new_length < poly.len() {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "new_length must be longer or equal to poly length",
-        ));
+        )));
     }
 
     poly.resize(new_length, CtFr::zero());
@@ -37,14 +38,14 @@ pub fn pad_poly(mut poly: Vec<CtFr>, new_length: usize) -> Result<Vec<CtFr>, Str
 pub fn pad_poly_coeffs<const N: usize, T>(
     mut coeffs: SmallVec<[T; N]>,
     new_length: usize,
-) -> Result<SmallVec<[T; N]>, String>
+) -> Result<SmallVec<[T; N]>, Error>
 where
     T: Default + Clone,
 {
     if new_length < coeffs.len() {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "new_length must be longer or equal to coeffs length",
-        ));
+        )));
     }
 
     coeffs.resize(new_length, T::default());
@@ -57,9 +58,9 @@ impl CtFFTSettings {
         &self,
         idxs: &[usize],
         stride: usize,
-    ) -> Result<SmallVec<[CtFr; DEGREE_OF_PARTIAL]>, String> {
+    ) -> Result<SmallVec<[CtFr; DEGREE_OF_PARTIAL]>, Error> {
         if idxs.is_empty() {
-            return Err(String::from("idx array must not be empty"));
+            return Err(Error::BadArgs(String::from("idx array must not be empty")));
         }
 
         // Makes use of long multiplication in terms of (x - w_0)(x - w_1)..
@@ -92,13 +93,15 @@ impl CtFFTSettings {
         &self,
         domain_size: usize,
         partial_coeffs: SmallVec<[SmallVec<[CtFr; DEGREE_OF_PARTIAL]>; REDUCTION_FACTOR]>,
-    ) -> Result<SmallVec<[CtFr; DEGREE_OF_PARTIAL]>, String> {
+    ) -> Result<SmallVec<[CtFr; DEGREE_OF_PARTIAL]>, Error> {
         if !domain_size.is_power_of_two() {
-            return Err(String::from("Expected domain size to be a power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "Expected domain size to be a power of 2",
+            )));
         }
 
         if partial_coeffs.is_empty() {
-            return Err(String::from("partials must not be empty"));
+            return Err(Error::BadArgs(String::from("partials must not be empty")));
         }
 
         // Calculate the resulting polynomial degree
@@ -112,9 +115,9 @@ impl CtFFTSettings {
             .sum::<usize>();
 
         if out_degree + 1 > domain_size {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Out degree is longer than possible polynomial size in domain",
-            ));
+            )));
         }
 
         let mut partial_coeffs = partial_coeffs.into_iter();
@@ -154,14 +157,14 @@ impl CtFFTSettings {
 }
 
 impl ZeroPoly<CtFr, CtPoly> for CtFFTSettings {
-    fn do_zero_poly_mul_partial(&self, idxs: &[usize], stride: usize) -> Result<CtPoly, String> {
+    fn do_zero_poly_mul_partial(&self, idxs: &[usize], stride: usize) -> Result<CtPoly, Error> {
         self.do_zero_poly_mul_partial(idxs, stride)
             .map(|coeffs| CtPoly {
                 coeffs: coeffs.into_vec(),
             })
     }
 
-    fn reduce_partials(&self, domain_size: usize, partials: &[CtPoly]) -> Result<CtPoly, String> {
+    fn reduce_partials(&self, domain_size: usize, partials: &[CtPoly]) -> Result<CtPoly, Error> {
         self.reduce_partials(
             domain_size,
             partials
@@ -178,7 +181,7 @@ impl ZeroPoly<CtFr, CtPoly> for CtFFTSettings {
         &self,
         domain_size: usize,
         missing_idxs: &[usize],
-    ) -> Result<(Vec<CtFr>, CtPoly), String> {
+    ) -> Result<(Vec<CtFr>, CtPoly), Error> {
         let zero_eval: Vec<CtFr>;
         let mut zero_poly: CtPoly;
 
@@ -189,13 +192,17 @@ impl ZeroPoly<CtFr, CtPoly> for CtFFTSettings {
         }
 
         if missing_idxs.len() >= domain_size {
-            return Err(String::from("Missing idxs greater than domain size"));
+            return Err(Error::BadArgs(String::from(
+                "Missing idxs greater than domain size",
+            )));
         } else if domain_size > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Domain size greater than fft_settings.max_width",
-            ));
+            )));
         } else if !domain_size.is_power_of_two() {
-            return Err(String::from("Domain size must be a power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "Domain size must be a power of 2",
+            )));
         }
 
         let missing_per_partial = DEGREE_OF_PARTIAL - 1; // Number of missing idxs needed per partial
@@ -5,11 +5,13 @@ mod tests {
         fr_div_by_zero, fr_div_works, fr_equal_works, fr_from_uint64_works, fr_is_null_works,
         fr_is_one_works, fr_is_zero_works, fr_negate_works, fr_pow_works, fr_uint64s_roundtrip,
         g1_identity_is_identity, g1_identity_is_infinity, g1_make_linear_combination,
-        g1_random_linear_combination, log_2_byte_works, p1_mul_works, p1_sub_works,
-        p2_add_or_dbl_works, p2_mul_works, p2_sub_works, pairings_work,
+        g1_random_linear_combination, log_2_byte_works, multi_pairings_work, p1_mul_works,
+        p1_sub_works, p2_add_or_dbl_works, p2_mul_works, p2_sub_works, pairings_work,
     };
 
-    use rust_kzg_constantine::kzg_proofs::{g1_linear_combination, pairings_verify};
+    use rust_kzg_constantine::kzg_proofs::{
+        g1_linear_combination, multi_pairings_verify, pairings_verify,
+    };
     use rust_kzg_constantine::types::fp::CtFp;
     use rust_kzg_constantine::types::fr::CtFr;
     use rust_kzg_constantine::types::g1::{CtG1, CtG1Affine};
@@ -119,4 +121,9 @@ mod tests {
     fn pairings_work_() {
         pairings_work::<CtFr, CtG1, CtG2>(&pairings_verify)
     }
+
+    #[test]
+    fn multi_pairings_work_() {
+        multi_pairings_work::<CtFr, CtG1, CtG2>(&multi_pairings_verify)
+    }
 }
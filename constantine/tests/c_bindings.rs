@@ -3,17 +3,27 @@ mod tests {
     use kzg_bench::tests::c_bindings::{
         blob_to_kzg_commitment_invalid_blob_test,
         compute_blob_kzg_proof_commitment_is_point_at_infinity_test,
-        compute_blob_kzg_proof_invalid_blob_test, free_trusted_setup_null_ptr_test,
-        free_trusted_setup_set_all_values_to_null_test,
-        load_trusted_setup_file_invalid_format_test, load_trusted_setup_file_valid_format_test,
-        load_trusted_setup_invalid_form_test, load_trusted_setup_invalid_g1_byte_length_test,
-        load_trusted_setup_invalid_g1_point_test, load_trusted_setup_invalid_g2_byte_length_test,
-        load_trusted_setup_invalid_g2_point_test,
+        compute_blob_kzg_proof_invalid_blob_test, das_c_api_test, fk20_multi_c_api_test,
+        free_trusted_setup_keeps_other_settings_test, free_trusted_setup_null_ptr_test,
+        free_trusted_setup_set_all_values_to_null_test, handle_api_multiple_setups_test,
+        kzg_to_versioned_hash_test, load_trusted_setup_file_invalid_format_test,
+        load_trusted_setup_file_valid_format_test, load_trusted_setup_invalid_form_test,
+        load_trusted_setup_invalid_g1_byte_length_test, load_trusted_setup_invalid_g1_point_test,
+        load_trusted_setup_invalid_g2_byte_length_test, load_trusted_setup_invalid_g2_point_test,
+        load_trusted_setup_invalid_pointers_test, point_evaluation_precompile_invalid_input_test,
+        verify_blob_kzg_proof_batch_per_item_test,
     };
     use rust_kzg_constantine::eip_4844::{
-        blob_to_kzg_commitment, compute_blob_kzg_proof, free_trusted_setup, load_trusted_setup,
-        load_trusted_setup_file,
+        blob_to_kzg_commitment, compute_blob_kzg_proof, free_trusted_setup, kzg_to_versioned_hash,
+        kzg_v1_blob_to_kzg_commitment, kzg_v1_compute_blob_kzg_proof, kzg_v1_das_fft_extension,
+        kzg_v1_data_availability_optimized, kzg_v1_fft_fr, kzg_v1_free_fft_settings,
+        kzg_v1_free_fk20_multi_settings, kzg_v1_free_trusted_setup,
+        kzg_v1_load_trusted_setup_contents, kzg_v1_new_fft_settings,
+        kzg_v1_new_fk20_multi_settings, kzg_v1_recover_poly_from_samples,
+        kzg_v1_verify_blob_kzg_proof, load_trusted_setup, load_trusted_setup_file,
+        point_evaluation_precompile, verify_blob_kzg_proof_batch_per_item, CtBackend,
     };
+    use rust_kzg_constantine::utils::generate_trusted_setup;
 
     #[test]
     fn blob_to_kzg_commitment_invalid_blob() {
@@ -55,6 +65,11 @@ mod tests {
         load_trusted_setup_file_valid_format_test(load_trusted_setup_file);
     }
 
+    #[test]
+    fn load_trusted_setup_invalid_pointers() {
+        load_trusted_setup_invalid_pointers_test(load_trusted_setup, load_trusted_setup_file);
+    }
+
     #[test]
     fn free_trusted_setup_null_ptr() {
         free_trusted_setup_null_ptr_test(free_trusted_setup);
@@ -77,4 +92,68 @@ mod tests {
             load_trusted_setup_file,
         );
     }
+
+    #[test]
+    fn verify_blob_kzg_proof_batch_per_item_() {
+        verify_blob_kzg_proof_batch_per_item_test(
+            blob_to_kzg_commitment,
+            compute_blob_kzg_proof,
+            verify_blob_kzg_proof_batch_per_item,
+            load_trusted_setup_file,
+        );
+    }
+
+    #[test]
+    fn kzg_to_versioned_hash_() {
+        kzg_to_versioned_hash_test(kzg_to_versioned_hash);
+    }
+
+    #[test]
+    fn point_evaluation_precompile_invalid_input() {
+        point_evaluation_precompile_invalid_input_test(
+            point_evaluation_precompile,
+            load_trusted_setup_file,
+        );
+    }
+    #[test]
+    fn free_trusted_setup_keeps_other_settings() {
+        free_trusted_setup_keeps_other_settings_test(
+            blob_to_kzg_commitment,
+            free_trusted_setup,
+            load_trusted_setup_file,
+        );
+    }
+
+    #[test]
+    fn handle_api_multiple_setups() {
+        handle_api_multiple_setups_test(
+            kzg_v1_load_trusted_setup_contents,
+            kzg_v1_blob_to_kzg_commitment,
+            kzg_v1_compute_blob_kzg_proof,
+            kzg_v1_verify_blob_kzg_proof,
+            kzg_v1_free_trusted_setup,
+        );
+    }
+    #[test]
+    fn das_c_api() {
+        das_c_api_test::<CtBackend>(
+            kzg_v1_new_fft_settings,
+            kzg_v1_fft_fr,
+            kzg_v1_das_fft_extension,
+            kzg_v1_recover_poly_from_samples,
+            kzg_v1_free_fft_settings,
+        );
+    }
+
+    #[test]
+    fn fk20_multi_c_api() {
+        fk20_multi_c_api_test::<CtBackend>(
+            kzg_v1_new_fft_settings,
+            kzg_v1_new_fk20_multi_settings,
+            kzg_v1_data_availability_optimized,
+            kzg_v1_free_fk20_multi_settings,
+            kzg_v1_free_fft_settings,
+            &generate_trusted_setup,
+        );
+    }
 }
@@ -3,13 +3,20 @@ mod tests {
     use kzg::eip_4844::{
         blob_to_kzg_commitment_rust, blob_to_polynomial, bytes_to_blob,
         compute_blob_kzg_proof_rust, compute_kzg_proof_rust, compute_powers,
-        evaluate_polynomial_in_evaluation_form, verify_blob_kzg_proof_batch_rust,
+        evaluate_polynomial_in_evaluation_form, kzg_to_versioned_hash_rust,
+        load_trusted_setup_rust, point_evaluation_precompile_rust,
+        verify_blob_kzg_proof_batch_failures_rust, verify_blob_kzg_proof_batch_rust,
         verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
     };
+    use kzg::eip_7594::{
+        compute_cells_and_kzg_proofs_rust, load_cell_kzg_settings_rust,
+        recover_cells_and_kzg_proofs_rust, verify_cell_kzg_proof_batch_rust,
+    };
     use kzg::Fr;
 
     use kzg_bench::tests::eip_4844::{
-        blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        blob_committer_test, blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        commit_coeff_and_eval_form_test, compute_and_verify_blob_kzg_proof_custom_width_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
@@ -18,20 +25,29 @@ mod tests {
         compute_kzg_proof_incorrect_blob_length_test,
         compute_kzg_proof_incorrect_commitments_len_test,
         compute_kzg_proof_incorrect_poly_length_test, compute_kzg_proof_incorrect_proofs_len_test,
-        compute_kzg_proof_test, compute_powers_test, test_vectors_blob_to_kzg_commitment,
+        compute_kzg_proof_test, compute_powers_test, dyn_kzg_test, kzg_context_test,
+        kzg_to_versioned_hash_test, load_trusted_setup_formats_test,
+        load_trusted_setup_malformed_json_test, pairing_accumulator_test,
+        point_evaluation_precompile_test, test_vectors_blob_to_kzg_commitment,
         test_vectors_compute_blob_kzg_proof, test_vectors_compute_kzg_proof,
         test_vectors_verify_blob_kzg_proof, test_vectors_verify_blob_kzg_proof_batch,
-        test_vectors_verify_kzg_proof, validate_batched_input_test,
+        test_vectors_verify_kzg_proof, thread_pool_config_test, validate_batched_input_test,
+        verify_blob_kzg_proof_batch_failures_test,
         verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
     };
     use rust_kzg_constantine::consts::SCALE2_ROOT_OF_UNITY;
+    #[cfg(feature = "embedded-mainnet-setup")]
+    use rust_kzg_constantine::eip_4844::load_mainnet_trusted_setup;
     use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_constantine::eip_4844::CtBackend;
     use rust_kzg_constantine::types::fft_settings::expand_root_of_unity;
+    use rust_kzg_constantine::types::fk20_multi_settings::CtFK20MultiSettings;
     use rust_kzg_constantine::types::g1::CtG1Affine;
     use rust_kzg_constantine::types::{
         fft_settings::CtFFTSettings, fp::CtFp, fr::CtFr, g1::CtG1, g2::CtG2,
         kzg_settings::CtKZGSettings, poly::CtPoly,
     };
+    use rust_kzg_constantine::utils::generate_trusted_setup;
 
     #[test]
     pub fn bytes_to_bls_field_test_() {
@@ -79,6 +95,41 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn dyn_kzg_test_() {
+        dyn_kzg_test::<CtBackend>(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn kzg_context_test_() {
+        kzg_context_test::<CtBackend>();
+    }
+
+    #[test]
+    pub fn kzg_to_versioned_hash_test_() {
+        kzg_to_versioned_hash_test(&kzg_to_versioned_hash_rust);
+    }
+
+    #[test]
+    pub fn point_evaluation_precompile_test_() {
+        point_evaluation_precompile_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &point_evaluation_precompile_rust,
+        );
+    }
+
     #[test]
     pub fn compute_and_verify_kzg_proof_round_trip_test_() {
         compute_and_verify_kzg_proof_round_trip_test::<
@@ -185,6 +236,106 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_and_verify_blob_kzg_proof_custom_width_test_() {
+        compute_and_verify_blob_kzg_proof_custom_width_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFK20MultiSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &generate_trusted_setup,
+            &load_trusted_setup_rust,
+            &blob_to_kzg_commitment_rust,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_rust,
+            &load_cell_kzg_settings_rust,
+            &compute_cells_and_kzg_proofs_rust,
+            &recover_cells_and_kzg_proofs_rust,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_formats_test_() {
+        load_trusted_setup_formats_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    pub fn load_trusted_setup_malformed_json_test_() {
+        load_trusted_setup_malformed_json_test();
+    }
+
+    #[test]
+    pub fn blob_committer_test_() {
+        blob_committer_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    pub fn commit_coeff_and_eval_form_test_() {
+        commit_coeff_and_eval_form_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[cfg(feature = "embedded-mainnet-setup")]
+    #[test]
+    pub fn load_mainnet_trusted_setup_test_() {
+        kzg_bench::tests::eip_4844::load_mainnet_trusted_setup_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &load_mainnet_trusted_setup,
+        );
+    }
+
     #[test]
     pub fn verify_kzg_proof_batch_test_() {
         verify_kzg_proof_batch_test::<
@@ -225,6 +376,66 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn verify_blob_kzg_proof_batch_failures_test_() {
+        verify_blob_kzg_proof_batch_failures_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_failures_rust,
+        );
+    }
+
+    #[test]
+    pub fn thread_pool_config_test_() {
+        thread_pool_config_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &verify_blob_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn pairing_accumulator_test_() {
+        pairing_accumulator_test::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_blob_kzg_proof_rust,
+            &compute_kzg_proof_rust,
+        );
+    }
+
     #[test]
     pub fn test_vectors_blob_to_kzg_commitment_() {
         test_vectors_blob_to_kzg_commitment::<
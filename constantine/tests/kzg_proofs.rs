@@ -3,6 +3,7 @@ mod tests {
 
     use kzg_bench::tests::kzg_proofs::{
         commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        verify_pairing_with_g2_secret_works,
     };
 
     use rust_kzg_constantine::types::fft_settings::CtFFTSettings;
@@ -21,6 +22,20 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_verify_pairing_with_g2_secret() {
+        verify_pairing_with_g2_secret_works::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
+
     #[test]
     pub fn test_commit_to_nil_poly() {
         commit_to_nil_poly::<
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::msm::buckets::{
+        test_bucket_accumulation_edge_cases, test_bucket_accumulations_agree,
+    };
+    use kzg_bench::tests::msm::fixed_base::{
+        test_fixed_base_msm, test_fixed_base_msm_short_inputs,
+    };
+    use kzg_bench::tests::msm::msm_strategy::{test_msm_auto_tuning, test_msm_strategies_agree};
+    use kzg_bench::tests::msm::sparse::{test_sparse_msm, test_tiling_pippenger_with_bits};
+    use rust_kzg_constantine::types::{
+        fp::CtFp,
+        fr::CtFr,
+        g1::{CtG1, CtG1Affine, CtG1ProjAddAffine},
+    };
+
+    #[test]
+    fn test_msm_strategies_agree_() {
+        test_msm_strategies_agree::<CtFr, CtG1, CtFp, CtG1Affine, CtG1ProjAddAffine>();
+    }
+
+    #[test]
+    fn test_msm_auto_tuning_() {
+        test_msm_auto_tuning::<CtFr, CtG1, CtFp, CtG1Affine, CtG1ProjAddAffine>();
+    }
+
+    #[test]
+    fn test_fixed_base_msm_() {
+        test_fixed_base_msm::<CtFr, CtG1, CtFp, CtG1Affine>();
+    }
+
+    #[test]
+    fn test_fixed_base_msm_short_inputs_() {
+        test_fixed_base_msm_short_inputs::<CtFr, CtG1, CtFp, CtG1Affine>();
+    }
+
+    #[test]
+    fn test_bucket_accumulations_agree_() {
+        test_bucket_accumulations_agree::<CtFr, CtG1, CtFp, CtG1Affine>();
+    }
+
+    #[test]
+    fn test_bucket_accumulation_edge_cases_() {
+        test_bucket_accumulation_edge_cases::<CtFr, CtG1, CtFp, CtG1Affine>();
+    }
+
+    #[test]
+    fn test_tiling_pippenger_with_bits_() {
+        test_tiling_pippenger_with_bits::<CtFr, CtG1, CtFp, CtG1Affine>();
+    }
+
+    #[test]
+    fn test_sparse_msm_() {
+        test_sparse_msm::<CtFr, CtG1, CtFp, CtG1Affine, CtG1ProjAddAffine>();
+    }
+}
@@ -0,0 +1,66 @@
+// Kept apart from other tests, because the cache is enabled through environment variable, which is shared by the
+// whole test process
+#[cfg(all(test, feature = "bgmw"))]
+mod tests {
+    use kzg::eip_4844::blob_to_kzg_commitment_rust;
+    #[cfg(feature = "embedded-mainnet-setup")]
+    use kzg_bench::tests::msm::precompute_cache::test_mainnet_setup_skips_precomputation_cache;
+    use kzg_bench::tests::msm::precompute_cache::{
+        test_precomputation_cache_rejects_invalid_tables, test_precomputation_env_cache,
+        test_precomputation_table_round_trip,
+    };
+    #[cfg(feature = "embedded-mainnet-setup")]
+    use rust_kzg_constantine::eip_4844::load_mainnet_trusted_setup;
+    use rust_kzg_constantine::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_constantine::types::{
+        fft_settings::CtFFTSettings,
+        fp::CtFp,
+        fr::CtFr,
+        g1::{CtG1, CtG1Affine},
+        g2::CtG2,
+        kzg_settings::CtKZGSettings,
+        poly::CtPoly,
+    };
+
+    #[test]
+    fn test_precomputation_env_cache_() {
+        test_precomputation_env_cache::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-mainnet-setup")]
+    fn test_mainnet_setup_skips_precomputation_cache_() {
+        test_mainnet_setup_skips_precomputation_cache::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&load_mainnet_trusted_setup);
+    }
+
+    #[test]
+    fn test_precomputation_table_round_trip_() {
+        test_precomputation_table_round_trip::<CtFr, CtG1, CtFp, CtG1Affine>();
+    }
+
+    #[test]
+    fn test_precomputation_cache_rejects_invalid_tables_() {
+        test_precomputation_cache_rejects_invalid_tables::<CtFr, CtG1, CtFp, CtG1Affine>();
+    }
+}
@@ -3,6 +3,7 @@ use std::env::set_current_dir;
 use crate::tests::eip_4844::{generate_random_blob_bytes, generate_random_field_element_bytes};
 use criterion::{BatchSize, BenchmarkId, Criterion, Throughput};
 use kzg::eip_4844::TRUSTED_SETUP_PATH;
+use kzg::Error;
 use kzg::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, KZGSettings, Poly, G1, G2};
 
 #[allow(clippy::type_complexity)]
@@ -18,19 +19,19 @@ pub fn bench_eip_4844<
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
     c: &mut Criterion,
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
-    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
     verify_blob_kzg_proof_batch: &dyn Fn(
         &[Vec<TFr>],
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     set_current_dir(env!("CARGO_MANIFEST_DIR")).unwrap();
     let ts = load_trusted_setup(TRUSTED_SETUP_PATH).unwrap();
@@ -1,7 +1,12 @@
 use criterion::Criterion;
 use kzg::{
-    msm::precompute::{precompute, PrecomputationTable},
-    Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1,
+    eip_4844::FIELD_ELEMENTS_PER_BLOB,
+    msm::{
+        msm_impls::{msm, msm_with_strategy},
+        precompute::{precompute, PrecomputationTable},
+        strategy::MsmStrategy,
+    },
+    Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1ProjAddAffine, G1,
 };
 
 #[allow(clippy::type_complexity)]
@@ -46,7 +51,7 @@ pub fn bench_g1_lincomb<
 
     let precomputation = precompute::<TFr, TG1, TG1Fp, TG1Affine>(&points).unwrap();
 
-    if precomputation.is_some() {
+    if precomputation.is_usable(MsmStrategy::Bgmw, num_points) {
         let id = format!(
             "bench_g1_lincomb with precomputation points: '{}'",
             num_points
@@ -59,7 +64,54 @@ pub fn bench_g1_lincomb<
                     points.as_slice(),
                     scalars.as_slice(),
                     num_points,
-                    precomputation.as_ref(),
+                    Some(&precomputation),
+                )
+            })
+        });
+    }
+}
+
+/// Compares MSM of blob-sized inputs with Pippenger alone. Dense scalars must not get slower from the sparse input
+/// check, while zero-padded ones should get faster.
+pub fn bench_g1_lincomb_blob<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    c: &mut Criterion,
+) {
+    let points = (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|_| TG1::rand())
+        .collect::<Vec<_>>();
+    let dense = (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|_| TFr::rand())
+        .collect::<Vec<_>>();
+    let mut padded = vec![TFr::zero(); FIELD_ELEMENTS_PER_BLOB];
+    padded[..FIELD_ELEMENTS_PER_BLOB / 8].clone_from_slice(&dense[..FIELD_ELEMENTS_PER_BLOB / 8]);
+
+    for (name, scalars) in [("dense", &dense), ("zero-padded", &padded)] {
+        let id = format!("bench_g1_lincomb_blob {} scalars", name);
+        c.bench_function(&id, |b| {
+            b.iter(|| {
+                msm::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+                    &points,
+                    scalars,
+                    FIELD_ELEMENTS_PER_BLOB,
+                    None,
+                )
+            })
+        });
+
+        let id = format!("bench_g1_lincomb_blob {} scalars, pippenger only", name);
+        c.bench_function(&id, |b| {
+            b.iter(|| {
+                msm_with_strategy::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+                    MsmStrategy::TilingPippenger,
+                    &points,
+                    scalars,
+                    None,
                 )
             })
         });
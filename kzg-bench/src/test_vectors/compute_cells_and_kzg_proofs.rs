@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Input<'a> {
+    blob: &'a str,
+}
+
+impl Input<'_> {
+    pub fn get_blob_bytes(&self) -> Vec<u8> {
+        hex::decode(&self.blob[2..]).unwrap()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Test<'a> {
+    #[serde(borrow)]
+    pub input: Input<'a>,
+    output: Option<(Vec<String>, Vec<String>)>,
+}
+
+impl Test<'_> {
+    #[allow(clippy::type_complexity)]
+    pub fn get_output(&self) -> Option<(Vec<Vec<u8>>, Vec<Vec<u8>>)> {
+        self.output.as_ref().map(|(cells, proofs)| {
+            (
+                cells
+                    .iter()
+                    .map(|s| hex::decode(&s[2..]).unwrap())
+                    .collect(),
+                proofs
+                    .iter()
+                    .map(|s| hex::decode(&s[2..]).unwrap())
+                    .collect(),
+            )
+        })
+    }
+}
@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Input {
+    cell_indices: Vec<u64>,
+    cells: Vec<String>,
+}
+
+impl Input {
+    pub fn get_cell_indices(&self) -> Vec<u64> {
+        self.cell_indices.clone()
+    }
+
+    pub fn get_cells_bytes(&self) -> Vec<Vec<u8>> {
+        let mut v = Vec::new();
+        for cell in &self.cells {
+            v.push(hex::decode(&cell[2..]).unwrap());
+        }
+        v
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Test {
+    pub input: Input,
+    output: Option<(Vec<String>, Vec<String>)>,
+}
+
+impl Test {
+    #[allow(clippy::type_complexity)]
+    pub fn get_output(&self) -> Option<(Vec<Vec<u8>>, Vec<Vec<u8>>)> {
+        self.output.as_ref().map(|(cells, proofs)| {
+            (
+                cells
+                    .iter()
+                    .map(|s| hex::decode(&s[2..]).unwrap())
+                    .collect(),
+                proofs
+                    .iter()
+                    .map(|s| hex::decode(&s[2..]).unwrap())
+                    .collect(),
+            )
+        })
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Input {
+    commitments: Vec<String>,
+    cell_indices: Vec<u64>,
+    cells: Vec<String>,
+    proofs: Vec<String>,
+}
+
+impl Input {
+    pub fn get_commitments_bytes(&self) -> Vec<Vec<u8>> {
+        let mut v = Vec::new();
+        for commitment in &self.commitments {
+            v.push(hex::decode(&commitment[2..]).unwrap());
+        }
+        v
+    }
+
+    pub fn get_cell_indices(&self) -> Vec<u64> {
+        self.cell_indices.clone()
+    }
+
+    pub fn get_cells_bytes(&self) -> Vec<Vec<u8>> {
+        let mut v = Vec::new();
+        for cell in &self.cells {
+            v.push(hex::decode(&cell[2..]).unwrap());
+        }
+        v
+    }
+
+    pub fn get_proofs_bytes(&self) -> Vec<Vec<u8>> {
+        let mut v = Vec::new();
+        for proof in &self.proofs {
+            v.push(hex::decode(&proof[2..]).unwrap());
+        }
+        v
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Test {
+    pub input: Input,
+    output: Option<bool>,
+}
+
+impl Test {
+    pub fn get_output(&self) -> Option<bool> {
+        self.output
+    }
+}
@@ -0,0 +1,119 @@
+use kzg::blob_codec::{
+    blob_capacity, decode_blob, decode_blobs, encode_blob, encode_blobs, BlobCodecVersion,
+};
+use kzg::eip_4844::{bytes_to_blob, BYTES_PER_BLOB, FIELD_ELEMENTS_PER_BLOB};
+use kzg::Fr;
+use rand::Rng;
+
+const VERSIONS: [BlobCodecVersion; 2] = [BlobCodecVersion::Bytes31, BlobCodecVersion::Bits254];
+
+fn random_payload(len: usize) -> Vec<u8> {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+pub fn blob_codec_roundtrip_test<TFr: Fr>() {
+    for version in VERSIONS {
+        let capacity = blob_capacity(version, FIELD_ELEMENTS_PER_BLOB);
+        for len in [0, 1, 30, 31, 32, 127, 128, 1000, capacity - 1, capacity] {
+            let payload = random_payload(len);
+            let blob = encode_blob(&payload, version, FIELD_ELEMENTS_PER_BLOB).unwrap();
+            assert_eq!(blob.len(), BYTES_PER_BLOB);
+
+            // Every field element must be canonical
+            assert!(bytes_to_blob::<TFr>(&blob).is_ok());
+            assert_eq!(decode_blob(&blob).unwrap(), payload);
+        }
+
+        let payload = random_payload(capacity + 1);
+        assert!(encode_blob(&payload, version, FIELD_ELEMENTS_PER_BLOB).is_err());
+    }
+
+    // Maximum payloads are packed densely into all but the header field element
+    let payload = vec![0xff; blob_capacity(BlobCodecVersion::Bits254, FIELD_ELEMENTS_PER_BLOB)];
+    let blob = encode_blob(&payload, BlobCodecVersion::Bits254, FIELD_ELEMENTS_PER_BLOB).unwrap();
+    assert!(bytes_to_blob::<TFr>(&blob).is_ok());
+    assert_eq!(decode_blob(&blob).unwrap(), payload);
+}
+
+pub fn blob_codec_multiple_blobs_test<TFr: Fr>() {
+    let width = 16;
+    for version in VERSIONS {
+        let capacity = blob_capacity(version, width);
+        for len in [0, capacity, capacity + 1, 5 * capacity - 3] {
+            let payload = random_payload(len);
+            let blobs = encode_blobs(&payload, version, width).unwrap();
+            assert_eq!(blobs.len(), len.div_ceil(capacity).max(1));
+            for blob in &blobs {
+                assert!(bytes_to_blob::<TFr>(blob).is_ok());
+            }
+            assert_eq!(decode_blobs(&blobs).unwrap(), payload);
+        }
+    }
+}
+
+pub fn blob_codec_rejects_invalid_blob_sequences_test() {
+    let width = 16;
+    for version in VERSIONS {
+        let capacity = blob_capacity(version, width);
+        let payload = random_payload(3 * capacity);
+        let blobs = encode_blobs(&payload, version, width).unwrap();
+        assert_eq!(blobs.len(), 3);
+
+        // Dropped blobs
+        assert!(decode_blobs(&blobs[..2]).is_err());
+        assert!(decode_blobs(&blobs[1..]).is_err());
+        assert!(decode_blobs(&[&blobs[0], &blobs[2]]).is_err());
+        assert!(decode_blobs::<Vec<u8>>(&[]).is_err());
+
+        // Duplicated blobs
+        assert!(decode_blobs(&[&blobs[0], &blobs[0], &blobs[2]]).is_err());
+        let mut duplicated = blobs.clone();
+        duplicated.push(blobs[2].clone());
+        assert!(decode_blobs(&duplicated).is_err());
+
+        // Reordered blobs
+        assert!(decode_blobs(&[&blobs[1], &blobs[0], &blobs[2]]).is_err());
+        assert!(decode_blobs(&[&blobs[0], &blobs[2], &blobs[1]]).is_err());
+
+        // First blob of another payload in place of the second one
+        let other = encode_blobs(&random_payload(3 * capacity), version, width).unwrap();
+        assert!(decode_blobs(&[&blobs[0], &other[0], &blobs[2]]).is_err());
+
+        // A blob of a split payload is not a payload on its own
+        assert!(decode_blob(&blobs[0]).is_err());
+        assert_eq!(decode_blobs(&blobs).unwrap(), payload);
+    }
+}
+
+pub fn blob_codec_rejects_invalid_blobs_test() {
+    for version in VERSIONS {
+        let payload = random_payload(100);
+        let blob = encode_blob(&payload, version, FIELD_ELEMENTS_PER_BLOB).unwrap();
+
+        // Non-zero padding
+        let mut invalid = blob.clone();
+        *invalid.last_mut().unwrap() = 1;
+        assert!(decode_blob(&invalid).is_err());
+
+        // Unknown version
+        let mut invalid = blob.clone();
+        invalid[1] = 0xff;
+        assert!(decode_blob(&invalid).is_err());
+
+        // Length over capacity
+        let mut invalid = blob.clone();
+        invalid[2..6].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(decode_blob(&invalid).is_err());
+
+        // Non-zero header padding
+        let mut invalid = blob.clone();
+        invalid[31] = 1;
+        assert!(decode_blob(&invalid).is_err());
+
+        // Not a power of two number of field elements
+        assert!(decode_blob(&blob[..blob.len() - 32]).is_err());
+    }
+
+    assert!(encode_blob(&[], BlobCodecVersion::Bytes31, 3).is_err());
+}
@@ -254,3 +254,42 @@ pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     assert!(pairings_verify(&g1_3, &g2_5, &g1_5, &g2_3));
     assert!(!pairings_verify(&g1_3, &g2_3, &g1_5, &g2_5));
 }
+
+pub fn multi_pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
+    multi_pairings_verify: &dyn Fn(&[TG1], &[TG2]) -> bool,
+) {
+    // Verify that e([3]g1, [5]g2) * e([7]g1, [11]g2) * e([-92]g1, g2) = 1
+    let three = TFr::from_u64(3);
+    let five = TFr::from_u64(5);
+    let seven = TFr::from_u64(7);
+    let eleven = TFr::from_u64(11);
+    let minus_92 = TFr::from_u64(92).negate();
+
+    let mut ps = vec![
+        TG1::generator().mul(&three),
+        TG1::generator().mul(&seven),
+        TG1::generator().mul(&minus_92),
+    ];
+    let mut qs = vec![
+        TG2::generator().mul(&five),
+        TG2::generator().mul(&eleven),
+        TG2::generator(),
+    ];
+    assert!(multi_pairings_verify(&ps, &qs));
+
+    // Pairs with the point at infinity do not change the product
+    ps.push(TG1::identity());
+    qs.push(TG2::generator().mul(&three));
+    ps.push(TG1::generator().mul(&five));
+    qs.push(TG2::generator().mul(&TFr::zero()));
+    assert!(multi_pairings_verify(&ps, &qs));
+
+    // Empty product is one
+    assert!(multi_pairings_verify(&[], &[]));
+
+    // Slices of different lengths are rejected
+    assert!(!multi_pairings_verify(&ps[..2], &qs[..3]));
+
+    ps[0] = TG1::generator().mul(&five);
+    assert!(!multi_pairings_verify(&ps, &qs));
+}
@@ -6,15 +6,20 @@ use std::{
     ptr::null_mut,
 };
 
+use kzg::backend::Backend;
+use kzg::c_api::{FFTSettingsHandle, FK20MultiSettingsHandle, KzgHandle};
 use kzg::eip_4844::{
-    load_trusted_setup_string, Blob, Bytes48, CKZGSettings, KZGCommitment, KZGProof,
-    BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, BYTES_PER_PROOF,
-    C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK,
+    kzg_to_versioned_hash_rust, load_trusted_setup_string, Blob, Bytes32, Bytes48, CKZGSettings,
+    KZGCommitment, KZGProof, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1,
+    BYTES_PER_G2, BYTES_PER_PROOF, C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK,
+    C_KZG_RET_PROOF_MISMATCH, POINT_EVALUATION_INPUT_LENGTH, POINT_EVALUATION_OUTPUT_LENGTH,
 };
+use kzg::{FFTFr, FFTSettings, FK20MultiSettings, Fr, KZGSettings, Poly, DAS, G1, G2};
 use libc::FILE;
 
 use crate::tests::{
     eip_4844::generate_random_blob_bytes,
+    fk20_proofs::SECRET,
     utils::{get_manifest_dir, get_trusted_setup_path},
 };
 
@@ -418,6 +423,78 @@ pub fn load_trusted_setup_file_valid_format_test(
     }
 }
 
+pub fn load_trusted_setup_invalid_pointers_test(
+    load_trusted_setup: unsafe extern "C" fn(
+        *mut CKZGSettings,
+        *const u8,
+        usize,
+        *const u8,
+        usize,
+    ) -> C_KZG_RET,
+    load_trusted_setup_file: unsafe extern "C" fn(
+        out: *mut CKZGSettings,
+        in_: *mut FILE,
+    ) -> C_KZG_RET,
+) {
+    let mut file = File::open(get_trusted_setup_path()).unwrap();
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).unwrap();
+    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents).unwrap();
+    let n1 = g1_bytes.len() / BYTES_PER_G1;
+    let n2 = g2_bytes.len() / BYTES_PER_G2;
+
+    let mut settings = CKZGSettings {
+        g1_values: null_mut(),
+        g2_values: null_mut(),
+        max_width: 0,
+        roots_of_unity: null_mut(),
+    };
+
+    unsafe {
+        assert_eq!(
+            load_trusted_setup(null_mut(), g1_bytes.as_ptr(), n1, g2_bytes.as_ptr(), n2),
+            C_KZG_RET_BADARGS
+        );
+        assert_eq!(
+            load_trusted_setup(&mut settings, core::ptr::null(), n1, g2_bytes.as_ptr(), n2),
+            C_KZG_RET_BADARGS
+        );
+        assert_eq!(
+            load_trusted_setup(&mut settings, g1_bytes.as_ptr(), n1, core::ptr::null(), n2),
+            C_KZG_RET_BADARGS
+        );
+
+        // Byte lengths of these point counts overflow
+        assert_eq!(
+            load_trusted_setup(
+                &mut settings,
+                g1_bytes.as_ptr(),
+                usize::MAX / BYTES_PER_G1 + 1,
+                g2_bytes.as_ptr(),
+                n2
+            ),
+            C_KZG_RET_BADARGS
+        );
+        assert_eq!(
+            load_trusted_setup(
+                &mut settings,
+                g1_bytes.as_ptr(),
+                n1,
+                g2_bytes.as_ptr(),
+                usize::MAX / BYTES_PER_G2 + 1
+            ),
+            C_KZG_RET_BADARGS
+        );
+
+        assert_eq!(
+            load_trusted_setup_file(&mut settings, null_mut()),
+            C_KZG_RET_BADARGS
+        );
+    }
+
+    assert!(settings.g1_values.is_null());
+}
+
 pub fn free_trusted_setup_null_ptr_test(
     free_trusted_setup: unsafe extern "C" fn(s: *mut CKZGSettings) -> (),
 ) {
@@ -568,3 +645,595 @@ pub fn compute_blob_kzg_proof_zero_input_test(
 
     assert_eq!(out, C_KZG_RET_OK);
 }
+
+pub fn verify_blob_kzg_proof_batch_per_item_test(
+    blob_to_kzg_commitment: unsafe extern "C" fn(
+        out: *mut KZGCommitment,
+        blob: *const Blob,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    compute_blob_kzg_proof: unsafe extern "C" fn(
+        out: *mut KZGProof,
+        blob: *const Blob,
+        commitment_bytes: *const Bytes48,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    verify_blob_kzg_proof_batch_per_item: unsafe extern "C" fn(
+        results: *mut bool,
+        blobs: *const Blob,
+        commitments_bytes: *const Bytes48,
+        proofs_bytes: *const Bytes48,
+        n: usize,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    load_trusted_setup_file: unsafe extern "C" fn(
+        out: *mut CKZGSettings,
+        in_: *mut FILE,
+    ) -> C_KZG_RET,
+) {
+    const N_SAMPLES: usize = 4;
+
+    let settings = get_ckzg_settings(load_trusted_setup_file);
+    let mut rng = rand::thread_rng();
+
+    let mut blobs = Vec::with_capacity(N_SAMPLES);
+    let mut commitments = Vec::with_capacity(N_SAMPLES);
+    let mut proofs = Vec::with_capacity(N_SAMPLES);
+    for _ in 0..N_SAMPLES {
+        let blob = Blob {
+            bytes: generate_random_blob_bytes(&mut rng),
+        };
+
+        let mut commitment = KZGCommitment {
+            bytes: [0; BYTES_PER_COMMITMENT],
+        };
+        assert_eq!(
+            unsafe { blob_to_kzg_commitment(&mut commitment, &blob, &settings) },
+            C_KZG_RET_OK
+        );
+        let commitment = Bytes48 {
+            bytes: commitment.bytes,
+        };
+
+        let mut proof = KZGProof {
+            bytes: [0; BYTES_PER_PROOF],
+        };
+        assert_eq!(
+            unsafe { compute_blob_kzg_proof(&mut proof, &blob, &commitment, &settings) },
+            C_KZG_RET_OK
+        );
+
+        blobs.push(blob);
+        commitments.push(commitment);
+        proofs.push(Bytes48 { bytes: proof.bytes });
+    }
+
+    // Overwrite third proof with an incorrect one
+    proofs[2] = Bytes48 {
+        bytes: proofs[0].bytes,
+    };
+
+    let mut results = [false; N_SAMPLES];
+    let out = unsafe {
+        verify_blob_kzg_proof_batch_per_item(
+            results.as_mut_ptr(),
+            blobs.as_ptr(),
+            commitments.as_ptr(),
+            proofs.as_ptr(),
+            N_SAMPLES,
+            &settings,
+        )
+    };
+
+    assert_eq!(out, C_KZG_RET_OK);
+    assert_eq!(results, [true, true, false, true]);
+}
+
+pub fn kzg_to_versioned_hash_test(
+    kzg_to_versioned_hash: unsafe extern "C" fn(
+        out: *mut Bytes32,
+        commitment: *const KZGCommitment,
+    ) -> C_KZG_RET,
+) {
+    let mut commitment = KZGCommitment {
+        bytes: [0; BYTES_PER_COMMITMENT],
+    };
+    commitment.bytes[0] = 0xc0;
+    let mut out = Bytes32 { bytes: [0; 32] };
+
+    let ret = unsafe { kzg_to_versioned_hash(&mut out, &commitment) };
+
+    assert_eq!(ret, C_KZG_RET_OK);
+    assert_eq!(
+        hex::encode(out.bytes),
+        "010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014"
+    );
+}
+
+pub fn point_evaluation_precompile_invalid_input_test(
+    point_evaluation_precompile: unsafe extern "C" fn(
+        out: *mut u8,
+        input: *const u8,
+        input_len: usize,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    load_trusted_setup_file: unsafe extern "C" fn(
+        out: *mut CKZGSettings,
+        in_: *mut FILE,
+    ) -> C_KZG_RET,
+) {
+    let settings = get_ckzg_settings(load_trusted_setup_file);
+
+    let mut out = [0u8; POINT_EVALUATION_OUTPUT_LENGTH];
+    let input = [0u8; POINT_EVALUATION_INPUT_LENGTH + 1];
+
+    // Wrong input length
+    let ret = unsafe {
+        point_evaluation_precompile(out.as_mut_ptr(), input.as_ptr(), input.len(), &settings)
+    };
+    assert_eq!(ret, C_KZG_RET_BADARGS);
+
+    // Versioned hash does not match commitment
+    let ret = unsafe {
+        point_evaluation_precompile(
+            out.as_mut_ptr(),
+            input.as_ptr(),
+            POINT_EVALUATION_INPUT_LENGTH,
+            &settings,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_BADARGS);
+
+    // Zero polynomial, committed to by the point at infinity, does not evaluate to one
+    let mut commitment = [0u8; BYTES_PER_COMMITMENT];
+    commitment[0] = 0xc0;
+    let mut input = Vec::with_capacity(POINT_EVALUATION_INPUT_LENGTH);
+    input.extend_from_slice(&kzg_to_versioned_hash_rust(&commitment));
+    input.extend_from_slice(&[0u8; BYTES_PER_FIELD_ELEMENT]);
+    input.extend_from_slice(&[0u8; BYTES_PER_FIELD_ELEMENT - 1]);
+    input.push(1);
+    input.extend_from_slice(&commitment);
+    input.extend_from_slice(&commitment);
+    let ret = unsafe {
+        point_evaluation_precompile(out.as_mut_ptr(), input.as_ptr(), input.len(), &settings)
+    };
+    assert_eq!(ret, C_KZG_RET_PROOF_MISMATCH);
+}
+
+pub fn free_trusted_setup_keeps_other_settings_test(
+    blob_to_kzg_commitment: unsafe extern "C" fn(
+        out: *mut KZGCommitment,
+        blob: *const Blob,
+        s: &CKZGSettings,
+    ) -> C_KZG_RET,
+    free_trusted_setup: unsafe extern "C" fn(s: *mut CKZGSettings) -> (),
+    load_trusted_setup_file: unsafe extern "C" fn(
+        out: *mut CKZGSettings,
+        in_: *mut FILE,
+    ) -> C_KZG_RET,
+) {
+    let mut first = get_ckzg_settings(load_trusted_setup_file);
+    let mut second = get_ckzg_settings(load_trusted_setup_file);
+
+    let mut rng = rand::thread_rng();
+    let blob = Blob {
+        bytes: generate_random_blob_bytes(&mut rng),
+    };
+    let mut expected = KZGCommitment {
+        bytes: [0; BYTES_PER_COMMITMENT],
+    };
+    assert_eq!(
+        unsafe { blob_to_kzg_commitment(&mut expected, &blob, &first) },
+        C_KZG_RET_OK
+    );
+
+    unsafe {
+        free_trusted_setup(&mut first);
+    }
+
+    let mut commitment = KZGCommitment {
+        bytes: [0; BYTES_PER_COMMITMENT],
+    };
+    assert_eq!(
+        unsafe { blob_to_kzg_commitment(&mut commitment, &blob, &second) },
+        C_KZG_RET_OK
+    );
+    assert_eq!(commitment.bytes, expected.bytes);
+
+    unsafe {
+        free_trusted_setup(&mut second);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn handle_api_multiple_setups_test<B: Backend>(
+    load_trusted_setup_contents: unsafe extern "C" fn(
+        out: *mut *mut KzgHandle<B>,
+        contents: *const u8,
+        len: usize,
+    ) -> C_KZG_RET,
+    blob_to_kzg_commitment: unsafe extern "C" fn(
+        out: *mut KZGCommitment,
+        blob: *const Blob,
+        handle: *const KzgHandle<B>,
+    ) -> C_KZG_RET,
+    compute_blob_kzg_proof: unsafe extern "C" fn(
+        out: *mut KZGProof,
+        blob: *const Blob,
+        commitment_bytes: *const Bytes48,
+        handle: *const KzgHandle<B>,
+    ) -> C_KZG_RET,
+    verify_blob_kzg_proof: unsafe extern "C" fn(
+        ok: *mut bool,
+        blob: *const Blob,
+        commitment_bytes: *const Bytes48,
+        proof_bytes: *const Bytes48,
+        handle: *const KzgHandle<B>,
+    ) -> C_KZG_RET,
+    free_trusted_setup: unsafe extern "C" fn(handle: *mut KzgHandle<B>),
+) {
+    let mut contents = Vec::new();
+    File::open(get_trusted_setup_path())
+        .unwrap()
+        .read_to_end(&mut contents)
+        .unwrap();
+
+    // Invalid setup leaves no handle behind
+    let mut handle = null_mut();
+    let ret = unsafe { load_trusted_setup_contents(&mut handle, contents.as_ptr(), 100) };
+    assert_eq!(ret, C_KZG_RET_BADARGS);
+    assert!(handle.is_null());
+
+    let mut first = null_mut();
+    let mut second = null_mut();
+    unsafe {
+        assert_eq!(
+            load_trusted_setup_contents(&mut first, contents.as_ptr(), contents.len()),
+            C_KZG_RET_OK
+        );
+        assert_eq!(
+            load_trusted_setup_contents(&mut second, contents.as_ptr(), contents.len()),
+            C_KZG_RET_OK
+        );
+    }
+    assert_ne!(first, second);
+
+    let mut rng = rand::thread_rng();
+    let blob = Blob {
+        bytes: generate_random_blob_bytes(&mut rng),
+    };
+
+    // Handles are used from two threads at once, then only the second one is kept
+    let commit = |handle: usize| {
+        let mut commitment = KZGCommitment {
+            bytes: [0; BYTES_PER_COMMITMENT],
+        };
+        let ret = unsafe {
+            blob_to_kzg_commitment(&mut commitment, &blob, handle as *const KzgHandle<B>)
+        };
+        assert_eq!(ret, C_KZG_RET_OK);
+        commitment.bytes
+    };
+    let (first_address, second_address) = (first as usize, second as usize);
+    let (first_commitment, second_commitment) = std::thread::scope(|scope| {
+        let first = scope.spawn(move || commit(first_address));
+        let second = scope.spawn(move || commit(second_address));
+        (first.join().unwrap(), second.join().unwrap())
+    });
+    assert_eq!(first_commitment, second_commitment);
+
+    unsafe {
+        free_trusted_setup(first);
+    }
+
+    let commitment = Bytes48 {
+        bytes: second_commitment,
+    };
+    let mut proof = KZGProof {
+        bytes: [0; BYTES_PER_PROOF],
+    };
+    let mut ok = false;
+    unsafe {
+        assert_eq!(
+            compute_blob_kzg_proof(&mut proof, &blob, &commitment, second),
+            C_KZG_RET_OK
+        );
+        let proof = Bytes48 { bytes: proof.bytes };
+        assert_eq!(
+            verify_blob_kzg_proof(&mut ok, &blob, &commitment, &proof, second),
+            C_KZG_RET_OK
+        );
+        assert!(ok);
+
+        assert_eq!(
+            verify_blob_kzg_proof(&mut ok, &blob, &commitment, &proof, null_mut()),
+            C_KZG_RET_BADARGS
+        );
+
+        free_trusted_setup(second);
+    }
+}
+
+fn zeroed_bytes32(n: usize) -> Vec<Bytes32> {
+    (0..n).map(|_| Bytes32 { bytes: [0; 32] }).collect()
+}
+
+fn fr_to_bytes32<TFr: Fr>(values: &[TFr]) -> Vec<Bytes32> {
+    values
+        .iter()
+        .map(|value| Bytes32 {
+            bytes: value.to_bytes(),
+        })
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
+pub fn das_c_api_test<B: Backend>(
+    new_fft_settings: unsafe extern "C" fn(
+        out: *mut *mut FFTSettingsHandle<B>,
+        max_scale: usize,
+    ) -> C_KZG_RET,
+    fft_fr: unsafe extern "C" fn(
+        out: *mut Bytes32,
+        data: *const Bytes32,
+        n: usize,
+        inverse: bool,
+        fs: *const FFTSettingsHandle<B>,
+    ) -> C_KZG_RET,
+    das_fft_extension: unsafe extern "C" fn(
+        out: *mut Bytes32,
+        evens: *const Bytes32,
+        n: usize,
+        fs: *const FFTSettingsHandle<B>,
+    ) -> C_KZG_RET,
+    recover_poly_from_samples: unsafe extern "C" fn(
+        out: *mut Bytes32,
+        samples: *const Bytes32,
+        present: *const bool,
+        n: usize,
+        fs: *const FFTSettingsHandle<B>,
+    ) -> C_KZG_RET,
+    free_fft_settings: unsafe extern "C" fn(fs: *mut FFTSettingsHandle<B>),
+) {
+    const SCALE: usize = 4;
+    const WIDTH: usize = 1 << SCALE;
+
+    let expected_fs = B::FFTSettings::new(SCALE).unwrap();
+    let mut fs = null_mut();
+    assert_eq!(unsafe { new_fft_settings(&mut fs, SCALE) }, C_KZG_RET_OK);
+
+    // Polynomial of degree below half of the domain, so that its evaluations can be extended and recovered
+    let coeffs = (0..WIDTH)
+        .map(|i| match i < WIDTH / 2 {
+            true => B::Fr::from_u64(i as u64 * 7 + 3),
+            false => B::Fr::zero(),
+        })
+        .collect::<Vec<_>>();
+    let data = expected_fs.fft_fr(&coeffs, false).unwrap();
+
+    let mut out = zeroed_bytes32(WIDTH);
+    let ret = unsafe {
+        fft_fr(
+            out.as_mut_ptr(),
+            fr_to_bytes32(&coeffs).as_ptr(),
+            WIDTH,
+            false,
+            fs,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_OK);
+    assert_eq!(
+        out.iter().map(|value| value.bytes).collect::<Vec<_>>(),
+        fr_to_bytes32(&data)
+            .iter()
+            .map(|value| value.bytes)
+            .collect::<Vec<_>>()
+    );
+
+    let ret = unsafe {
+        fft_fr(
+            out.as_mut_ptr(),
+            fr_to_bytes32(&data).as_ptr(),
+            WIDTH,
+            true,
+            fs,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_OK);
+    assert_eq!(
+        out.iter().map(|value| value.bytes).collect::<Vec<_>>(),
+        fr_to_bytes32(&coeffs)
+            .iter()
+            .map(|value| value.bytes)
+            .collect::<Vec<_>>()
+    );
+
+    let evens = data.iter().step_by(2).cloned().collect::<Vec<_>>();
+    let odds = expected_fs.das_fft_extension(&evens).unwrap();
+    let mut out = zeroed_bytes32(WIDTH / 2);
+    let ret = unsafe {
+        das_fft_extension(
+            out.as_mut_ptr(),
+            fr_to_bytes32(&evens).as_ptr(),
+            WIDTH / 2,
+            fs,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_OK);
+    assert_eq!(
+        out.iter().map(|value| value.bytes).collect::<Vec<_>>(),
+        fr_to_bytes32(&odds)
+            .iter()
+            .map(|value| value.bytes)
+            .collect::<Vec<_>>()
+    );
+
+    // Every other sample is missing
+    let present = (0..WIDTH).map(|i| i % 2 == 0).collect::<Vec<_>>();
+    let samples = data
+        .iter()
+        .zip(&present)
+        .map(|(value, present)| match present {
+            true => *value,
+            false => B::Fr::zero(),
+        })
+        .collect::<Vec<_>>();
+    let mut out = zeroed_bytes32(WIDTH);
+    let ret = unsafe {
+        recover_poly_from_samples(
+            out.as_mut_ptr(),
+            fr_to_bytes32(&samples).as_ptr(),
+            present.as_ptr(),
+            WIDTH,
+            fs,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_OK);
+    assert_eq!(
+        out.iter().map(|value| value.bytes).collect::<Vec<_>>(),
+        fr_to_bytes32(&data)
+            .iter()
+            .map(|value| value.bytes)
+            .collect::<Vec<_>>()
+    );
+
+    // Domain of settings is too small
+    let ret = unsafe {
+        fft_fr(
+            out.as_mut_ptr(),
+            fr_to_bytes32(&[coeffs.clone(), coeffs.clone()].concat()).as_ptr(),
+            2 * WIDTH,
+            false,
+            fs,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_BADARGS);
+
+    let ret = unsafe { fft_fr(out.as_mut_ptr(), out.as_ptr(), WIDTH, false, null_mut()) };
+    assert_eq!(ret, C_KZG_RET_BADARGS);
+
+    unsafe {
+        free_fft_settings(fs);
+        free_fft_settings(null_mut());
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn fk20_multi_c_api_test<B: Backend>(
+    new_fft_settings: unsafe extern "C" fn(
+        out: *mut *mut FFTSettingsHandle<B>,
+        max_scale: usize,
+    ) -> C_KZG_RET,
+    new_fk20_multi_settings: unsafe extern "C" fn(
+        out: *mut *mut FK20MultiSettingsHandle<B>,
+        g1_bytes: *const u8,
+        num_g1: usize,
+        g2_bytes: *const u8,
+        num_g2: usize,
+        n2: usize,
+        chunk_len: usize,
+        fs: *const FFTSettingsHandle<B>,
+    ) -> C_KZG_RET,
+    data_availability_optimized: unsafe extern "C" fn(
+        out: *mut KZGProof,
+        out_len: usize,
+        coeffs: *const Bytes32,
+        n: usize,
+        fk: *const FK20MultiSettingsHandle<B>,
+    ) -> C_KZG_RET,
+    free_fk20_multi_settings: unsafe extern "C" fn(fk: *mut FK20MultiSettingsHandle<B>),
+    free_fft_settings: unsafe extern "C" fn(fs: *mut FFTSettingsHandle<B>),
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<B::G1>, Vec<B::G2>),
+) {
+    const N: usize = 16;
+    const CHUNK_LEN: usize = 4;
+    const SECRETS_LEN: usize = 2 * N;
+    const SCALE: usize = 5;
+
+    let (s1, s2) = generate_trusted_setup(SECRETS_LEN, SECRET);
+    let expected_fs = B::FFTSettings::new(SCALE).unwrap();
+    let ks = B::KZGSettings::new(&s1, &s2, SECRETS_LEN, &expected_fs).unwrap();
+    let expected_fk = B::FK20MultiSettings::new(&ks, 2 * N, CHUNK_LEN).unwrap();
+
+    let coeffs = (0..N)
+        .map(|i| B::Fr::from_u64(i as u64 * 13 + 1))
+        .collect::<Vec<_>>();
+    let expected = expected_fk
+        .data_availability_optimized(&B::Poly::from_coeffs(&coeffs))
+        .unwrap();
+    assert_eq!(expected.len(), 2 * N / CHUNK_LEN);
+
+    let g1_bytes = s1.iter().flat_map(|g1| g1.to_bytes()).collect::<Vec<_>>();
+    let g2_bytes = s2.iter().flat_map(|g2| g2.to_bytes()).collect::<Vec<_>>();
+
+    let mut fs = null_mut();
+    let mut fk = null_mut();
+    unsafe {
+        assert_eq!(new_fft_settings(&mut fs, SCALE), C_KZG_RET_OK);
+        assert_eq!(
+            new_fk20_multi_settings(
+                &mut fk,
+                g1_bytes.as_ptr(),
+                s1.len(),
+                g2_bytes.as_ptr(),
+                s2.len(),
+                2 * N,
+                CHUNK_LEN,
+                fs,
+            ),
+            C_KZG_RET_OK
+        );
+    }
+
+    let mut proofs = (0..expected.len())
+        .map(|_| KZGProof {
+            bytes: [0; BYTES_PER_PROOF],
+        })
+        .collect::<Vec<_>>();
+    let coeffs = fr_to_bytes32(&coeffs);
+    let ret = unsafe {
+        data_availability_optimized(proofs.as_mut_ptr(), proofs.len(), coeffs.as_ptr(), N, fk)
+    };
+    assert_eq!(ret, C_KZG_RET_OK);
+    assert_eq!(
+        proofs.iter().map(|proof| proof.bytes).collect::<Vec<_>>(),
+        expected
+            .iter()
+            .map(|proof| proof.to_bytes())
+            .collect::<Vec<_>>()
+    );
+
+    // Output has room for fewer proofs, than there are chunks
+    let ret = unsafe {
+        data_availability_optimized(
+            proofs.as_mut_ptr(),
+            proofs.len() - 1,
+            coeffs.as_ptr(),
+            N,
+            fk,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_BADARGS);
+
+    // Chunk is longer than half of the domain
+    let mut invalid = null_mut();
+    let ret = unsafe {
+        new_fk20_multi_settings(
+            &mut invalid,
+            g1_bytes.as_ptr(),
+            s1.len(),
+            g2_bytes.as_ptr(),
+            s2.len(),
+            2 * N,
+            2 * N,
+            fs,
+        )
+    };
+    assert_eq!(ret, C_KZG_RET_BADARGS);
+    assert!(invalid.is_null());
+
+    unsafe {
+        free_fk20_multi_settings(fk);
+        free_fft_settings(fs);
+    }
+}
@@ -4,12 +4,25 @@ use crate::test_vectors::{
     blob_to_kzg_commitment, compute_blob_kzg_proof, compute_kzg_proof, verify_blob_kzg_proof,
     verify_blob_kzg_proof_batch, verify_kzg_proof,
 };
+use crate::tests::fk20_proofs::SECRET;
 use crate::tests::utils::{get_manifest_dir, get_trusted_setup_path};
+use kzg::backend::{Backend, BackendRegistry, DynKzg, KzgContext};
+use kzg::common_utils::reverse_bit_order;
 use kzg::eip_4844::{
-    BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
-    FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_PATH,
+    bytes_to_blob, kzg_to_versioned_hash_rust, Blob, BlobCommitter, Bytes32, Bytes48,
+    PairingAccumulator, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT,
+    BYTES_PER_G1, BYTES_PER_PROOF, BYTES_PER_VERSIONED_HASH, FIELD_ELEMENTS_PER_BLOB,
+    POINT_EVALUATION_INPUT_LENGTH, POINT_EVALUATION_OUTPUT_LENGTH, TRUSTED_SETUP_NUM_G2_POINTS,
+    TRUSTED_SETUP_PATH,
+};
+use kzg::eip_7594::{cells_per_ext_blob, CellKZGSettings, FIELD_ELEMENTS_PER_CELL};
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::trusted_setup::{read_trusted_setup, write_trusted_setup, TrustedSetupFormat};
+use kzg::Error;
+use kzg::{
+    FFTFr, FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify,
+    Poly, FFTG1, G1, G2,
 };
-use kzg::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, KZGSettings, Poly, G1, G2};
 use pathdiff::diff_paths;
 use rand::rngs::{OsRng, ThreadRng};
 use rand::Rng;
@@ -90,8 +103,8 @@ pub fn blob_to_kzg_commitment_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
 
@@ -128,14 +141,14 @@ pub fn compute_kzg_proof_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, Error>,
     evaluate_polynomial_in_evaluation_form: &dyn Fn(
         &TPoly,
         &TFr,
         &TKZGSettings,
-    ) -> Result<TFr, String>,
+    ) -> Result<TFr, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
 
@@ -181,17 +194,17 @@ pub fn compute_and_verify_kzg_proof_round_trip_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, Error>,
     evaluate_polynomial_in_evaluation_form: &dyn Fn(
         &TPoly,
         &TFr,
         &TKZGSettings,
-    ) -> Result<TFr, String>,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    ) -> Result<TFr, Error>,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let mut rng = rand::thread_rng();
@@ -238,17 +251,17 @@ pub fn compute_and_verify_kzg_proof_within_domain_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, Error>,
     evaluate_polynomial_in_evaluation_form: &dyn Fn(
         &TPoly,
         &TFr,
         &TKZGSettings,
-    ) -> Result<TFr, String>,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    ) -> Result<TFr, Error>,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let mut rng = rand::thread_rng();
@@ -292,17 +305,17 @@ pub fn compute_and_verify_kzg_proof_fails_with_incorrect_proof_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, Error>,
     evaluate_polynomial_in_evaluation_form: &dyn Fn(
         &TPoly,
         &TFr,
         &TKZGSettings,
-    ) -> Result<TFr, String>,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    ) -> Result<TFr, Error>,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let mut rng = rand::thread_rng();
@@ -349,11 +362,11 @@ pub fn compute_and_verify_blob_kzg_proof_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
-    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let mut rng = rand::thread_rng();
@@ -384,11 +397,11 @@ pub fn compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
-    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let mut rng = rand::thread_rng();
@@ -411,6 +424,547 @@ pub fn compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test<
     assert!(!result);
 }
 
+/// Serializes a setup of the given width the way trusted setup files store it
+fn custom_width_setup_bytes<
+    TFr: Fr,
+    TG1: G1,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+    field_elements_per_blob: usize,
+) -> (Vec<u8>, Vec<u8>) {
+    let (s1, s2) = generate_trusted_setup(
+        field_elements_per_blob.max(TRUSTED_SETUP_NUM_G2_POINTS),
+        SECRET,
+    );
+
+    // Trusted setup files store G1 points in Lagrange form
+    let fs = TFFTSettings::new(field_elements_per_blob.trailing_zeros() as usize).unwrap();
+    let g1_lagrange = fs.fft_g1(&s1[..field_elements_per_blob], true).unwrap();
+    let g1_bytes = g1_lagrange
+        .iter()
+        .flat_map(|point| point.to_bytes())
+        .collect::<Vec<u8>>();
+    let g2_bytes = s2[..TRUSTED_SETUP_NUM_G2_POINTS]
+        .iter()
+        .flat_map(|point| point.to_bytes())
+        .collect::<Vec<u8>>();
+
+    (g1_bytes, g2_bytes)
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn compute_and_verify_blob_kzg_proof_custom_width_test<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20MultiSettings,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+    load_trusted_setup: &dyn Fn(&[u8], &[u8]) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
+    verify_blob_kzg_proof_batch: &dyn Fn(
+        &[Vec<TFr>],
+        &[TG1],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, Error>,
+    load_cell_kzg_settings: &dyn Fn(
+        &TKZGSettings,
+    ) -> Result<
+        CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+        Error,
+    >,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error>,
+    recover_cells_and_kzg_proofs: &dyn Fn(
+        &[usize],
+        &[Vec<TFr>],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error>,
+    verify_cell_kzg_proof_batch: &dyn Fn(
+        &[TG1],
+        &[usize],
+        &[Vec<TFr>],
+        &[TG1],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<bool, Error>,
+) {
+    let field_elements_per_blob = 16;
+    let (g1_bytes, g2_bytes) = custom_width_setup_bytes::<TFr, TG1, TG2, TFFTSettings>(
+        generate_trusted_setup,
+        field_elements_per_blob,
+    );
+
+    // Widths which are not a power of two are rejected
+    assert!(load_trusted_setup(&g1_bytes[..12 * BYTES_PER_G1], &g2_bytes).is_err());
+
+    let ts = load_trusted_setup(&g1_bytes, &g2_bytes).unwrap();
+    assert_eq!(ts.get_field_elements_per_blob(), field_elements_per_blob);
+
+    let mut rng = rand::thread_rng();
+    let blobs = (0..4)
+        .map(|_| {
+            (0..field_elements_per_blob)
+                .map(|_| TFr::from_u64(rng.gen()))
+                .collect::<Vec<TFr>>()
+        })
+        .collect::<Vec<Vec<TFr>>>();
+
+    let commitments = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment(blob, &ts).unwrap())
+        .collect::<Vec<TG1>>();
+    let proofs = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| compute_blob_kzg_proof(blob, commitment, &ts).unwrap())
+        .collect::<Vec<TG1>>();
+
+    assert!(verify_blob_kzg_proof(&blobs[0], &commitments[0], &proofs[0], &ts).unwrap());
+    assert!(verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &ts).unwrap());
+
+    // Blobs of a different width cannot be used with this setup
+    let blob = vec![TFr::zero(); FIELD_ELEMENTS_PER_BLOB];
+    assert!(blob_to_kzg_commitment(&blob, &ts).is_err());
+
+    // Blobs narrower than a single cell cannot be split into cells
+    assert!(load_cell_kzg_settings(&ts).is_err());
+
+    let field_elements_per_blob = 2 * FIELD_ELEMENTS_PER_CELL;
+    let (g1_bytes, g2_bytes) = custom_width_setup_bytes::<TFr, TG1, TG2, TFFTSettings>(
+        generate_trusted_setup,
+        field_elements_per_blob,
+    );
+    let ts = load_trusted_setup(&g1_bytes, &g2_bytes).unwrap();
+    let cell_ts = load_cell_kzg_settings(&ts).unwrap();
+
+    let blob = (0..field_elements_per_blob)
+        .map(|_| TFr::from_u64(rng.gen()))
+        .collect::<Vec<TFr>>();
+    let commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+
+    let (cells, proofs) = compute_cells_and_kzg_proofs(&blob, &cell_ts).unwrap();
+    let num_cells = cells_per_ext_blob(field_elements_per_blob);
+    assert_eq!(cells.len(), num_cells);
+    assert_eq!(proofs.len(), num_cells);
+
+    let commitments = vec![commitment; num_cells];
+    let cell_indices = (0..num_cells).collect::<Vec<_>>();
+    assert!(
+        verify_cell_kzg_proof_batch(&commitments, &cell_indices, &cells, &proofs, &cell_ts)
+            .unwrap()
+    );
+
+    // Cell indices of the mainnet extended blob are out of range
+    assert!(verify_cell_kzg_proof_batch(
+        &commitments[..1],
+        &[num_cells],
+        &cells[..1],
+        &proofs[..1],
+        &cell_ts
+    )
+    .is_err());
+
+    let (recovered_cells, recovered_proofs) = recover_cells_and_kzg_proofs(
+        &cell_indices[..num_cells / 2],
+        &cells[..num_cells / 2],
+        &cell_ts,
+    )
+    .unwrap();
+    assert!(recovered_cells == cells);
+    assert_eq!(recovered_proofs, proofs);
+
+    // Blobs of a different width cannot be split with this setup
+    let blob = vec![TFr::zero(); FIELD_ELEMENTS_PER_BLOB];
+    assert!(compute_cells_and_kzg_proofs(&blob, &cell_ts).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn load_trusted_setup_formats_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+) {
+    let contents = fs::read(get_trusted_setup_path()).unwrap();
+    let (g1_bytes, g2_bytes) = read_trusted_setup(&contents).unwrap();
+
+    let mut rng = rand::thread_rng();
+    let blob = (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|_| TFr::from_u64(rng.gen()))
+        .collect::<Vec<TFr>>();
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let expected_commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+
+    for format in [
+        TrustedSetupFormat::Text,
+        TrustedSetupFormat::Json,
+        TrustedSetupFormat::Binary,
+        TrustedSetupFormat::BinaryUncompressed,
+    ] {
+        let serialized = write_trusted_setup(&g1_bytes, &g2_bytes, format).unwrap();
+        assert_eq!(TrustedSetupFormat::detect(&serialized), format);
+        assert_eq!(
+            read_trusted_setup(&serialized).unwrap(),
+            (g1_bytes.clone(), g2_bytes.clone())
+        );
+
+        // Truncated files are rejected
+        assert!(read_trusted_setup(&serialized[..serialized.len() - 8]).is_err());
+
+        let path = std::env::temp_dir().join(format!(
+            "rust-kzg-trusted-setup-{}-{:?}",
+            std::process::id(),
+            format
+        ));
+        fs::write(&path, &serialized).unwrap();
+        let loaded_ts = load_trusted_setup(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let commitment = blob_to_kzg_commitment(&blob, &loaded_ts.unwrap()).unwrap();
+        assert!(commitment.equals(&expected_commitment));
+    }
+}
+
+pub fn load_trusted_setup_malformed_json_test() {
+    let contents = fs::read(get_trusted_setup_path()).unwrap();
+    let (g1_bytes, g2_bytes) = read_trusted_setup(&contents).unwrap();
+    let json = String::from_utf8(
+        write_trusted_setup(&g1_bytes, &g2_bytes, TrustedSetupFormat::Json).unwrap(),
+    )
+    .unwrap();
+
+    // Replaces bytes of the first G1 point, starting right after its `0x` prefix
+    let digits = json.find("\"0x").unwrap() + 3;
+    for (range, replacement) in [
+        (0..2, "zz"),
+        (0..2, "+f"),
+        (0..2, " f"),
+        (0..2, "0x"),
+        (0..2, "\u{e9}"),
+        // Multi-byte characters, which straddle a pair of hex digits
+        (1..3, "\u{e9}"),
+        (1..4, "\u{20ac}"),
+        // Too few and too many digits
+        (0..1, ""),
+        (0..0, "0"),
+    ] {
+        let mut malformed = json.clone();
+        malformed.replace_range(digits + range.start..digits + range.end, replacement);
+        assert!(matches!(
+            read_trusted_setup(malformed.as_bytes()),
+            Err(Error::InvalidTrustedSetup(_))
+        ));
+    }
+
+    // Unterminated arrays and strings
+    let g2_key = json.find("\"g2_monomial\"").unwrap();
+    assert!(read_trusted_setup(json[..g2_key].as_bytes()).is_err());
+    assert!(read_trusted_setup(json[..digits + 10].as_bytes()).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn load_mainnet_trusted_setup_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    load_mainnet_trusted_setup: &dyn Fn() -> Result<TKZGSettings, Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mainnet_ts = load_mainnet_trusted_setup().unwrap();
+
+    assert_eq!(
+        mainnet_ts.get_field_elements_per_blob(),
+        FIELD_ELEMENTS_PER_BLOB
+    );
+    assert!(ts
+        .get_g1_secret()
+        .iter()
+        .zip(mainnet_ts.get_g1_secret())
+        .all(|(a, b)| a.equals(b)));
+    assert!(ts
+        .get_g2_secret()
+        .iter()
+        .zip(mainnet_ts.get_g2_secret())
+        .all(|(a, b)| a.equals(b)));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn blob_committer_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+
+    let mut rng = rand::thread_rng();
+    let blob_bytes = generate_random_blob_bytes(&mut rng);
+    let blob = bytes_to_blob::<TFr>(&blob_bytes).unwrap();
+    let expected = blob_to_kzg_commitment(&blob, &ts).unwrap();
+
+    // Whole blob at once
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    committer.push_field_elements(&blob).unwrap();
+    assert_eq!(committer.len(), FIELD_ELEMENTS_PER_BLOB);
+    assert!(committer.finalize().unwrap().equals(&expected));
+
+    // Field elements in uneven chunks
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    for chunk in blob.chunks(1000) {
+        committer.push_field_elements(chunk).unwrap();
+    }
+    assert!(committer.finalize().unwrap().equals(&expected));
+
+    // Byte chunks which are not aligned to field elements
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    for chunk in blob_bytes.chunks(1001) {
+        committer.push_bytes(chunk).unwrap();
+    }
+    assert!(committer.finalize().unwrap().equals(&expected));
+
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    for chunk in blob_bytes.chunks(7) {
+        committer.push_bytes(chunk).unwrap();
+    }
+    assert!(committer.finalize().unwrap().equals(&expected));
+
+    // Partially filled blob is committed as if it was padded with zeros
+    let mut padded = blob[..100].to_vec();
+    padded.resize(FIELD_ELEMENTS_PER_BLOB, TFr::zero());
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    committer.push_field_elements(&blob[..100]).unwrap();
+    assert!(committer
+        .finalize()
+        .unwrap()
+        .equals(&blob_to_kzg_commitment(&padded, &ts).unwrap()));
+
+    // Too many field elements
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    committer.push_field_elements(&blob).unwrap();
+    assert!(committer.push_field_elements(&blob[..1]).is_err());
+
+    // Trailing partial field element
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    committer.push_bytes(&blob_bytes[..40]).unwrap();
+    assert_eq!(committer.len(), 1);
+    assert!(committer.push_field_elements(&blob[1..2]).is_err());
+    assert!(committer.finalize().is_err());
+
+    // Non-canonical field element
+    let mut committer =
+        BlobCommitter::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::new(
+            &ts,
+        );
+    assert!(committer
+        .push_bytes(&[0xff; BYTES_PER_FIELD_ELEMENT])
+        .is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn pairing_accumulator_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    const N_SAMPLES: usize = 4;
+
+    let mut blobs: Vec<Vec<TFr>> = Vec::with_capacity(N_SAMPLES);
+    let mut commitments: Vec<TG1> = Vec::with_capacity(N_SAMPLES);
+    let mut blob_proofs: Vec<TG1> = Vec::with_capacity(N_SAMPLES);
+    let mut zs: Vec<TFr> = Vec::with_capacity(N_SAMPLES);
+    let mut ys: Vec<TFr> = Vec::with_capacity(N_SAMPLES);
+    let mut proofs: Vec<TG1> = Vec::with_capacity(N_SAMPLES);
+
+    for _ in 0..N_SAMPLES {
+        let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+        let commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+        let blob_proof = compute_blob_kzg_proof(&blob, &commitment, &ts).unwrap();
+        let z = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+        let (proof, y) = compute_kzg_proof(&blob, &z, &ts).unwrap();
+
+        blobs.push(blob);
+        commitments.push(commitment);
+        blob_proofs.push(blob_proof);
+        zs.push(z);
+        ys.push(y);
+        proofs.push(proof);
+    }
+
+    let accumulator = PairingAccumulator::<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TG1Fp,
+        TG1Affine,
+    >::new(&ts);
+    assert!(accumulator.is_empty());
+    assert!(accumulator.finalize().unwrap());
+
+    // Proofs of both kinds, added one by one
+    let mut accumulator = PairingAccumulator::new(&ts);
+    for i in 0..N_SAMPLES {
+        accumulator
+            .add_blob_kzg_proof(&blobs[i], &commitments[i], &blob_proofs[i])
+            .unwrap();
+        accumulator
+            .add_kzg_proof(&commitments[i], &zs[i], &ys[i], &proofs[i])
+            .unwrap();
+    }
+    assert_eq!(accumulator.len(), 2 * N_SAMPLES);
+    assert!(accumulator.finalize().unwrap());
+
+    // Single incorrect proof makes the whole accumulator fail
+    let mut accumulator = PairingAccumulator::new(&ts);
+    for i in 0..N_SAMPLES {
+        accumulator
+            .add_blob_kzg_proof(&blobs[i], &commitments[i], &blob_proofs[i])
+            .unwrap();
+    }
+    accumulator
+        .add_kzg_proof(&commitments[0], &zs[0], &ys[1], &proofs[0])
+        .unwrap();
+    assert!(!accumulator.finalize().unwrap());
+
+    let mut accumulator = PairingAccumulator::new(&ts);
+    accumulator
+        .add_blob_kzg_proof(&blobs[0], &commitments[0], &blob_proofs[1])
+        .unwrap();
+    assert!(!accumulator.finalize().unwrap());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn commit_coeff_and_eval_form_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let fs = ts.get_fft_settings();
+
+    let mut rng = rand::thread_rng();
+    let coeffs = (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|_| TFr::from_u64(rng.gen()))
+        .collect::<Vec<TFr>>();
+    let poly = TPoly::from_coeffs(&coeffs);
+
+    // Evaluations of the same polynomial, in bit-reversed order like blobs are
+    let mut evals = fs.fft_fr(&coeffs, false).unwrap();
+    reverse_bit_order(&mut evals).unwrap();
+
+    let commitment = ts.commit_coeff_form(&poly).unwrap();
+    assert!(commitment.equals(&ts.commit_eval_form(&TPoly::from_coeffs(&evals)).unwrap()));
+    assert!(commitment.equals(&blob_to_kzg_commitment(&evals, &ts).unwrap()));
+
+    // Shorter polynomials only use a prefix of the monomial points
+    let short = TPoly::from_coeffs(&coeffs[..16]);
+    let short_commitment = ts.commit_coeff_form(&short).unwrap();
+    let expected = coeffs[..16]
+        .iter()
+        .zip(ts.get_g1_monomial())
+        .fold(TG1::identity(), |acc, (c, p)| acc.add_or_dbl(&p.mul(c)));
+    assert!(short_commitment.equals(&expected));
+
+    let x = TFr::from_u64(rng.gen());
+    let proof = ts.compute_proof_single(&poly, &x).unwrap();
+    let value = poly.eval(&x);
+    assert!(ts
+        .check_proof_single(&commitment, &proof, &x, &value)
+        .unwrap());
+    assert!(!ts
+        .check_proof_single(&commitment, &proof, &x, &value.add(&TFr::one()))
+        .unwrap());
+
+    // Evaluation form requires exactly one evaluation per setup point
+    assert!(ts
+        .commit_eval_form(&TPoly::from_coeffs(&evals[..16]))
+        .is_err());
+
+    // Monomial points are derived when settings are built, so setups they can't be derived from are rejected
+    assert!(matches!(
+        TKZGSettings::new_from_lagrange(&ts.get_g1_secret()[..3], ts.get_g2_secret(), 3, fs),
+        Err(Error::InvalidTrustedSetup(_))
+    ));
+}
+
 #[allow(clippy::type_complexity)]
 pub fn verify_kzg_proof_batch_test<
     TFr: Fr,
@@ -422,16 +976,16 @@ pub fn verify_kzg_proof_batch_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
     verify_blob_kzg_proof_batch: &dyn Fn(
         &[Vec<TFr>],
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let mut rng = rand::thread_rng();
@@ -482,16 +1036,16 @@ pub fn verify_kzg_proof_batch_fails_with_incorrect_proof_test<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
     verify_blob_kzg_proof_batch: &dyn Fn(
         &[Vec<TFr>],
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let mut rng = rand::thread_rng();
@@ -524,6 +1078,391 @@ pub fn verify_kzg_proof_batch_fails_with_incorrect_proof_test<
     assert!(!result);
 }
 
+#[allow(clippy::type_complexity)]
+pub fn verify_blob_kzg_proof_batch_failures_test<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof_batch_failures: &dyn Fn(
+        &[Vec<TFr>],
+        &[TG1],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<Vec<usize>, Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    const N_SAMPLES: usize = 8;
+
+    let mut blobs: Vec<Vec<TFr>> = Vec::with_capacity(N_SAMPLES);
+    let mut commitments: Vec<TG1> = Vec::with_capacity(N_SAMPLES);
+    let mut proofs: Vec<TG1> = Vec::with_capacity(N_SAMPLES);
+
+    for _ in 0..N_SAMPLES {
+        let blob = {
+            let blob_bytes = generate_random_blob_bytes(&mut rng);
+            bytes_to_blob(&blob_bytes).unwrap()
+        };
+
+        let commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+        let proof = compute_blob_kzg_proof(&blob, &commitment, &ts).unwrap();
+
+        blobs.push(blob);
+        commitments.push(commitment);
+        proofs.push(proof);
+    }
+
+    let result = verify_blob_kzg_proof_batch_failures(&blobs, &commitments, &proofs, &ts).unwrap();
+    assert!(result.is_empty());
+
+    let result = verify_blob_kzg_proof_batch_failures(&[], &[], &[], &ts).unwrap();
+    assert!(result.is_empty());
+
+    // Overwrite some proofs with incorrect ones
+    let mut invalid_proofs = proofs.clone();
+    invalid_proofs[1] = proofs[0].clone();
+    invalid_proofs[5] = proofs[0].clone();
+    invalid_proofs[6] = proofs[0].clone();
+
+    let result =
+        verify_blob_kzg_proof_batch_failures(&blobs, &commitments, &invalid_proofs, &ts).unwrap();
+    assert_eq!(result, vec![1, 5, 6]);
+
+    let result = verify_blob_kzg_proof_batch_failures(
+        &blobs[1..2],
+        &commitments[1..2],
+        &invalid_proofs[1..2],
+        &ts,
+    )
+    .unwrap();
+    assert_eq!(result, vec![0]);
+
+    let result = verify_blob_kzg_proof_batch_failures(&blobs, &commitments[1..], &proofs[1..], &ts);
+    assert!(result.is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn thread_pool_config_test<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
+    verify_blob_kzg_proof_batch: &dyn Fn(
+        &[Vec<TFr>],
+        &[TG1],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, Error>,
+) {
+    let mut ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blobs = (0..4)
+        .map(|_| bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap())
+        .collect::<Vec<_>>();
+    let commitments = blobs
+        .iter()
+        .map(|blob| blob_to_kzg_commitment(blob, &ts).unwrap())
+        .collect::<Vec<_>>();
+    let proofs = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| compute_blob_kzg_proof(blob, commitment, &ts).unwrap())
+        .collect::<Vec<_>>();
+
+    // Multiple threads are only supported with `parallel` feature
+    let mut pools = vec![ThreadPoolConfig::with_num_threads(1).unwrap()];
+    match ThreadPoolConfig::with_num_threads(3) {
+        Ok(pool) => {
+            assert_eq!(pool.num_threads(), 3);
+            pools.push(pool);
+        }
+        Err(err) => assert_eq!(err, Error::FeatureNotEnabled("parallel")),
+    }
+
+    for pool in pools {
+        ts.set_thread_pool(pool.clone());
+        assert_eq!(ts.get_thread_pool().num_threads(), pool.num_threads());
+
+        for ((blob, commitment), proof) in blobs.iter().zip(commitments.iter()).zip(proofs.iter()) {
+            assert!(blob_to_kzg_commitment(blob, &ts)
+                .unwrap()
+                .equals(commitment));
+            assert!(compute_blob_kzg_proof(blob, commitment, &ts)
+                .unwrap()
+                .equals(proof));
+        }
+        assert!(verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &ts).unwrap());
+    }
+
+    ts.set_thread_pool(ThreadPoolConfig::global());
+    assert!(verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &ts).unwrap());
+}
+
+pub fn kzg_context_test<B: Backend>() {
+    let mut rng = rand::thread_rng();
+    let kzg = KzgContext::<B>::load_trusted_setup_file(get_trusted_setup_path().as_str()).unwrap();
+    assert_eq!(kzg.field_elements_per_blob(), FIELD_ELEMENTS_PER_BLOB);
+    assert!(kzg.bytes_to_blob(&[0u8; BYTES_PER_FIELD_ELEMENT]).is_err());
+
+    let blobs = (0..3)
+        .map(|_| {
+            kzg.bytes_to_blob(&generate_random_blob_bytes(&mut rng))
+                .unwrap()
+        })
+        .collect::<Vec<Vec<B::Fr>>>();
+    let commitments = blobs
+        .iter()
+        .map(|blob| kzg.blob_to_kzg_commitment(blob).unwrap())
+        .collect::<Vec<B::G1>>();
+    let mut proofs = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| kzg.compute_blob_kzg_proof(blob, commitment).unwrap())
+        .collect::<Vec<B::G1>>();
+
+    // Commitments match direct commitment to the polynomial with the same settings
+    let poly = B::Poly::from_coeffs(&blobs[0]);
+    assert!(commitments[0].equals(&kzg.settings().commit_eval_form(&poly).unwrap()));
+
+    for ((blob, commitment), proof) in blobs.iter().zip(commitments.iter()).zip(proofs.iter()) {
+        assert!(kzg.verify_blob_kzg_proof(blob, commitment, proof).unwrap());
+    }
+
+    let z = B::Fr::from_u64(31337);
+    let (proof, y) = kzg.compute_kzg_proof(&blobs[1], &z).unwrap();
+    assert!(kzg
+        .verify_kzg_proof(&commitments[1], &z, &y, &proof)
+        .unwrap());
+    let wrong_y = y.add(&B::Fr::one());
+    assert!(!kzg
+        .verify_kzg_proof(&commitments[1], &z, &wrong_y, &proof)
+        .unwrap());
+
+    assert!(kzg
+        .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+        .unwrap());
+    proofs.swap(0, 2);
+    assert!(!kzg
+        .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+        .unwrap());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn dyn_kzg_test<B: Backend + 'static>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<B::G1>, Vec<B::G2>),
+) where
+    B::FFTSettings: FFTG1<B::G1>,
+{
+    let mut rng = rand::thread_rng();
+    let mut registry = BackendRegistry::new();
+    registry.register::<B>().register::<B>();
+    assert_eq!(registry.names().collect::<Vec<_>>(), vec![B::NAME]);
+    assert!(registry.contains(B::NAME));
+    assert!(matches!(
+        registry.load_trusted_setup_file("unknown", get_trusted_setup_path().as_str()),
+        Err(Error::UnknownBackend(_))
+    ));
+
+    let kzg: Box<dyn DynKzg> = registry
+        .load_trusted_setup_file(B::NAME, get_trusted_setup_path().as_str())
+        .unwrap();
+    let typed =
+        KzgContext::<B>::load_trusted_setup_file(get_trusted_setup_path().as_str()).unwrap();
+    assert_eq!(kzg.backend_name(), B::NAME);
+    assert_eq!(kzg.field_elements_per_blob(), FIELD_ELEMENTS_PER_BLOB);
+
+    let blobs = (0..3)
+        .map(|_| generate_random_blob_bytes(&mut rng).to_vec())
+        .collect::<Vec<_>>();
+    let blob_refs = blobs.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let commitments = blobs
+        .iter()
+        .map(|blob| Bytes48 {
+            bytes: kzg.blob_to_kzg_commitment(blob).unwrap().bytes,
+        })
+        .collect::<Vec<_>>();
+    let proofs = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| Bytes48 {
+            bytes: kzg.compute_blob_kzg_proof(blob, commitment).unwrap().bytes,
+        })
+        .collect::<Vec<_>>();
+
+    // Serialized results match the typed interface
+    let blob = typed.bytes_to_blob(&blobs[0]).unwrap();
+    let commitment = typed.blob_to_kzg_commitment(&blob).unwrap();
+    assert_eq!(commitments[0].bytes, commitment.to_bytes());
+    assert_eq!(
+        proofs[0].bytes,
+        typed
+            .compute_blob_kzg_proof(&blob, &commitment)
+            .unwrap()
+            .to_bytes()
+    );
+
+    for ((blob, commitment), proof) in blobs.iter().zip(commitments.iter()).zip(proofs.iter()) {
+        assert!(kzg.verify_blob_kzg_proof(blob, commitment, proof).unwrap());
+    }
+    assert!(kzg
+        .verify_blob_kzg_proof_batch(&blob_refs, &commitments, &proofs)
+        .unwrap());
+    assert!(!kzg
+        .verify_blob_kzg_proof_batch(&blob_refs, &commitments[..1], &proofs)
+        .unwrap_or(false));
+
+    let z = Bytes32 {
+        bytes: generate_random_field_element_bytes(&mut rng),
+    };
+    let (proof, y) = kzg.compute_kzg_proof(&blobs[1], &z).unwrap();
+    let proof = Bytes48 { bytes: proof.bytes };
+    assert!(kzg
+        .verify_kzg_proof(&commitments[1], &z, &y, &proof)
+        .unwrap());
+    assert!(!kzg
+        .verify_kzg_proof(&commitments[2], &z, &y, &proof)
+        .unwrap());
+
+    let invalid_point = Bytes48 { bytes: [0xff; 48] };
+    assert!(kzg
+        .verify_blob_kzg_proof(&blobs[0], &invalid_point, &proofs[0])
+        .is_err());
+
+    // Setups of other widths take blobs of their own width
+    let field_elements_per_blob = 16;
+    let (g1_bytes, g2_bytes) = custom_width_setup_bytes::<B::Fr, B::G1, B::G2, B::FFTSettings>(
+        generate_trusted_setup,
+        field_elements_per_blob,
+    );
+    let contents = write_trusted_setup(&g1_bytes, &g2_bytes, TrustedSetupFormat::Text).unwrap();
+    let narrow = registry.load_trusted_setup(B::NAME, &contents).unwrap();
+    assert_eq!(narrow.field_elements_per_blob(), field_elements_per_blob);
+
+    let blob = (0..field_elements_per_blob)
+        .flat_map(|_| generate_random_field_element_bytes(&mut rng))
+        .collect::<Vec<u8>>();
+    let commitment = Bytes48 {
+        bytes: narrow.blob_to_kzg_commitment(&blob).unwrap().bytes,
+    };
+    let proof = Bytes48 {
+        bytes: narrow
+            .compute_blob_kzg_proof(&blob, &commitment)
+            .unwrap()
+            .bytes,
+    };
+    assert!(narrow
+        .verify_blob_kzg_proof(&blob, &commitment, &proof)
+        .unwrap());
+    assert!(narrow
+        .verify_blob_kzg_proof_batch(&[&blob], &[commitment], &[proof])
+        .unwrap());
+
+    assert!(narrow.blob_to_kzg_commitment(&blobs[0]).is_err());
+    assert!(kzg.blob_to_kzg_commitment(&blob).is_err());
+    assert!(narrow.blob_to_kzg_commitment(&blob[1..]).is_err());
+}
+
+pub fn kzg_to_versioned_hash_test(
+    kzg_to_versioned_hash: &dyn Fn(&[u8; BYTES_PER_COMMITMENT]) -> [u8; BYTES_PER_VERSIONED_HASH],
+) {
+    // Commitment to the zero blob is the point at infinity
+    let mut commitment = [0u8; BYTES_PER_COMMITMENT];
+    commitment[0] = 0xc0;
+
+    assert_eq!(
+        hex::encode(kzg_to_versioned_hash(&commitment)),
+        "010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014"
+    );
+}
+
+#[allow(clippy::type_complexity)]
+pub fn point_evaluation_precompile_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+    point_evaluation_precompile: &dyn Fn(
+        &[u8],
+        &TKZGSettings,
+    )
+        -> Result<[u8; POINT_EVALUATION_OUTPUT_LENGTH], Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob = bytes_to_blob(&generate_random_blob_bytes(&mut rng)).unwrap();
+    let z = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+    let commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+    let (proof, y) = compute_kzg_proof(&blob, &z, &ts).unwrap();
+
+    let commitment_bytes = commitment.to_bytes();
+    let mut input = Vec::with_capacity(POINT_EVALUATION_INPUT_LENGTH);
+    input.extend_from_slice(&kzg_to_versioned_hash_rust(&commitment_bytes));
+    input.extend_from_slice(&z.to_bytes());
+    input.extend_from_slice(&y.to_bytes());
+    input.extend_from_slice(&commitment_bytes);
+    input.extend_from_slice(&proof.to_bytes());
+
+    let output = point_evaluation_precompile(&input, &ts).unwrap();
+    assert_eq!(
+        hex::encode(output),
+        "0000000000000000000000000000000000000000000000000000000000001000\
+         73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+
+    // Versioned hash of a different commitment
+    let mut invalid = input.clone();
+    invalid[1] ^= 1;
+    assert_eq!(
+        point_evaluation_precompile(&invalid, &ts),
+        Err(Error::VersionedHashMismatch)
+    );
+
+    // Wrong evaluation
+    let mut invalid = input.clone();
+    invalid[64..96].copy_from_slice(&y.add(&TFr::one()).to_bytes());
+    assert_eq!(
+        point_evaluation_precompile(&invalid, &ts),
+        Err(Error::ProofMismatch)
+    );
+
+    // Wrong input length
+    assert!(matches!(
+        point_evaluation_precompile(&input[..input.len() - 1], &ts),
+        Err(Error::InvalidLength(_))
+    ));
+}
+
 const BLOB_TO_KZG_COMMITMENT_TESTS: &str = "src/test_vectors/blob_to_kzg_commitment/*/*/*";
 const COMPUTE_KZG_PROOF_TESTS: &str = "src/test_vectors/compute_kzg_proof/*/*/*";
 const COMPUTE_BLOB_KZG_PROOF_TESTS: &str = "src/test_vectors/compute_blob_kzg_proof/*/*/*";
@@ -543,9 +1482,9 @@ pub fn test_vectors_blob_to_kzg_commitment<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(&format!(
@@ -595,9 +1534,9 @@ pub fn test_vectors_compute_kzg_proof<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(&format!(
@@ -660,9 +1599,9 @@ pub fn test_vectors_compute_blob_kzg_proof<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TKZGSettings) -> Result<TG1, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(&format!(
@@ -727,8 +1666,8 @@ pub fn test_vectors_verify_kzg_proof<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    verify_kzg_proof: &dyn Fn(&TG1, &TFr, &TFr, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(&format!(
@@ -797,9 +1736,9 @@ pub fn test_vectors_verify_blob_kzg_proof<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
-    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    verify_blob_kzg_proof: &dyn Fn(&[TFr], &TG1, &TG1, &TKZGSettings) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(&format!(
@@ -861,14 +1800,14 @@ pub fn test_vectors_verify_blob_kzg_proof_batch<
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
-    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
     verify_blob_kzg_proof_batch: &dyn Fn(
         &[Vec<TFr>],
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
     let test_files: Vec<PathBuf> = glob::glob(&format!(
@@ -938,7 +1877,7 @@ pub fn test_vectors_verify_blob_kzg_proof_batch<
 
 #[allow(clippy::type_complexity)]
 pub fn compute_kzg_proof_incorrect_blob_length_test<TFr: Fr, TPoly: Poly<TFr>>(
-    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, String>,
+    blob_to_polynomial: &dyn Fn(&[TFr]) -> Result<TPoly, Error>,
 ) {
     let blob = &[TFr::zero()];
     let out = blob_to_polynomial(blob);
@@ -960,7 +1899,7 @@ pub fn compute_kzg_proof_incorrect_poly_length_test<
         &TPoly,
         &TFr,
         &TKZGSettings,
-    ) -> Result<TFr, String>,
+    ) -> Result<TFr, Error>,
 ) {
     let out = evaluate_polynomial_in_evaluation_form(
         &TPoly::new(1),
@@ -987,7 +1926,7 @@ pub fn compute_kzg_proof_empty_blob_vector_test<
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     let res = verify_blob_kzg_proof_batch(
         &[],
@@ -1015,7 +1954,7 @@ pub fn compute_kzg_proof_incorrect_commitments_len_test<
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     let blob1 = vec![TFr::default(); 3];
     let blob2 = vec![TFr::default(); 3];
@@ -1046,7 +1985,7 @@ pub fn compute_kzg_proof_incorrect_proofs_len_test<
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
+    ) -> Result<bool, Error>,
 ) {
     let blob1 = vec![TFr::default(); 3];
     let blob2 = vec![TFr::default(); 3];
@@ -1077,8 +2016,8 @@ pub fn validate_batched_input_test<
         &[TG1],
         &[TG1],
         &TKZGSettings,
-    ) -> Result<bool, String>,
-    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+    ) -> Result<bool, Error>,
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
 ) {
     let path = get_trusted_setup_path();
     let setup = &load_trusted_setup(path.as_str()).unwrap();
@@ -0,0 +1,349 @@
+use crate::test_vectors::{
+    compute_cells_and_kzg_proofs, recover_cells_and_kzg_proofs, verify_cell_kzg_proof_batch,
+};
+use crate::tests::eip_4844::generate_random_blob_bytes;
+use crate::tests::utils::{get_manifest_dir, get_trusted_setup_path};
+use kzg::eip_7594::{CellKZGSettings, CELLS_PER_EXT_BLOB};
+use kzg::Error;
+use kzg::{Fr, G1};
+use std::fs;
+use std::path::PathBuf;
+
+const COMPUTE_CELLS_AND_KZG_PROOFS_TESTS: &str =
+    "src/test_vectors/compute_cells_and_kzg_proofs/*/*/*";
+const VERIFY_CELL_KZG_PROOF_BATCH_TESTS: &str =
+    "src/test_vectors/verify_cell_kzg_proof_batch/*/*/*";
+const RECOVER_CELLS_AND_KZG_PROOFS_TESTS: &str =
+    "src/test_vectors/recover_cells_and_kzg_proofs/*/*/*";
+
+fn cell_to_bytes<TFr: Fr>(cell: &[TFr]) -> Vec<u8> {
+    cell.iter().flat_map(|fr| fr.to_bytes()).collect()
+}
+
+#[allow(clippy::type_complexity)]
+pub fn compute_recover_verify_cells_test<TFr: Fr, TG1: G1, TKZGSettings, TFK20MultiSettings>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    load_cell_kzg_settings: &dyn Fn(
+        &TKZGSettings,
+    ) -> Result<
+        CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+        Error,
+    >,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error>,
+    recover_cells_and_kzg_proofs: &dyn Fn(
+        &[usize],
+        &[Vec<TFr>],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error>,
+    verify_cell_kzg_proof_batch: &dyn Fn(
+        &[TG1],
+        &[usize],
+        &[Vec<TFr>],
+        &[TG1],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<bool, Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let cell_ts = load_cell_kzg_settings(&ts).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob_bytes = generate_random_blob_bytes(&mut rng);
+    let blob = bytes_to_blob(&blob_bytes).unwrap();
+    let commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+
+    let (cells, proofs) = compute_cells_and_kzg_proofs(&blob, &cell_ts).unwrap();
+    assert_eq!(cells.len(), CELLS_PER_EXT_BLOB);
+    assert_eq!(proofs.len(), CELLS_PER_EXT_BLOB);
+
+    let commitments = vec![commitment; CELLS_PER_EXT_BLOB];
+    let cell_indices = (0..CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    assert!(
+        verify_cell_kzg_proof_batch(&commitments, &cell_indices, &cells, &proofs, &cell_ts)
+            .unwrap()
+    );
+
+    // Recover from every other cell
+    let known_indices = cell_indices.iter().copied().step_by(2).collect::<Vec<_>>();
+    let known_cells = known_indices
+        .iter()
+        .map(|&i| cells[i].clone())
+        .collect::<Vec<_>>();
+    let (recovered_cells, recovered_proofs) =
+        recover_cells_and_kzg_proofs(&known_indices, &known_cells, &cell_ts).unwrap();
+    assert!(recovered_cells == cells);
+    assert_eq!(recovered_proofs, proofs);
+
+    // Tamper with a single cell
+    let mut bad_cells = cells.clone();
+    bad_cells[1][0] = bad_cells[1][0].add(&TFr::one());
+    assert!(!verify_cell_kzg_proof_batch(
+        &commitments,
+        &cell_indices,
+        &bad_cells,
+        &proofs,
+        &cell_ts
+    )
+    .unwrap());
+
+    // Less than half of the cells is not enough for recovery
+    assert!(
+        recover_cells_and_kzg_proofs(&known_indices[1..], &known_cells[1..], &cell_ts).is_err()
+    );
+}
+
+#[allow(clippy::type_complexity)]
+pub fn test_vectors_compute_cells_and_kzg_proofs<
+    TFr: Fr,
+    TG1: G1,
+    TKZGSettings,
+    TFK20MultiSettings,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    load_cell_kzg_settings: &dyn Fn(
+        &TKZGSettings,
+    ) -> Result<
+        CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+        Error,
+    >,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    compute_cells_and_kzg_proofs: &dyn Fn(
+        &[TFr],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let cell_ts = load_cell_kzg_settings(&ts).unwrap();
+    let test_files: Vec<PathBuf> = glob::glob(&format!(
+        "{}/{}",
+        get_manifest_dir(),
+        COMPUTE_CELLS_AND_KZG_PROOFS_TESTS
+    ))
+    .unwrap()
+    .map(Result::unwrap)
+    .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: compute_cells_and_kzg_proofs::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let blob = match bytes_to_blob(&test.input.get_blob_bytes()) {
+            Ok(blob) => blob,
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        match compute_cells_and_kzg_proofs(&blob, &cell_ts) {
+            Ok((cells, proofs)) => {
+                let (expected_cells, expected_proofs) = test.get_output().expect(
+                    "Expected that `compute_cells_and_kzg_proofs` would throw an error, but it succeeded",
+                );
+
+                let cells = cells
+                    .iter()
+                    .map(|cell| cell_to_bytes(cell))
+                    .collect::<Vec<_>>();
+                let proofs = proofs
+                    .iter()
+                    .map(|proof| proof.to_bytes().to_vec())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(cells, expected_cells);
+                assert_eq!(proofs, expected_proofs);
+            }
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn test_vectors_verify_cell_kzg_proof_batch<
+    TFr: Fr,
+    TG1: G1,
+    TKZGSettings,
+    TFK20MultiSettings,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    load_cell_kzg_settings: &dyn Fn(
+        &TKZGSettings,
+    ) -> Result<
+        CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+        Error,
+    >,
+    bytes_to_cell: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    verify_cell_kzg_proof_batch: &dyn Fn(
+        &[TG1],
+        &[usize],
+        &[Vec<TFr>],
+        &[TG1],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<bool, Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let cell_ts = load_cell_kzg_settings(&ts).unwrap();
+    let test_files: Vec<PathBuf> = glob::glob(&format!(
+        "{}/{}",
+        get_manifest_dir(),
+        VERIFY_CELL_KZG_PROOF_BATCH_TESTS
+    ))
+    .unwrap()
+    .map(Result::unwrap)
+    .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: verify_cell_kzg_proof_batch::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let commitments = match test
+            .input
+            .get_commitments_bytes()
+            .iter()
+            .map(|bytes| TG1::from_bytes(bytes))
+            .collect::<Result<Vec<TG1>, Error>>()
+        {
+            Ok(commitments) => commitments,
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        let cells = match test
+            .input
+            .get_cells_bytes()
+            .iter()
+            .map(|bytes| bytes_to_cell(bytes))
+            .collect::<Result<Vec<Vec<TFr>>, Error>>()
+        {
+            Ok(cells) => cells,
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        let proofs = match test
+            .input
+            .get_proofs_bytes()
+            .iter()
+            .map(|bytes| TG1::from_bytes(bytes))
+            .collect::<Result<Vec<TG1>, Error>>()
+        {
+            Ok(proofs) => proofs,
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        let cell_indices = test
+            .input
+            .get_cell_indices()
+            .iter()
+            .map(|&index| index as usize)
+            .collect::<Vec<_>>();
+
+        match verify_cell_kzg_proof_batch(&commitments, &cell_indices, &cells, &proofs, &cell_ts) {
+            Ok(result) => assert_eq!(result, test.get_output().unwrap()),
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn test_vectors_recover_cells_and_kzg_proofs<
+    TFr: Fr,
+    TG1: G1,
+    TKZGSettings,
+    TFK20MultiSettings,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    load_cell_kzg_settings: &dyn Fn(
+        &TKZGSettings,
+    ) -> Result<
+        CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+        Error,
+    >,
+    bytes_to_cell: &dyn Fn(&[u8]) -> Result<Vec<TFr>, Error>,
+    recover_cells_and_kzg_proofs: &dyn Fn(
+        &[usize],
+        &[Vec<TFr>],
+        &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+    ) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let cell_ts = load_cell_kzg_settings(&ts).unwrap();
+    let test_files: Vec<PathBuf> = glob::glob(&format!(
+        "{}/{}",
+        get_manifest_dir(),
+        RECOVER_CELLS_AND_KZG_PROOFS_TESTS
+    ))
+    .unwrap()
+    .map(Result::unwrap)
+    .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: recover_cells_and_kzg_proofs::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let cells = match test
+            .input
+            .get_cells_bytes()
+            .iter()
+            .map(|bytes| bytes_to_cell(bytes))
+            .collect::<Result<Vec<Vec<TFr>>, Error>>()
+        {
+            Ok(cells) => cells,
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        };
+
+        let cell_indices = test
+            .input
+            .get_cell_indices()
+            .iter()
+            .map(|&index| index as usize)
+            .collect::<Vec<_>>();
+
+        match recover_cells_and_kzg_proofs(&cell_indices, &cells, &cell_ts) {
+            Ok((recovered_cells, recovered_proofs)) => {
+                let (expected_cells, expected_proofs) = test.get_output().expect(
+                    "Expected that `recover_cells_and_kzg_proofs` would throw an error, but it succeeded",
+                );
+
+                let recovered_cells = recovered_cells
+                    .iter()
+                    .map(|cell| cell_to_bytes(cell))
+                    .collect::<Vec<_>>();
+                let recovered_proofs = recovered_proofs
+                    .iter()
+                    .map(|proof| proof.to_bytes().to_vec())
+                    .collect::<Vec<_>>();
+
+                assert_eq!(recovered_cells, expected_cells);
+                assert_eq!(recovered_proofs, expected_proofs);
+            }
+            Err(_) => {
+                assert!(test.get_output().is_none());
+                continue;
+            }
+        }
+    }
+}
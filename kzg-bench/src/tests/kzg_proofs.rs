@@ -52,6 +52,44 @@ pub fn proof_single<
         .unwrap());
 }
 
+pub fn verify_pairing_with_g2_secret_works<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let secrets_len = 16;
+    let (s1, s2) = generate_trusted_setup(secrets_len, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, secrets_len, &fs).unwrap();
+
+    // e(G1, [s]G2) == e([s]G1, G2)
+    assert!(ks.verify_pairing_with_g2_secret(&TG1::generator(), &s1[1]));
+    assert!(!ks.verify_pairing_with_g2_secret(&s1[1], &TG1::generator()));
+    assert!(ks.verify_pairing_with_g2_secret(&TG1::identity(), &TG1::identity()));
+    assert!(!ks.verify_pairing_with_g2_secret(&TG1::identity(), &TG1::generator()));
+
+    // Proof of a constant polynomial is the point at infinity
+    let mut p = TPoly::new(1);
+    p.set_coeff_at(0, &TFr::from_u64(7));
+    let x = TFr::from_u64(25);
+    let commitment = ks.commit_to_poly(&p).unwrap();
+    let proof = ks.compute_proof_single(&p, &x).unwrap();
+    assert!(proof.is_inf());
+    assert!(ks
+        .check_proof_single(&commitment, &proof, &x, &TFr::from_u64(7))
+        .unwrap());
+    assert!(!ks
+        .check_proof_single(&commitment, &proof, &x, &TFr::from_u64(8))
+        .unwrap());
+}
+
 pub fn commit_to_nil_poly<
     TFr: Fr,
     TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
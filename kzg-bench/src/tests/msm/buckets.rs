@@ -0,0 +1,123 @@
+use kzg::msm::{
+    buckets::{BucketAccumulation, Buckets},
+    msm_impls::batch_convert,
+    tiling_pippenger_ops::tiling_pippenger_with_accumulation,
+};
+use kzg::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1};
+
+fn naive_msm<TFr: Fr, TG1: G1 + G1Mul<TFr>>(points: &[TG1], scalars: &[TFr]) -> TG1 {
+    let mut out = TG1::identity();
+    for (point, scalar) in points.iter().zip(scalars) {
+        out.add_or_dbl_assign(&point.mul(scalar));
+    }
+    out
+}
+
+pub fn test_bucket_accumulations_agree<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    for len in [2, 100, 1500] {
+        let mut points = (0..len).map(|_| TG1::rand()).collect::<Vec<_>>();
+        let mut scalars = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+        // Same point with same scalar lands in the same bucket in every window, so it is doubled, and its negation
+        // cancels it out
+        if len > 8 {
+            let point = points[0].clone();
+            let scalar = scalars[0].clone();
+            for i in 1..4 {
+                points[i] = point.clone();
+                scalars[i] = scalar.clone();
+            }
+            points[4] = TG1::identity().sub(&point);
+            scalars[4] = scalar;
+            scalars[5] = TFr::zero();
+        }
+
+        let expected = naive_msm(&points, &scalars);
+        let affines = batch_convert::<TG1, TG1Fp, TG1Affine>(&points);
+        let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+        for accumulation in BucketAccumulation::ALL {
+            let result = tiling_pippenger_with_accumulation::<TG1, TG1Fp, TG1Affine>(
+                &affines,
+                &scalars,
+                255,
+                accumulation,
+            );
+            assert!(result.equals(&expected), "{:?} failed", accumulation);
+        }
+    }
+}
+
+/// Window, for which buckets are flushed in batches of 128 points
+const EDGE_CASE_WINDOW: usize = 10;
+const EDGE_CASE_WBITS: usize = EDGE_CASE_WINDOW - 1;
+
+/// Sequence of `(bucket index, negate, point)`, which is split into three batch affine flushes. Its buckets meet a
+/// point equal to their content (doubling), its negation (cancellation), a point after being cancelled, and points
+/// added while they are already a part of pending batch.
+fn bucket_edge_case_points<TG1: G1>() -> Vec<(u64, bool, TG1)> {
+    let p = TG1::rand();
+    let q = TG1::rand();
+
+    // First batch: buckets 1 to 3 hold P, bucket 4 holds -Q. Repeated points hit buckets of pending batch
+    let mut points = vec![
+        (1, false, p.clone()),
+        (1, false, p.clone()),
+        (2, false, p.clone()),
+        (2, true, p.clone()),
+        (3, false, p.clone()),
+        (4, true, q.clone()),
+    ];
+    points.extend((5..=128).map(|idx| (idx, false, TG1::rand())));
+
+    // Second batch: P + P, P + (-P) and -Q + Q on buckets, which were filled by previous flush
+    points.push((1, false, p.clone()));
+    points.push((2, true, p.clone()));
+    points.push((4, false, q.clone()));
+    points.push((3, false, p.clone()));
+    points.push((3, false, p.clone()));
+    points.extend((5..=128).map(|idx| (idx, true, TG1::rand())));
+
+    // Third batch, which is flushed only when buckets are integrated: cancelled bucket is filled again
+    points.push((2, false, q.clone()));
+    points.push((1, true, p.clone()));
+    points.push(((1 << EDGE_CASE_WBITS) - 1, false, p));
+    points.push((4, false, q));
+
+    points
+}
+
+pub fn test_bucket_accumulation_edge_cases<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let points = bucket_edge_case_points::<TG1>();
+
+    let mut expected = TG1::identity();
+    for (idx, negate, point) in &points {
+        let point = if *negate {
+            TG1::identity().sub(point)
+        } else {
+            point.clone()
+        };
+        expected.add_or_dbl_assign(&point.mul(&TFr::from_u64(*idx)));
+    }
+
+    for accumulation in BucketAccumulation::ALL {
+        let mut buckets = Buckets::<TG1, TG1Fp, TG1Affine>::new(accumulation, EDGE_CASE_WINDOW);
+        for (idx, negate, point) in &points {
+            let booth_idx = idx | ((*negate as u64) << EDGE_CASE_WBITS);
+            buckets.booth_decode(booth_idx, EDGE_CASE_WBITS, &TG1Affine::into_affine(point));
+        }
+
+        let mut result = TG1::identity();
+        buckets.integrate(&mut result, EDGE_CASE_WBITS);
+        assert!(result.equals(&expected), "{:?} failed", accumulation);
+    }
+}
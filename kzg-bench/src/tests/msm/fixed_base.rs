@@ -0,0 +1,67 @@
+use kzg::msm::fixed_base::FixedBaseMsm;
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::{Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, G1};
+
+pub fn test_fixed_base_msm<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let points = (0..40).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let scalars = (0..40).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    let unbounded = FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::new(&points).unwrap();
+    let budget = unbounded.memory_usage() / 2;
+    let bounded =
+        FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_memory_budget(&points, budget).unwrap();
+    let no_table =
+        FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_memory_budget(&points, 0).unwrap();
+    assert!(bounded.memory_usage() <= budget);
+    assert_eq!(no_table.memory_usage(), 0);
+
+    for len in [0, 1, 5, 39, 40] {
+        let mut expected = TG1::identity();
+        for i in 0..len {
+            expected.add_or_dbl_assign(&points[i].mul(&scalars[i]));
+        }
+
+        for msm in [&unbounded, &bounded, &no_table] {
+            assert_eq!(msm.len(), points.len());
+            assert!(msm.msm(&scalars[..len]).unwrap().equals(&expected));
+            assert!(
+                TG1::g1_lincomb(msm.points(), &scalars, len, Some(msm.precomputation()))
+                    .equals(&expected)
+            );
+        }
+    }
+
+    let too_many = (0..41).map(|_| TFr::rand()).collect::<Vec<_>>();
+    assert!(unbounded.msm(&too_many).is_err());
+}
+
+/// Inputs much shorter than the table, so that parallel BGMW has fewer points than columns it was built for
+pub fn test_fixed_base_msm_short_inputs<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    // Parallel tables are built only when there are several threads
+    let pool = ThreadPoolConfig::with_num_threads(8).unwrap_or_else(|_| ThreadPoolConfig::global());
+
+    pool.install(|| {
+        let points = (0..64).map(|_| TG1::rand()).collect::<Vec<_>>();
+        let scalars = (0..24).map(|_| TFr::rand()).collect::<Vec<_>>();
+        let msm = FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::new(&points).unwrap();
+
+        for len in 2..=scalars.len() {
+            let mut expected = TG1::identity();
+            for i in 0..len {
+                expected.add_or_dbl_assign(&points[i].mul(&scalars[i]));
+            }
+
+            assert!(msm.msm(&scalars[..len]).unwrap().equals(&expected));
+        }
+    });
+}
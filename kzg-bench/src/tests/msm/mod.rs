@@ -1,3 +1,8 @@
 pub mod batch_adder;
 pub mod bucket_msm;
+pub mod buckets;
+pub mod fixed_base;
 pub mod msm_slice;
+pub mod msm_strategy;
+pub mod precompute_cache;
+pub mod sparse;
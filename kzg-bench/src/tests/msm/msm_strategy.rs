@@ -0,0 +1,94 @@
+use kzg::msm::{
+    msm_impls::{msm, msm_with_strategy},
+    precompute::precompute,
+    strategy::MsmStrategy,
+};
+use kzg::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1ProjAddAffine, G1};
+
+fn naive_msm<TFr: Fr, TG1: G1 + G1Mul<TFr>>(points: &[TG1], scalars: &[TFr]) -> TG1 {
+    let mut out = TG1::identity();
+    for (point, scalar) in points.iter().zip(scalars) {
+        out.add_or_dbl_assign(&point.mul(scalar));
+    }
+    out
+}
+
+pub fn test_msm_strategies_agree<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>() {
+    let points = (0..64).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let scalars = (0..64).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let precomputation = precompute::<TFr, TG1, TG1Fp, TG1Affine>(&points).unwrap();
+
+    for len in [2, 37, 64] {
+        let expected = naive_msm(&points[..len], &scalars[..len]);
+
+        for strategy in MsmStrategy::ALL {
+            if !precomputation.is_usable(strategy, len) {
+                assert!(strategy != MsmStrategy::TilingPippenger);
+                continue;
+            }
+
+            let result = msm_with_strategy::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+                strategy,
+                &points[..len],
+                &scalars[..len],
+                Some(&precomputation),
+            );
+            assert!(result.equals(&expected), "{:?} failed", strategy);
+
+            precomputation.set_msm_strategy(strategy).unwrap();
+            let result = msm::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+                &points,
+                &scalars,
+                len,
+                Some(&precomputation),
+            );
+            assert!(result.equals(&expected), "{:?} failed", strategy);
+        }
+
+        precomputation.use_default_msm_strategy();
+        assert_eq!(precomputation.msm_strategy(), None);
+    }
+
+    for strategy in MsmStrategy::ALL {
+        if !strategy.is_compiled() {
+            assert!(precomputation.set_msm_strategy(strategy).is_err());
+        }
+    }
+}
+
+pub fn test_msm_auto_tuning<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>() {
+    let points = (0..64).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let scalars = (0..64).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let precomputation = precompute::<TFr, TG1, TG1Fp, TG1Affine>(&points).unwrap();
+    precomputation.enable_msm_auto_tuning();
+
+    for len in [16, 64] {
+        assert_eq!(precomputation.tuned_msm_strategy(len), None);
+
+        let expected = naive_msm(&points[..len], &scalars[..len]);
+        for _ in 0..2 {
+            let result = msm::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+                &points,
+                &scalars,
+                len,
+                Some(&precomputation),
+            );
+            assert!(result.equals(&expected));
+        }
+
+        let tuned = precomputation.tuned_msm_strategy(len).unwrap();
+        assert!(precomputation.is_usable(tuned, len));
+    }
+}
@@ -0,0 +1,213 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use crate::tests::utils::get_trusted_setup_path;
+use kzg::eip_4844::{hash, FIELD_ELEMENTS_PER_BLOB};
+use kzg::msm::precompute::{
+    precompute, precompute_with_cache, PrecomputationTable, PRECOMPUTATION_DIR_ENV,
+};
+use kzg::Error;
+use kzg::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, Poly, G1, G2};
+
+const CHECKSUM_SIZE: usize = 32;
+
+/// Serializes tests, which set the cache directory environment variable
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+fn fresh_dir(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let dir = std::env::temp_dir().join(format!(
+        "rust-kzg-{}-{}-{}",
+        name,
+        std::process::id(),
+        nanos
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Tables cached in `dir`, ignoring temporary files of unfinished writes
+fn cached_tables(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "bin"))
+        .collect()
+}
+
+fn modified(path: &Path) -> SystemTime {
+    fs::metadata(path).unwrap().modified().unwrap()
+}
+
+/// Backdates the file, so that rewriting it can be told apart from leaving it as is
+fn backdate(path: &Path) -> SystemTime {
+    let time = SystemTime::UNIX_EPOCH + Duration::from_secs(86400);
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(time)
+        .unwrap();
+    time
+}
+
+/// Sets environment variable, so must not run in the same process with tests, which load trusted setups
+#[allow(clippy::type_complexity)]
+pub fn test_precomputation_env_cache<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, Error>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, Error>,
+) {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let dir = fresh_dir("precomputation-env-cache");
+    std::env::set_var(PRECOMPUTATION_DIR_ENV, &dir);
+
+    // First load builds the table and writes it into the cache directory
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let cached = cached_tables(&dir);
+    assert_eq!(cached.len(), 1);
+    let contents = fs::read(&cached[0]).unwrap();
+
+    // Second load reads the table back, instead of rebuilding and rewriting it
+    let written_at = backdate(&cached[0]);
+    let cached_ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    assert_eq!(modified(&cached[0]), written_at);
+    assert!(cached_ts.get_precomputation().is_some());
+
+    // Corrupted table is rebuilt and replaced
+    fs::write(&cached[0], &contents[..contents.len() / 2]).unwrap();
+    backdate(&cached[0]);
+    let rebuilt_ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    assert_ne!(modified(&cached[0]), written_at);
+    assert_eq!(fs::read(&cached[0]).unwrap(), contents);
+
+    std::env::remove_var(PRECOMPUTATION_DIR_ENV);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let blob = (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|_| TFr::rand())
+        .collect::<Vec<TFr>>();
+    let commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+    assert!(blob_to_kzg_commitment(&blob, &cached_ts)
+        .unwrap()
+        .equals(&commitment));
+    assert!(blob_to_kzg_commitment(&blob, &rebuilt_ts)
+        .unwrap()
+        .equals(&commitment));
+}
+
+/// Sets environment variable, so must not run in the same process with tests, which load trusted setups
+#[allow(clippy::type_complexity)]
+pub fn test_mainnet_setup_skips_precomputation_cache<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_mainnet_trusted_setup: &dyn Fn() -> Result<TKZGSettings, Error>,
+) {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let dir = fresh_dir("precomputation-mainnet");
+    std::env::set_var(PRECOMPUTATION_DIR_ENV, &dir);
+
+    // Embedded setup builds its table in memory, even when caching is enabled
+    let ts = load_mainnet_trusted_setup().unwrap();
+    let cached = cached_tables(&dir);
+
+    std::env::remove_var(PRECOMPUTATION_DIR_ENV);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(cached.is_empty());
+    assert!(ts.get_precomputation().is_some());
+}
+
+pub fn test_precomputation_table_round_trip<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let points = (0..64).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let scalars = (0..64).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    let table = precompute::<TFr, TG1, TG1Fp, TG1Affine>(&points).unwrap();
+    let bytes = table.to_bytes().unwrap();
+    let loaded =
+        PrecomputationTable::<TFr, TG1, TG1Fp, TG1Affine>::from_bytes(&bytes, &points).unwrap();
+    assert_eq!(loaded.to_bytes().unwrap(), bytes);
+
+    let expected = TG1::g1_lincomb(&points, &scalars, points.len(), None);
+    assert!(TG1::g1_lincomb(&points, &scalars, points.len(), Some(&loaded)).equals(&expected));
+
+    // Tables are tied to the points they were built for
+    let other_points = (0..64).map(|_| TG1::rand()).collect::<Vec<_>>();
+    assert!(
+        PrecomputationTable::<TFr, TG1, TG1Fp, TG1Affine>::from_bytes(&bytes, &other_points)
+            .is_err()
+    );
+}
+
+pub fn test_precomputation_cache_rejects_invalid_tables<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let dir = fresh_dir("precomputation-cache");
+    let points = (0..64).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let other_points = (0..64).map(|_| TG1::rand()).collect::<Vec<_>>();
+
+    let table = precompute_with_cache::<TFr, TG1, TG1Fp, TG1Affine>(&points, &dir).unwrap();
+    let cached = cached_tables(&dir);
+    assert_eq!(cached.len(), 1);
+    let contents = fs::read(&cached[0]).unwrap();
+    assert_eq!(table.to_bytes().unwrap(), contents);
+
+    let mut corrupted = contents.clone();
+    corrupted[contents.len() / 2] ^= 1;
+
+    let truncated = contents[..contents.len() - 1].to_vec();
+
+    let other = precompute::<TFr, TG1, TG1Fp, TG1Affine>(&other_points)
+        .unwrap()
+        .to_bytes()
+        .unwrap();
+
+    // Changed point with checksum fixed up, as a table planted on purpose would have
+    let mut tampered = contents.clone();
+    let data_len = contents.len() - CHECKSUM_SIZE;
+    tampered[data_len - 8] ^= 1;
+    let checksum = hash(&tampered[..data_len]);
+    tampered[data_len..].copy_from_slice(&checksum);
+
+    for invalid in [corrupted, truncated, other, tampered] {
+        assert!(
+            PrecomputationTable::<TFr, TG1, TG1Fp, TG1Affine>::from_bytes(&invalid, &points)
+                .is_err()
+        );
+
+        fs::write(&cached[0], &invalid).unwrap();
+        let table = precompute_with_cache::<TFr, TG1, TG1Fp, TG1Affine>(&points, &dir).unwrap();
+        assert_eq!(table.to_bytes().unwrap(), contents);
+        assert_eq!(fs::read(&cached[0]).unwrap(), contents);
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
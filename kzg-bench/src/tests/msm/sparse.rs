@@ -0,0 +1,99 @@
+use kzg::msm::{
+    msm_impls::{batch_convert, msm},
+    sparse::SparseScalars,
+    tiling_pippenger_ops::tiling_pippenger_with_bits,
+};
+use kzg::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1ProjAddAffine, G1};
+
+fn naive_msm<TFr: Fr, TG1: G1 + G1Mul<TFr>>(points: &[TG1], scalars: &[TFr]) -> TG1 {
+    let mut out = TG1::identity();
+    for (point, scalar) in points.iter().zip(scalars) {
+        out.add_or_dbl_assign(&point.mul(scalar));
+    }
+    out
+}
+
+pub fn test_tiling_pippenger_with_bits<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let points = (0..300).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let affines = batch_convert::<TG1, TG1Fp, TG1Affine>(&points);
+
+    for nbits in [1, 2, 3, 7, 8, 13, 31, 64] {
+        let scalars = (0..points.len())
+            .map(|i| {
+                let value = (i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+                TFr::from_u64(if nbits == 64 {
+                    value
+                } else {
+                    value >> (64 - nbits)
+                })
+            })
+            .collect::<Vec<_>>();
+        let expected = naive_msm(&points, &scalars);
+
+        let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+        let result = tiling_pippenger_with_bits::<TG1, TG1Fp, TG1Affine>(&affines, &scalars, nbits);
+        assert!(result.equals(&expected), "failed for {} bits", nbits);
+    }
+}
+
+pub fn test_sparse_msm<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>() {
+    const LEN: usize = 512;
+    let points = (0..LEN).map(|_| TG1::rand()).collect::<Vec<_>>();
+
+    let zeros = vec![TFr::zero(); LEN];
+    let mut mostly_zeros = zeros.clone();
+    for i in (0..LEN).step_by(50) {
+        mostly_zeros[i] = TFr::rand();
+    }
+    let mut zeros_and_ones = zeros.clone();
+    for i in (0..LEN).step_by(3) {
+        zeros_and_ones[i] = TFr::one();
+    }
+    let small = (0..LEN)
+        .map(|i| TFr::from_u64((i as u64 * 7919) % 1000))
+        .collect::<Vec<_>>();
+    let mut small_and_large = small.clone();
+    small_and_large[17] = TFr::rand();
+    let dense = (0..LEN).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    let sparse = SparseScalars::new(&zeros);
+    assert!(sparse.is_empty());
+    assert!(sparse.is_cheaper(LEN, 4));
+    let sparse = SparseScalars::new(&zeros_and_ones);
+    assert_eq!(sparse.len(), 0);
+    assert!(sparse.is_cheaper(LEN, 4));
+    let sparse = SparseScalars::new(&small);
+    assert_eq!(sparse.num_bits(), 10);
+    assert!(sparse.is_cheaper(LEN, 4));
+    assert!(!SparseScalars::new(&small_and_large).is_cheaper(LEN, 2));
+    assert!(!SparseScalars::new(&dense).is_cheaper(LEN, 2));
+
+    for scalars in [
+        zeros,
+        mostly_zeros,
+        zeros_and_ones,
+        small,
+        small_and_large,
+        dense,
+    ] {
+        let expected = naive_msm(&points, &scalars);
+        let result =
+            msm::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(&points, &scalars, LEN, None);
+        assert!(result.equals(&expected));
+
+        let result =
+            SparseScalars::new(&scalars).msm::<TG1, TG1Fp, TG1Affine, TFr>(&points, &scalars);
+        assert!(result.equals(&expected));
+    }
+}
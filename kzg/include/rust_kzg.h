@@ -0,0 +1,125 @@
+/*
+ * Handle-based C ABI of rust-kzg, see kzg/src/c_api.rs.
+ *
+ * Every backend exports the same functions, so this header can be used with whichever backend the library was
+ * built with. Check kzg_abi_version() against RUST_KZG_ABI_VERSION before calling anything else.
+ */
+
+#ifndef RUST_KZG_H
+#define RUST_KZG_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define RUST_KZG_ABI_VERSION 1
+
+#define RUST_KZG_BYTES_PER_FIELD_ELEMENT 32
+#define RUST_KZG_FIELD_ELEMENTS_PER_BLOB 4096
+#define RUST_KZG_BYTES_PER_BLOB (RUST_KZG_BYTES_PER_FIELD_ELEMENT * RUST_KZG_FIELD_ELEMENTS_PER_BLOB)
+#define RUST_KZG_BYTES_PER_COMMITMENT 48
+#define RUST_KZG_BYTES_PER_PROOF 48
+#define RUST_KZG_BYTES_PER_G1 48
+#define RUST_KZG_BYTES_PER_G2 96
+#define RUST_KZG_POINT_EVALUATION_INPUT_LENGTH 192
+#define RUST_KZG_POINT_EVALUATION_OUTPUT_LENGTH 64
+
+typedef unsigned int C_KZG_RET;
+
+#define C_KZG_RET_OK 0
+#define C_KZG_RET_BADARGS 1
+#define C_KZG_RET_ERROR 2
+#define C_KZG_RET_MALLOC 3
+/* Inputs are well-formed, but the proof does not verify, see kzg_v1_point_evaluation_precompile() */
+#define C_KZG_RET_PROOF_MISMATCH 4
+
+typedef struct { uint8_t bytes[32]; } Bytes32;
+typedef struct { uint8_t bytes[48]; } Bytes48;
+typedef struct { uint8_t bytes[RUST_KZG_BYTES_PER_BLOB]; } Blob;
+typedef struct { uint8_t bytes[RUST_KZG_BYTES_PER_COMMITMENT]; } KZGCommitment;
+typedef struct { uint8_t bytes[RUST_KZG_BYTES_PER_PROOF]; } KZGProof;
+
+/* Trusted setup, together with its precomputation table and thread pool */
+typedef struct KzgHandle KzgHandle;
+/* Roots of unity for FFTs, erasure extension and recovery */
+typedef struct FFTSettingsHandle FFTSettingsHandle;
+/* FK20 multi-proof settings */
+typedef struct FK20MultiSettingsHandle FK20MultiSettingsHandle;
+
+uint32_t kzg_abi_version(void);
+
+/* EIP-4844 */
+
+C_KZG_RET kzg_v1_load_trusted_setup(KzgHandle **out, const uint8_t *g1_bytes, size_t n1,
+                                    const uint8_t *g2_bytes, size_t n2);
+
+C_KZG_RET kzg_v1_load_trusted_setup_contents(KzgHandle **out, const uint8_t *contents, size_t len);
+
+void kzg_v1_free_trusted_setup(KzgHandle *handle);
+
+C_KZG_RET kzg_v1_set_num_threads(KzgHandle *handle, size_t num_threads);
+
+size_t kzg_v1_field_elements_per_blob(const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_blob_to_kzg_commitment(KZGCommitment *out, const Blob *blob, const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_compute_kzg_proof(KZGProof *proof_out, Bytes32 *y_out, const Blob *blob,
+                                   const Bytes32 *z_bytes, const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_compute_blob_kzg_proof(KZGProof *out, const Blob *blob, const Bytes48 *commitment_bytes,
+                                        const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_verify_kzg_proof(bool *ok, const Bytes48 *commitment_bytes, const Bytes32 *z_bytes,
+                                  const Bytes32 *y_bytes, const Bytes48 *proof_bytes,
+                                  const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_verify_blob_kzg_proof(bool *ok, const Blob *blob, const Bytes48 *commitment_bytes,
+                                       const Bytes48 *proof_bytes, const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_verify_blob_kzg_proof_batch(bool *ok, const Blob *blobs, const Bytes48 *commitments_bytes,
+                                             const Bytes48 *proofs_bytes, size_t n, const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_verify_blob_kzg_proof_batch_per_item(bool *results, const Blob *blobs,
+                                                      const Bytes48 *commitments_bytes,
+                                                      const Bytes48 *proofs_bytes, size_t n,
+                                                      const KzgHandle *handle);
+
+C_KZG_RET kzg_v1_point_evaluation_precompile(uint8_t *out, const uint8_t *input, size_t input_len,
+                                             const KzgHandle *handle);
+
+/* FFT, erasure extension and recovery. Field elements are 32 bytes, big-endian */
+
+C_KZG_RET kzg_v1_new_fft_settings(FFTSettingsHandle **out, size_t max_scale);
+
+void kzg_v1_free_fft_settings(FFTSettingsHandle *fs);
+
+C_KZG_RET kzg_v1_fft_fr(Bytes32 *out, const Bytes32 *data, size_t n, bool inverse, const FFTSettingsHandle *fs);
+
+/* Takes n even-indexed evaluations over a domain of 2 * n elements, writes n odd-indexed ones */
+C_KZG_RET kzg_v1_das_fft_extension(Bytes32 *out, const Bytes32 *evens, size_t n, const FFTSettingsHandle *fs);
+
+/* present[i] tells whether samples[i] is known, at least half of n samples are required */
+C_KZG_RET kzg_v1_recover_poly_from_samples(Bytes32 *out, const Bytes32 *samples, const bool *present, size_t n,
+                                           const FFTSettingsHandle *fs);
+
+/* FK20 multi-proofs. G1 and G2 points are powers of secret in monomial form */
+
+C_KZG_RET kzg_v1_new_fk20_multi_settings(FK20MultiSettingsHandle **out, const uint8_t *g1_bytes, size_t num_g1,
+                                         const uint8_t *g2_bytes, size_t num_g2, size_t n2, size_t chunk_len,
+                                         const FFTSettingsHandle *fs);
+
+void kzg_v1_free_fk20_multi_settings(FK20MultiSettingsHandle *fk);
+
+/* out_len has to be 2 * n / chunk_len */
+C_KZG_RET kzg_v1_data_availability_optimized(KZGProof *out, size_t out_len, const Bytes32 *coeffs, size_t n,
+                                             const FK20MultiSettingsHandle *fk);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* RUST_KZG_H */
@@ -0,0 +1,141 @@
+/*
+ * Checks rust_kzg.h against a backend built as a static library, see run-c-api-header-tests.sh.
+ *
+ * Every function declared in the header is referenced below, so linking fails if one of them is missing from the
+ * library. Calls below go through the declared signatures, so drift of the common ones shows up as failed checks.
+ */
+
+#include "rust_kzg.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#define CHECK(cond)                                                                   \
+    do {                                                                              \
+        if (!(cond)) {                                                                \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+            exit(1);                                                                  \
+        }                                                                             \
+    } while (0)
+
+typedef void (*any_fn)(void);
+
+static const any_fn exported[] = {
+    (any_fn)kzg_abi_version,
+    (any_fn)kzg_v1_load_trusted_setup,
+    (any_fn)kzg_v1_load_trusted_setup_contents,
+    (any_fn)kzg_v1_free_trusted_setup,
+    (any_fn)kzg_v1_set_num_threads,
+    (any_fn)kzg_v1_field_elements_per_blob,
+    (any_fn)kzg_v1_blob_to_kzg_commitment,
+    (any_fn)kzg_v1_compute_kzg_proof,
+    (any_fn)kzg_v1_compute_blob_kzg_proof,
+    (any_fn)kzg_v1_verify_kzg_proof,
+    (any_fn)kzg_v1_verify_blob_kzg_proof,
+    (any_fn)kzg_v1_verify_blob_kzg_proof_batch,
+    (any_fn)kzg_v1_verify_blob_kzg_proof_batch_per_item,
+    (any_fn)kzg_v1_point_evaluation_precompile,
+    (any_fn)kzg_v1_new_fft_settings,
+    (any_fn)kzg_v1_free_fft_settings,
+    (any_fn)kzg_v1_fft_fr,
+    (any_fn)kzg_v1_das_fft_extension,
+    (any_fn)kzg_v1_recover_poly_from_samples,
+    (any_fn)kzg_v1_new_fk20_multi_settings,
+    (any_fn)kzg_v1_free_fk20_multi_settings,
+    (any_fn)kzg_v1_data_availability_optimized,
+};
+
+static uint8_t *read_file(const char *path, size_t *len) {
+    FILE *f = fopen(path, "rb");
+    CHECK(f != NULL);
+    CHECK(fseek(f, 0, SEEK_END) == 0);
+    long size = ftell(f);
+    CHECK(size > 0);
+    rewind(f);
+
+    uint8_t *contents = malloc((size_t)size);
+    CHECK(contents != NULL);
+    CHECK(fread(contents, 1, (size_t)size, f) == (size_t)size);
+    fclose(f);
+
+    *len = (size_t)size;
+    return contents;
+}
+
+static void test_eip_4844(const char *trusted_setup_path) {
+    size_t len;
+    uint8_t *contents = read_file(trusted_setup_path, &len);
+
+    KzgHandle *handle = NULL;
+    CHECK(kzg_v1_load_trusted_setup_contents(&handle, contents, len) == C_KZG_RET_OK);
+    free(contents);
+    CHECK(kzg_v1_field_elements_per_blob(handle) == RUST_KZG_FIELD_ELEMENTS_PER_BLOB);
+    CHECK(kzg_v1_set_num_threads(handle, 1) == C_KZG_RET_OK);
+
+    static Blob blob;
+    for (size_t i = 0; i < RUST_KZG_FIELD_ELEMENTS_PER_BLOB; i++) {
+        blob.bytes[i * RUST_KZG_BYTES_PER_FIELD_ELEMENT + RUST_KZG_BYTES_PER_FIELD_ELEMENT - 1] = (uint8_t)i;
+    }
+
+    KZGCommitment commitment;
+    KZGProof proof;
+    CHECK(kzg_v1_blob_to_kzg_commitment(&commitment, &blob, handle) == C_KZG_RET_OK);
+
+    Bytes48 commitment_bytes;
+    memcpy(commitment_bytes.bytes, commitment.bytes, sizeof(commitment_bytes.bytes));
+    CHECK(kzg_v1_compute_blob_kzg_proof(&proof, &blob, &commitment_bytes, handle) == C_KZG_RET_OK);
+
+    Bytes48 proof_bytes;
+    memcpy(proof_bytes.bytes, proof.bytes, sizeof(proof_bytes.bytes));
+    bool ok = false;
+    CHECK(kzg_v1_verify_blob_kzg_proof(&ok, &blob, &commitment_bytes, &proof_bytes, handle) == C_KZG_RET_OK);
+    CHECK(ok);
+
+    bool results[1] = {false};
+    CHECK(kzg_v1_verify_blob_kzg_proof_batch_per_item(results, &blob, &commitment_bytes, &proof_bytes, 1,
+                                                      handle) == C_KZG_RET_OK);
+    CHECK(results[0]);
+
+    Bytes32 z = {{0}};
+    Bytes32 y;
+    z.bytes[31] = 7;
+    CHECK(kzg_v1_compute_kzg_proof(&proof, &y, &blob, &z, handle) == C_KZG_RET_OK);
+    memcpy(proof_bytes.bytes, proof.bytes, sizeof(proof_bytes.bytes));
+    CHECK(kzg_v1_verify_kzg_proof(&ok, &commitment_bytes, &z, &y, &proof_bytes, handle) == C_KZG_RET_OK);
+    CHECK(ok);
+
+    kzg_v1_free_trusted_setup(handle);
+}
+
+static void test_fft(void) {
+    FFTSettingsHandle *fs = NULL;
+    CHECK(kzg_v1_new_fft_settings(&fs, 4) == C_KZG_RET_OK);
+
+    Bytes32 data[16] = {{{0}}};
+    Bytes32 coeffs[16];
+    Bytes32 back[16];
+    for (size_t i = 0; i < 16; i++) {
+        data[i].bytes[31] = (uint8_t)(i + 1);
+    }
+
+    CHECK(kzg_v1_fft_fr(coeffs, data, 16, false, fs) == C_KZG_RET_OK);
+    CHECK(kzg_v1_fft_fr(back, coeffs, 16, true, fs) == C_KZG_RET_OK);
+    CHECK(memcmp(back, data, sizeof(data)) == 0);
+
+    kzg_v1_free_fft_settings(fs);
+}
+
+int main(int argc, char **argv) {
+    CHECK(argc == 2);
+    CHECK(kzg_abi_version() == RUST_KZG_ABI_VERSION);
+    for (size_t i = 0; i < sizeof(exported) / sizeof(exported[0]); i++) {
+        CHECK(exported[i] != NULL);
+    }
+
+    test_eip_4844(argv[1]);
+    test_fft();
+
+    printf("rust_kzg.h: ok\n");
+    return 0;
+}
@@ -0,0 +1,430 @@
+//! Backend-agnostic entry point to EIP-4844 functions.
+//!
+//! Generic functions in [`crate::eip_4844`] take eight type parameters, one for every type of a backend. A
+//! [`Backend`] names all of them at once, so application code can be written against [`KzgContext`] and switch
+//! backends by changing a single type alias:
+//!
+//! ```ignore
+//! type Kzg = KzgContext<rust_kzg_blst::eip_4844::BlstBackend>;
+//!
+//! let kzg = Kzg::load_trusted_setup_file("trusted_setup.txt")?;
+//! let commitment = kzg.blob_to_kzg_commitment(&blob)?;
+//! ```
+//!
+//! When the backend is only known at runtime, e.g. from a config file, [`BackendRegistry`] builds a [`DynKzg`],
+//! which works on serialized inputs and hides the backend behind dynamic dispatch:
+//!
+//! ```ignore
+//! let mut registry = BackendRegistry::new();
+//! registry.register::<rust_kzg_blst::eip_4844::BlstBackend>();
+//! registry.register::<rust_kzg_constantine::eip_4844::CtBackend>();
+//!
+//! let kzg: Box<dyn DynKzg> = registry.load_trusted_setup(&config.backend, &contents)?;
+//! let commitment = kzg.blob_to_kzg_commitment(&blob)?;
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::eip_4844::{
+    blob_to_kzg_commitment_rust, bytes_to_blob, compute_blob_kzg_proof_rust,
+    compute_kzg_proof_rust, verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_rust,
+    verify_kzg_proof_rust, Bytes32, Bytes48, KZGCommitment, KZGProof,
+};
+use crate::{
+    Error, FFTFr, FFTSettings, FK20MultiSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul,
+    KZGSettings, PairingVerify, Poly, PolyRecover, DAS, G1, G2,
+};
+
+/// Family of types, which make up a backend
+pub trait Backend {
+    /// Name, which the backend is registered under in [`BackendRegistry`]
+    const NAME: &'static str;
+
+    type Fr: Fr + Copy;
+    type G1: G1
+        + G1Mul<Self::Fr>
+        + G1GetFp<Self::G1Fp>
+        + G1LinComb<Self::Fr, Self::G1Fp, Self::G1Affine>
+        + PairingVerify<Self::G1, Self::G2>;
+    type G2: G2;
+    type G1Fp: G1Fp;
+    type G1Affine: G1Affine<Self::G1, Self::G1Fp>;
+    type Poly: Poly<Self::Fr> + PolyRecover<Self::Fr, Self::Poly, Self::FFTSettings>;
+    type FFTSettings: FFTSettings<Self::Fr> + FFTFr<Self::Fr> + DAS<Self::Fr>;
+    type KZGSettings: KZGSettings<
+            Self::Fr,
+            Self::G1,
+            Self::G2,
+            Self::FFTSettings,
+            Self::Poly,
+            Self::G1Fp,
+            Self::G1Affine,
+        > + Send
+        + Sync;
+    type FK20MultiSettings: FK20MultiSettings<
+        Self::Fr,
+        Self::G1,
+        Self::G2,
+        Self::FFTSettings,
+        Self::Poly,
+        Self::KZGSettings,
+        Self::G1Fp,
+        Self::G1Affine,
+    >;
+
+    /// Load trusted setup in any format supported by the backend
+    fn load_trusted_setup(contents: &[u8]) -> Result<Self::KZGSettings, Error>;
+}
+
+/// Settings of a backend, together with EIP-4844 functions, which use them
+pub struct KzgContext<B: Backend> {
+    settings: B::KZGSettings,
+}
+
+impl<B: Backend> Clone for KzgContext<B> {
+    fn clone(&self) -> Self {
+        Self::new(self.settings.clone())
+    }
+}
+
+impl<B: Backend> KzgContext<B> {
+    pub fn new(settings: B::KZGSettings) -> Self {
+        Self { settings }
+    }
+
+    /// Load trusted setup in any format supported by the backend
+    pub fn load_trusted_setup(contents: &[u8]) -> Result<Self, Error> {
+        B::load_trusted_setup(contents).map(Self::new)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn load_trusted_setup_file(filepath: &str) -> Result<Self, Error> {
+        let contents = std::fs::read(filepath)
+            .map_err(|_| Error::InvalidTrustedSetup(String::from("Unable to read file")))?;
+        Self::load_trusted_setup(&contents)
+    }
+
+    pub fn settings(&self) -> &B::KZGSettings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut B::KZGSettings {
+        &mut self.settings
+    }
+
+    pub fn into_settings(self) -> B::KZGSettings {
+        self.settings
+    }
+
+    pub fn field_elements_per_blob(&self) -> usize {
+        self.settings.get_field_elements_per_blob()
+    }
+
+    /// Deserialize blob of the width of the loaded trusted setup
+    pub fn bytes_to_blob(&self, bytes: &[u8]) -> Result<Vec<B::Fr>, Error> {
+        let blob = bytes_to_blob(bytes)?;
+        if blob.len() != self.field_elements_per_blob() {
+            return Err(Error::InvalidLength(String::from(
+                "Blob width does not match trusted setup",
+            )));
+        }
+
+        Ok(blob)
+    }
+
+    pub fn blob_to_kzg_commitment(&self, blob: &[B::Fr]) -> Result<B::G1, Error> {
+        blob_to_kzg_commitment_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+        >(blob, &self.settings)
+    }
+
+    /// Returns proof and evaluation of blob polynomial at `z`
+    pub fn compute_kzg_proof(&self, blob: &[B::Fr], z: &B::Fr) -> Result<(B::G1, B::Fr), Error> {
+        compute_kzg_proof_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+        >(blob, z, &self.settings)
+    }
+
+    pub fn compute_blob_kzg_proof(
+        &self,
+        blob: &[B::Fr],
+        commitment: &B::G1,
+    ) -> Result<B::G1, Error> {
+        compute_blob_kzg_proof_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+        >(blob, commitment, &self.settings)
+    }
+
+    pub fn verify_kzg_proof(
+        &self,
+        commitment: &B::G1,
+        z: &B::Fr,
+        y: &B::Fr,
+        proof: &B::G1,
+    ) -> Result<bool, Error> {
+        verify_kzg_proof_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+        >(commitment, z, y, proof, &self.settings)
+    }
+
+    pub fn verify_blob_kzg_proof(
+        &self,
+        blob: &[B::Fr],
+        commitment: &B::G1,
+        proof: &B::G1,
+    ) -> Result<bool, Error> {
+        verify_blob_kzg_proof_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+        >(blob, commitment, proof, &self.settings)
+    }
+
+    pub fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[Vec<B::Fr>],
+        commitments: &[B::G1],
+        proofs: &[B::G1],
+    ) -> Result<bool, Error> {
+        verify_blob_kzg_proof_batch_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+        >(blobs, commitments, proofs, &self.settings)
+    }
+}
+
+/// Object-safe EIP-4844 interface over serialized inputs, see [`BackendRegistry`]. Blobs are concatenated field
+/// elements, and must be as wide as the loaded trusted setup, see [`DynKzg::field_elements_per_blob`]
+pub trait DynKzg: Send + Sync {
+    /// Name of the backend, which does the work
+    fn backend_name(&self) -> &'static str;
+
+    fn field_elements_per_blob(&self) -> usize;
+
+    fn blob_to_kzg_commitment(&self, blob: &[u8]) -> Result<KZGCommitment, Error>;
+
+    /// Returns proof and evaluation of blob polynomial at `z`
+    fn compute_kzg_proof(&self, blob: &[u8], z: &Bytes32) -> Result<(KZGProof, Bytes32), Error>;
+
+    fn compute_blob_kzg_proof(&self, blob: &[u8], commitment: &Bytes48) -> Result<KZGProof, Error>;
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &Bytes48,
+        z: &Bytes32,
+        y: &Bytes32,
+        proof: &Bytes48,
+    ) -> Result<bool, Error>;
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8],
+        commitment: &Bytes48,
+        proof: &Bytes48,
+    ) -> Result<bool, Error>;
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[&[u8]],
+        commitments: &[Bytes48],
+        proofs: &[Bytes48],
+    ) -> Result<bool, Error>;
+}
+
+impl<B: Backend> DynKzg for KzgContext<B> {
+    fn backend_name(&self) -> &'static str {
+        B::NAME
+    }
+
+    fn field_elements_per_blob(&self) -> usize {
+        KzgContext::field_elements_per_blob(self)
+    }
+
+    fn blob_to_kzg_commitment(&self, blob: &[u8]) -> Result<KZGCommitment, Error> {
+        let blob = self.bytes_to_blob(blob)?;
+        let commitment = KzgContext::blob_to_kzg_commitment(self, &blob)?;
+        Ok(KZGCommitment {
+            bytes: commitment.to_bytes(),
+        })
+    }
+
+    fn compute_kzg_proof(&self, blob: &[u8], z: &Bytes32) -> Result<(KZGProof, Bytes32), Error> {
+        let blob = self.bytes_to_blob(blob)?;
+        let z = B::Fr::from_bytes(&z.bytes)?;
+        let (proof, y) = KzgContext::compute_kzg_proof(self, &blob, &z)?;
+        Ok((
+            KZGProof {
+                bytes: proof.to_bytes(),
+            },
+            Bytes32 {
+                bytes: y.to_bytes(),
+            },
+        ))
+    }
+
+    fn compute_blob_kzg_proof(&self, blob: &[u8], commitment: &Bytes48) -> Result<KZGProof, Error> {
+        let blob = self.bytes_to_blob(blob)?;
+        let commitment = B::G1::from_bytes(&commitment.bytes)?;
+        let proof = KzgContext::compute_blob_kzg_proof(self, &blob, &commitment)?;
+        Ok(KZGProof {
+            bytes: proof.to_bytes(),
+        })
+    }
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &Bytes48,
+        z: &Bytes32,
+        y: &Bytes32,
+        proof: &Bytes48,
+    ) -> Result<bool, Error> {
+        let commitment = B::G1::from_bytes(&commitment.bytes)?;
+        let z = B::Fr::from_bytes(&z.bytes)?;
+        let y = B::Fr::from_bytes(&y.bytes)?;
+        let proof = B::G1::from_bytes(&proof.bytes)?;
+        KzgContext::verify_kzg_proof(self, &commitment, &z, &y, &proof)
+    }
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8],
+        commitment: &Bytes48,
+        proof: &Bytes48,
+    ) -> Result<bool, Error> {
+        let blob = self.bytes_to_blob(blob)?;
+        let commitment = B::G1::from_bytes(&commitment.bytes)?;
+        let proof = B::G1::from_bytes(&proof.bytes)?;
+        KzgContext::verify_blob_kzg_proof(self, &blob, &commitment, &proof)
+    }
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[&[u8]],
+        commitments: &[Bytes48],
+        proofs: &[Bytes48],
+    ) -> Result<bool, Error> {
+        let blobs = blobs
+            .iter()
+            .map(|blob| self.bytes_to_blob(blob))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let commitments = commitments
+            .iter()
+            .map(|commitment| B::G1::from_bytes(&commitment.bytes))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let proofs = proofs
+            .iter()
+            .map(|proof| B::G1::from_bytes(&proof.bytes))
+            .collect::<Result<Vec<_>, Error>>()?;
+        KzgContext::verify_blob_kzg_proof_batch(self, &blobs, &commitments, &proofs)
+    }
+}
+
+type DynKzgLoader = fn(&[u8]) -> Result<Box<dyn DynKzg>, Error>;
+
+/// Backends, which can be chosen by name at runtime
+#[derive(Debug, Clone, Default)]
+pub struct BackendRegistry {
+    loaders: Vec<(&'static str, DynKzgLoader)>,
+}
+
+impl BackendRegistry {
+    pub const fn new() -> Self {
+        Self {
+            loaders: Vec::new(),
+        }
+    }
+
+    /// Make backend available under [`Backend::NAME`]. Registering the same name again replaces the backend
+    pub fn register<B: Backend + 'static>(&mut self) -> &mut Self {
+        let loader: DynKzgLoader = |contents| {
+            KzgContext::<B>::load_trusted_setup(contents)
+                .map(|kzg| Box::new(kzg) as Box<dyn DynKzg>)
+        };
+
+        match self.loaders.iter_mut().find(|(name, _)| *name == B::NAME) {
+            Some(entry) => entry.1 = loader,
+            None => self.loaders.push((B::NAME, loader)),
+        }
+        self
+    }
+
+    /// Names of registered backends, in order of registration
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.loaders.iter().map(|(name, _)| *name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.loaders
+            .iter()
+            .any(|(registered, _)| *registered == name)
+    }
+
+    /// Load trusted setup with the backend registered under `name`
+    pub fn load_trusted_setup(
+        &self,
+        name: &str,
+        contents: &[u8],
+    ) -> Result<Box<dyn DynKzg>, Error> {
+        let (_, loader) = self
+            .loaders
+            .iter()
+            .find(|(registered, _)| *registered == name)
+            .ok_or_else(|| Error::UnknownBackend(String::from(name)))?;
+
+        loader(contents)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn load_trusted_setup_file(
+        &self,
+        name: &str,
+        filepath: &str,
+    ) -> Result<Box<dyn DynKzg>, Error> {
+        let contents = std::fs::read(filepath)
+            .map_err(|_| Error::InvalidTrustedSetup(String::from("Unable to read file")))?;
+        self.load_trusted_setup(name, &contents)
+    }
+}
@@ -0,0 +1,338 @@
+//! Packing of arbitrary payloads into blobs of canonical field elements.
+//!
+//! The first field element of every blob is a header: a zero byte, the codec version, then the
+//! payload length, the index of the blob and the number of blobs the payload was split into, all
+//! as big-endian `u32`, followed by zeros. The payload follows in the remaining field elements,
+//! packed according to the codec version and padded with zeros. Decoding rejects blobs which are
+//! not exactly what the encoder would produce, so each payload has a single valid encoding, and
+//! dropped, duplicated or reordered blobs are detected.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::eip_4844::{is_valid_blob_width, BYTES_PER_FIELD_ELEMENT};
+use crate::Error;
+
+const HEADER_VERSION_OFFSET: usize = 1;
+const HEADER_LENGTH_OFFSET: usize = 2;
+const HEADER_INDEX_OFFSET: usize = 6;
+const HEADER_COUNT_OFFSET: usize = 10;
+const BITS_PER_PACKED_FIELD_ELEMENT: usize = 254;
+
+/// Ways of packing payload bytes into field elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlobCodecVersion {
+    /// 31 payload bytes per field element, the most significant byte is always zero
+    Bytes31 = 0,
+    /// 254 payload bits per field element, the two most significant bits are always zero
+    Bits254 = 1,
+}
+
+impl BlobCodecVersion {
+    pub fn from_u8(version: u8) -> Result<Self, Error> {
+        match version {
+            0 => Ok(Self::Bytes31),
+            1 => Ok(Self::Bits254),
+            _ => Err(Error::BadArgs(format!(
+                "Unsupported blob codec version {}",
+                version
+            ))),
+        }
+    }
+}
+
+/// Maximum number of payload bytes, which fit into a single blob
+pub fn blob_capacity(version: BlobCodecVersion, field_elements_per_blob: usize) -> usize {
+    let data_elements = field_elements_per_blob.saturating_sub(1);
+    match version {
+        BlobCodecVersion::Bytes31 => data_elements * (BYTES_PER_FIELD_ELEMENT - 1),
+        BlobCodecVersion::Bits254 => data_elements * BITS_PER_PACKED_FIELD_ELEMENT / 8,
+    }
+}
+
+/// Encodes payload into a single blob of `field_elements_per_blob` field elements
+pub fn encode_blob(
+    payload: &[u8],
+    version: BlobCodecVersion,
+    field_elements_per_blob: usize,
+) -> Result<Vec<u8>, Error> {
+    encode_blob_part(payload, version, field_elements_per_blob, 0, 1)
+}
+
+/// Encodes `index`-th of `count` blobs, which a payload was split into
+fn encode_blob_part(
+    payload: &[u8],
+    version: BlobCodecVersion,
+    field_elements_per_blob: usize,
+    index: u32,
+    count: u32,
+) -> Result<Vec<u8>, Error> {
+    if !is_valid_blob_width(field_elements_per_blob) {
+        return Err(Error::BadArgs(String::from(
+            "Blob width must be a power of two",
+        )));
+    }
+    let capacity = blob_capacity(version, field_elements_per_blob);
+    if payload.len() > capacity {
+        return Err(Error::InvalidLength(format!(
+            "Payload of {} bytes does not fit into a blob of {} bytes",
+            payload.len(),
+            capacity
+        )));
+    }
+
+    let mut blob = vec![0u8; field_elements_per_blob * BYTES_PER_FIELD_ELEMENT];
+    let (header, data) = blob.split_at_mut(BYTES_PER_FIELD_ELEMENT);
+    header[HEADER_VERSION_OFFSET] = version as u8;
+    header[HEADER_LENGTH_OFFSET..HEADER_LENGTH_OFFSET + 4]
+        .copy_from_slice(&(payload.len() as u32).to_be_bytes());
+    header[HEADER_INDEX_OFFSET..HEADER_INDEX_OFFSET + 4].copy_from_slice(&index.to_be_bytes());
+    header[HEADER_COUNT_OFFSET..HEADER_COUNT_OFFSET + 4].copy_from_slice(&count.to_be_bytes());
+
+    match version {
+        BlobCodecVersion::Bytes31 => {
+            for (element, chunk) in data
+                .chunks_exact_mut(BYTES_PER_FIELD_ELEMENT)
+                .zip(payload.chunks(BYTES_PER_FIELD_ELEMENT - 1))
+            {
+                element[1..1 + chunk.len()].copy_from_slice(chunk);
+            }
+        }
+        BlobCodecVersion::Bits254 => pack_bits(payload, data),
+    }
+
+    Ok(blob)
+}
+
+/// Decodes payload from a blob produced by [`encode_blob`]
+pub fn decode_blob(blob: &[u8]) -> Result<Vec<u8>, Error> {
+    let part = decode_blob_part(blob)?;
+    if part.count != 1 {
+        return Err(Error::BadArgs(format!(
+            "Blob is one of {} blobs, decode them together",
+            part.count
+        )));
+    }
+
+    Ok(part.payload)
+}
+
+/// Blob decoded by [`decode_blob_part`]
+struct BlobPart {
+    payload: Vec<u8>,
+    version: BlobCodecVersion,
+    index: u32,
+    count: u32,
+}
+
+/// Decodes a blob produced by [`encode_blob_part`], together with its position
+fn decode_blob_part(blob: &[u8]) -> Result<BlobPart, Error> {
+    if blob.len() % BYTES_PER_FIELD_ELEMENT != 0
+        || !is_valid_blob_width(blob.len() / BYTES_PER_FIELD_ELEMENT)
+    {
+        return Err(Error::InvalidLength(format!(
+            "Invalid byte length. Expected a power of two number of field elements, got {} bytes",
+            blob.len(),
+        )));
+    }
+    let field_elements_per_blob = blob.len() / BYTES_PER_FIELD_ELEMENT;
+
+    let version = BlobCodecVersion::from_u8(blob[HEADER_VERSION_OFFSET])?;
+    let len = u32::from_be_bytes(
+        blob[HEADER_LENGTH_OFFSET..HEADER_LENGTH_OFFSET + 4]
+            .try_into()
+            .unwrap(),
+    ) as usize;
+    let read_u32 = |offset: usize| u32::from_be_bytes(blob[offset..offset + 4].try_into().unwrap());
+    let index = read_u32(HEADER_INDEX_OFFSET);
+    let count = read_u32(HEADER_COUNT_OFFSET);
+    if index >= count {
+        return Err(Error::BadArgs(format!(
+            "Blob index {} is out of {} blobs",
+            index, count
+        )));
+    }
+    if len > blob_capacity(version, field_elements_per_blob) {
+        return Err(Error::InvalidLength(format!(
+            "Payload length {} exceeds blob capacity",
+            len
+        )));
+    }
+
+    let data = &blob[BYTES_PER_FIELD_ELEMENT..];
+    let payload = match version {
+        BlobCodecVersion::Bytes31 => data
+            .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+            .flat_map(|element| element[1..].iter().copied())
+            .take(len)
+            .collect::<Vec<u8>>(),
+        BlobCodecVersion::Bits254 => {
+            let mut payload = unpack_bits(data, blob_capacity(version, field_elements_per_blob));
+            payload.truncate(len);
+            payload
+        }
+    };
+
+    // Header, padding and unused bits must be zero
+    if encode_blob_part(&payload, version, field_elements_per_blob, index, count)? != blob {
+        return Err(Error::BadArgs(String::from(
+            "Blob is not canonically encoded",
+        )));
+    }
+
+    Ok(BlobPart {
+        payload,
+        version,
+        index,
+        count,
+    })
+}
+
+/// Encodes payload into as many blobs as needed. Empty payload is encoded into a single blob.
+pub fn encode_blobs(
+    payload: &[u8],
+    version: BlobCodecVersion,
+    field_elements_per_blob: usize,
+) -> Result<Vec<Vec<u8>>, Error> {
+    let capacity = blob_capacity(version, field_elements_per_blob);
+    if payload.is_empty() || capacity == 0 {
+        return Ok(vec![encode_blob(
+            payload,
+            version,
+            field_elements_per_blob,
+        )?]);
+    }
+
+    let count = u32::try_from(payload.len().div_ceil(capacity)).map_err(|_| {
+        Error::InvalidLength(format!(
+            "Payload of {} bytes needs more than {} blobs",
+            payload.len(),
+            u32::MAX
+        ))
+    })?;
+    payload
+        .chunks(capacity)
+        .enumerate()
+        .map(|(index, chunk)| {
+            encode_blob_part(chunk, version, field_elements_per_blob, index as u32, count)
+        })
+        .collect()
+}
+
+/// Decodes payload split by [`encode_blobs`]. Blobs must be given in order and all of them, as
+/// each blob records its index and the number of blobs.
+pub fn decode_blobs<T: AsRef<[u8]>>(blobs: &[T]) -> Result<Vec<u8>, Error> {
+    let Some(first) = blobs.first() else {
+        return Err(Error::InvalidLength(String::from("No blobs to decode")));
+    };
+    let width = first.as_ref().len();
+
+    let mut payload = Vec::new();
+    let mut version = None;
+    for (i, blob) in blobs.iter().enumerate() {
+        let blob = blob.as_ref();
+        if blob.len() != width {
+            return Err(Error::InvalidLength(format!(
+                "Blob {} has {} bytes, expected {}",
+                i,
+                blob.len(),
+                width
+            )));
+        }
+
+        let part = decode_blob_part(blob)?;
+        if part.count as usize != blobs.len() {
+            return Err(Error::InvalidLength(format!(
+                "Payload was encoded into {} blobs, got {}",
+                part.count,
+                blobs.len()
+            )));
+        }
+        if part.index as usize != i {
+            return Err(Error::BadArgs(format!(
+                "Blob {} was encoded at index {}",
+                i, part.index
+            )));
+        }
+        if *version.get_or_insert(part.version) != part.version {
+            return Err(Error::BadArgs(format!(
+                "Blob {} uses a different codec version",
+                i
+            )));
+        }
+
+        // Only the last blob may be partially filled
+        let capacity = blob_capacity(part.version, width / BYTES_PER_FIELD_ELEMENT);
+        if i + 1 != blobs.len() && part.payload.len() != capacity {
+            return Err(Error::BadArgs(format!(
+                "Blob {} is not the last one, but is not full",
+                i
+            )));
+        }
+
+        payload.extend_from_slice(&part.payload);
+    }
+
+    Ok(payload)
+}
+
+/// Byte of `data` starting at bit `pos`, bits outside of `data` are zero
+fn read_byte_at_bit(data: &[u8], pos: isize) -> u8 {
+    let get = |i: isize| {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| data.get(i).copied())
+            .unwrap_or(0)
+    };
+    let byte = pos.div_euclid(8);
+    let shift = pos.rem_euclid(8) as u32;
+    if shift == 0 {
+        get(byte)
+    } else {
+        (get(byte) << shift) | (get(byte + 1) >> (8 - shift))
+    }
+}
+
+/// Writes bit stream of `payload` into the low 254 bits of each field element in `out`
+fn pack_bits(payload: &[u8], out: &mut [u8]) {
+    for (i, element) in out.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT).enumerate() {
+        let start = (i * BITS_PER_PACKED_FIELD_ELEMENT) as isize;
+        if start >= (payload.len() * 8) as isize {
+            break;
+        }
+
+        // Element is a 256 bit window, which starts two bits before its first payload bit
+        for (k, byte) in element.iter_mut().enumerate() {
+            *byte = read_byte_at_bit(payload, start - 2 + 8 * k as isize);
+        }
+        element[0] &= 0x3f;
+    }
+}
+
+/// Reads `len` bytes of bit stream from the low 254 bits of each field element in `data`
+fn unpack_bits(data: &[u8], len: usize) -> Vec<u8> {
+    let mut out = vec![0u8; len];
+    for (i, element) in data.chunks_exact(BYTES_PER_FIELD_ELEMENT).enumerate() {
+        let start = (i * BITS_PER_PACKED_FIELD_ELEMENT) as isize - 2;
+        for (k, byte) in element.iter().enumerate() {
+            let pos = start + 8 * k as isize;
+            let index = pos.div_euclid(8);
+            let shift = pos.rem_euclid(8) as u32;
+            let byte = if k == 0 { byte & 0x3f } else { *byte };
+            if let Some(out) = usize::try_from(index).ok().and_then(|i| out.get_mut(i)) {
+                *out |= byte >> shift;
+            }
+            if shift != 0 {
+                if let Some(out) = usize::try_from(index + 1).ok().and_then(|i| out.get_mut(i)) {
+                    *out |= byte << (8 - shift);
+                }
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,1121 @@
+//! Versioned C ABI, where every loaded trusted setup is an opaque handle.
+//!
+//! c-kzg-4844 compatible functions pass settings as [`CKZGSettings`](crate::eip_4844::CKZGSettings), which has
+//! room for raw points only, so everything else, like precomputation tables and thread pools, has to be found
+//! on the side. Functions of this ABI take a pointer to [`KzgHandle`] instead, which owns settings together with
+//! all of their state. Handles are independent of each other, so e.g. mainnet and devnet setups can be used at
+//! the same time from any number of threads.
+//!
+//! FFTs, erasure extension, recovery and FK20 multi-proofs work on handles of their own, see
+//! [`FFTSettingsHandle`] and [`FK20MultiSettingsHandle`]. Field elements are passed as [`Bytes32`], proofs as
+//! [`KZGProof`].
+//!
+//! Backends export these functions with [`export_c_api!`](crate::export_c_api), prefixed with `kzg_v1_`. The
+//! c-kzg-4844 compatible functions of every backend are thin wrappers around the same functions, which find
+//! handle of settings in [`CKZGHandles`].
+//!
+//! Declarations for C callers are in `kzg/include/rust_kzg.h`. `run-c-api-header-tests.sh` checks that it declares
+//! every function of [`export_c_api!`](crate::export_c_api), then links `kzg/include/test_rust_kzg.c` against a
+//! backend built as a static library and runs it.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::backend::{Backend, DynKzg, KzgContext};
+use crate::eip_4844::{
+    load_trusted_setup_rust, point_evaluation_precompile_rust,
+    verify_blob_kzg_proof_batch_failures_rust, Blob, Bytes32, Bytes48, KZGCommitment, KZGProof,
+    BYTES_PER_G1, BYTES_PER_G2, C_KZG_RET, C_KZG_RET_BADARGS, C_KZG_RET_OK,
+    FIELD_ELEMENTS_PER_BLOB,
+};
+use crate::thread_pool::ThreadPoolConfig;
+use crate::{
+    Error, FFTFr, FFTSettings, FK20MultiSettings, Fr, KZGSettings, Poly, PolyRecover, DAS, G1, G2,
+};
+
+/// Version of the handle-based ABI. Incremented whenever signature or behaviour of an exported function changes
+pub const KZG_ABI_VERSION: u32 = 1;
+
+/// Settings loaded through the C API, together with their precomputation table and thread pool
+pub type KzgHandle<B> = KzgContext<B>;
+
+macro_rules! handle_ckzg_error {
+    ($x: expr) => {
+        match $x {
+            Ok(value) => value,
+            Err(err) => return err.c_kzg_ret(),
+        }
+    };
+}
+
+/// Load trusted setup from `n1` G1 points in Lagrange form and `n2` G2 points, compressed
+pub fn load_trusted_setup_handle<B: Backend>(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+) -> Result<KzgHandle<B>, Error> {
+    check_blob_width(g1_bytes.len() / BYTES_PER_G1)?;
+
+    load_trusted_setup_rust::<
+        B::Fr,
+        B::G1,
+        B::G2,
+        B::FFTSettings,
+        B::Poly,
+        B::KZGSettings,
+        B::G1Fp,
+        B::G1Affine,
+    >(g1_bytes, g2_bytes)
+    .map(KzgHandle::new)
+}
+
+/// Load trusted setup in any format supported by the backend
+pub fn load_trusted_setup_contents_handle<B: Backend>(
+    contents: &[u8],
+) -> Result<KzgHandle<B>, Error> {
+    let handle = KzgHandle::<B>::load_trusted_setup(contents)?;
+    check_blob_width(handle.field_elements_per_blob())?;
+
+    Ok(handle)
+}
+
+// Blobs of the C API have a fixed size, so only setups of matching width can be used
+fn check_blob_width(field_elements_per_blob: usize) -> Result<(), Error> {
+    if field_elements_per_blob != FIELD_ELEMENTS_PER_BLOB {
+        return Err(Error::InvalidTrustedSetup(format!(
+            "C API supports blobs of {} field elements only, trusted setup has {} G1 points",
+            FIELD_ELEMENTS_PER_BLOB, field_elements_per_blob
+        )));
+    }
+
+    Ok(())
+}
+
+unsafe fn slice_from_raw<'a, T>(data: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        &[]
+    } else {
+        core::slice::from_raw_parts(data, len)
+    }
+}
+
+/// Bytes of `n` points of `point_size` bytes each. Returns `None` if `data` is null, or if their length overflows
+///
+/// # Safety
+///
+/// `data` must be null, or point to `n` points
+pub unsafe fn points_from_raw<'a>(
+    data: *const u8,
+    n: usize,
+    point_size: usize,
+) -> Option<&'a [u8]> {
+    let len = n.checked_mul(point_size)?;
+    if data.is_null() || len > isize::MAX as usize {
+        return None;
+    }
+
+    Some(slice_from_raw(data, len))
+}
+
+/// Writes pointer to a new handle to `out`, which has to be released with [`free_trusted_setup`]
+///
+/// # Safety
+///
+/// `g1_bytes` and `g2_bytes` must point to `n1` and `n2` compressed points, `out` must be valid for writes
+pub unsafe fn load_trusted_setup<B: Backend>(
+    out: *mut *mut KzgHandle<B>,
+    g1_bytes: *const u8,
+    n1: usize,
+    g2_bytes: *const u8,
+    n2: usize,
+) -> C_KZG_RET {
+    if out.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
+    let (Some(g1_bytes), Some(g2_bytes)) = (
+        points_from_raw(g1_bytes, n1, BYTES_PER_G1),
+        points_from_raw(g2_bytes, n2, BYTES_PER_G2),
+    ) else {
+        return C_KZG_RET_BADARGS;
+    };
+    let handle = handle_ckzg_error!(load_trusted_setup_handle::<B>(g1_bytes, g2_bytes));
+
+    *out = Box::into_raw(Box::new(handle));
+    C_KZG_RET_OK
+}
+
+/// Like [`load_trusted_setup`], but reads trusted setup in any format supported by the backend
+///
+/// # Safety
+///
+/// `contents` must point to `len` bytes, `out` must be valid for writes
+pub unsafe fn load_trusted_setup_contents<B: Backend>(
+    out: *mut *mut KzgHandle<B>,
+    contents: *const u8,
+    len: usize,
+) -> C_KZG_RET {
+    if out.is_null() || contents.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
+    let contents = slice_from_raw(contents, len);
+    let handle = handle_ckzg_error!(load_trusted_setup_contents_handle::<B>(contents));
+
+    *out = Box::into_raw(Box::new(handle));
+    C_KZG_RET_OK
+}
+
+/// Releases handle and everything it owns. Null is ignored
+///
+/// # Safety
+///
+/// `handle` must come from one of the load functions, and must not be used afterwards
+pub unsafe fn free_trusted_setup<B: Backend>(handle: *mut KzgHandle<B>) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Sets number of threads, which functions called with `handle` split their work between. Zero means the global
+/// thread pool.
+///
+/// # Safety
+///
+/// `handle` must be valid, and must not be used by other threads during the call
+pub unsafe fn set_num_threads<B: Backend>(
+    handle: *mut KzgHandle<B>,
+    num_threads: usize,
+) -> C_KZG_RET {
+    if handle.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
+    let thread_pool = handle_ckzg_error!(ThreadPoolConfig::with_num_threads(num_threads));
+    (*handle).settings_mut().set_thread_pool(thread_pool);
+    C_KZG_RET_OK
+}
+
+/// # Safety
+///
+/// All pointers must be valid
+pub unsafe fn blob_to_kzg_commitment<B: Backend>(
+    out: *mut KZGCommitment,
+    blob: *const Blob,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    *out = handle_ckzg_error!(DynKzg::blob_to_kzg_commitment(s, &(*blob).bytes));
+    C_KZG_RET_OK
+}
+
+/// # Safety
+///
+/// All pointers must be valid
+pub unsafe fn compute_kzg_proof<B: Backend>(
+    proof_out: *mut KZGProof,
+    y_out: *mut Bytes32,
+    blob: *const Blob,
+    z_bytes: *const Bytes32,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    let (proof, y) = handle_ckzg_error!(DynKzg::compute_kzg_proof(s, &(*blob).bytes, &*z_bytes));
+
+    *proof_out = proof;
+    *y_out = y;
+    C_KZG_RET_OK
+}
+
+/// # Safety
+///
+/// All pointers must be valid
+pub unsafe fn compute_blob_kzg_proof<B: Backend>(
+    out: *mut KZGProof,
+    blob: *const Blob,
+    commitment_bytes: *const Bytes48,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    *out = handle_ckzg_error!(DynKzg::compute_blob_kzg_proof(
+        s,
+        &(*blob).bytes,
+        &*commitment_bytes
+    ));
+    C_KZG_RET_OK
+}
+
+/// # Safety
+///
+/// All pointers must be valid
+pub unsafe fn verify_kzg_proof<B: Backend>(
+    ok: *mut bool,
+    commitment_bytes: *const Bytes48,
+    z_bytes: *const Bytes32,
+    y_bytes: *const Bytes32,
+    proof_bytes: *const Bytes48,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    *ok = handle_ckzg_error!(DynKzg::verify_kzg_proof(
+        s,
+        &*commitment_bytes,
+        &*z_bytes,
+        &*y_bytes,
+        &*proof_bytes
+    ));
+    C_KZG_RET_OK
+}
+
+/// # Safety
+///
+/// All pointers must be valid
+pub unsafe fn verify_blob_kzg_proof<B: Backend>(
+    ok: *mut bool,
+    blob: *const Blob,
+    commitment_bytes: *const Bytes48,
+    proof_bytes: *const Bytes48,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    *ok = handle_ckzg_error!(DynKzg::verify_blob_kzg_proof(
+        s,
+        &(*blob).bytes,
+        &*commitment_bytes,
+        &*proof_bytes
+    ));
+    C_KZG_RET_OK
+}
+
+/// # Safety
+///
+/// `blobs`, `commitments_bytes` and `proofs_bytes` must point to `n` elements each
+pub unsafe fn verify_blob_kzg_proof_batch<B: Backend>(
+    ok: *mut bool,
+    blobs: *const Blob,
+    commitments_bytes: *const Bytes48,
+    proofs_bytes: *const Bytes48,
+    n: usize,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    *ok = false;
+    let blobs = slice_from_raw(blobs, n)
+        .iter()
+        .map(|blob| blob.bytes.as_slice())
+        .collect::<Vec<_>>();
+    *ok = handle_ckzg_error!(DynKzg::verify_blob_kzg_proof_batch(
+        s,
+        &blobs,
+        slice_from_raw(commitments_bytes, n),
+        slice_from_raw(proofs_bytes, n)
+    ));
+    C_KZG_RET_OK
+}
+
+/// Verifies a batch of blob proofs, setting `results[i]` to whether proof `i` is valid
+///
+/// # Safety
+///
+/// `results`, `blobs`, `commitments_bytes` and `proofs_bytes` must point to `n` elements each
+pub unsafe fn verify_blob_kzg_proof_batch_per_item<B: Backend>(
+    results: *mut bool,
+    blobs: *const Blob,
+    commitments_bytes: *const Bytes48,
+    proofs_bytes: *const Bytes48,
+    n: usize,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    let blobs = handle_ckzg_error!(slice_from_raw(blobs, n)
+        .iter()
+        .map(|blob| s.bytes_to_blob(&blob.bytes))
+        .collect::<Result<Vec<_>, Error>>());
+    let commitments = handle_ckzg_error!(slice_from_raw(commitments_bytes, n)
+        .iter()
+        .map(|commitment| B::G1::from_bytes(&commitment.bytes))
+        .collect::<Result<Vec<_>, Error>>());
+    let proofs = handle_ckzg_error!(slice_from_raw(proofs_bytes, n)
+        .iter()
+        .map(|proof| B::G1::from_bytes(&proof.bytes))
+        .collect::<Result<Vec<_>, Error>>());
+
+    let failures = handle_ckzg_error!(verify_blob_kzg_proof_batch_failures_rust::<
+        B::Fr,
+        B::G1,
+        B::G2,
+        B::FFTSettings,
+        B::Poly,
+        B::KZGSettings,
+        B::G1Fp,
+        B::G1Affine,
+    >(&blobs, &commitments, &proofs, s.settings()));
+
+    if n > 0 {
+        let results = core::slice::from_raw_parts_mut(results, n);
+        results.fill(true);
+        for i in failures {
+            results[i] = false;
+        }
+    }
+    C_KZG_RET_OK
+}
+
+/// Executes the point evaluation precompile. On success, writes
+/// `POINT_EVALUATION_OUTPUT_LENGTH` bytes to `out`. Returns `C_KZG_RET_PROOF_MISMATCH` if the
+/// proof does not verify
+///
+/// # Safety
+///
+/// `input` must point to `input_len` bytes, `out` must be valid for writes of the output
+pub unsafe fn point_evaluation_precompile<B: Backend>(
+    out: *mut u8,
+    input: *const u8,
+    input_len: usize,
+    s: &KzgHandle<B>,
+) -> C_KZG_RET {
+    let output = handle_ckzg_error!(point_evaluation_precompile_rust::<
+        B::Fr,
+        B::G1,
+        B::G2,
+        B::FFTSettings,
+        B::Poly,
+        B::KZGSettings,
+        B::G1Fp,
+        B::G1Affine,
+    >(slice_from_raw(input, input_len), s.settings()));
+
+    core::ptr::copy_nonoverlapping(output.as_ptr(), out, output.len());
+    C_KZG_RET_OK
+}
+
+/// FFT settings created through the C API, used by FFT, erasure extension and recovery functions
+pub type FFTSettingsHandle<B> = <B as Backend>::FFTSettings;
+
+/// FK20 multi-proof settings created through the C API
+pub type FK20MultiSettingsHandle<B> = <B as Backend>::FK20MultiSettings;
+
+unsafe fn read_field_elements<B: Backend>(
+    data: *const Bytes32,
+    n: usize,
+) -> Result<Vec<B::Fr>, Error> {
+    slice_from_raw(data, n)
+        .iter()
+        .map(|bytes| B::Fr::from_bytes(&bytes.bytes))
+        .collect()
+}
+
+unsafe fn write_field_elements<B: Backend>(out: *mut Bytes32, values: &[B::Fr]) {
+    for (i, value) in values.iter().enumerate() {
+        *out.add(i) = Bytes32 {
+            bytes: value.to_bytes(),
+        };
+    }
+}
+
+/// Writes pointer to new FFT settings for domains of up to `2^max_scale` elements to `out`, which have to be
+/// released with [`free_fft_settings`]
+///
+/// # Safety
+///
+/// `out` must be valid for writes
+pub unsafe fn new_fft_settings<B: Backend>(
+    out: *mut *mut FFTSettingsHandle<B>,
+    max_scale: usize,
+) -> C_KZG_RET {
+    if out.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
+    let fs = handle_ckzg_error!(B::FFTSettings::new(max_scale));
+
+    *out = Box::into_raw(Box::new(fs));
+    C_KZG_RET_OK
+}
+
+/// Releases FFT settings. Null is ignored
+///
+/// # Safety
+///
+/// `fs` must come from [`new_fft_settings`], and must not be used afterwards
+pub unsafe fn free_fft_settings<B: Backend>(fs: *mut FFTSettingsHandle<B>) {
+    if !fs.is_null() {
+        drop(Box::from_raw(fs));
+    }
+}
+
+/// Writes forward, or inverse, FFT of `n` field elements to `out`
+///
+/// # Safety
+///
+/// `data` and `out` must point to `n` elements each
+pub unsafe fn fft_fr<B: Backend>(
+    out: *mut Bytes32,
+    data: *const Bytes32,
+    n: usize,
+    inverse: bool,
+    fs: &FFTSettingsHandle<B>,
+) -> C_KZG_RET {
+    let data = handle_ckzg_error!(read_field_elements::<B>(data, n));
+    let result = handle_ckzg_error!(fs.fft_fr(&data, inverse));
+
+    write_field_elements::<B>(out, &result);
+    C_KZG_RET_OK
+}
+
+/// Takes `n` even-indexed evaluations of a polynomial of degree less than `n` over a domain of `2 * n` elements,
+/// and writes `n` odd-indexed evaluations to `out`
+///
+/// # Safety
+///
+/// `evens` and `out` must point to `n` elements each
+pub unsafe fn das_fft_extension<B: Backend>(
+    out: *mut Bytes32,
+    evens: *const Bytes32,
+    n: usize,
+    fs: &FFTSettingsHandle<B>,
+) -> C_KZG_RET {
+    let evens = handle_ckzg_error!(read_field_elements::<B>(evens, n));
+    let odds = handle_ckzg_error!(fs.das_fft_extension(&evens));
+
+    write_field_elements::<B>(out, &odds);
+    C_KZG_RET_OK
+}
+
+/// Recovers all `n` evaluations of a polynomial from samples, where `present[i]` tells whether `samples[i]` is
+/// known. At least half of samples have to be known
+///
+/// # Safety
+///
+/// `samples`, `present` and `out` must point to `n` elements each
+pub unsafe fn recover_poly_from_samples<B: Backend>(
+    out: *mut Bytes32,
+    samples: *const Bytes32,
+    present: *const bool,
+    n: usize,
+    fs: &FFTSettingsHandle<B>,
+) -> C_KZG_RET {
+    let samples = handle_ckzg_error!(slice_from_raw(samples, n)
+        .iter()
+        .zip(slice_from_raw(present, n))
+        .map(|(sample, present)| match present {
+            true => B::Fr::from_bytes(&sample.bytes).map(Some),
+            false => Ok(None),
+        })
+        .collect::<Result<Vec<_>, Error>>());
+    let recovered = handle_ckzg_error!(B::Poly::recover_poly_from_samples(&samples, fs));
+
+    write_field_elements::<B>(out, recovered.get_coeffs());
+    C_KZG_RET_OK
+}
+
+/// Writes pointer to new FK20 settings to `out`, which have to be released with [`free_fk20_multi_settings`].
+/// Settings compute proofs for chunks of `chunk_len` evaluations, over a domain of `n2` elements.
+///
+/// Unlike [`load_trusted_setup`], points are powers of secret in monomial form.
+///
+/// # Safety
+///
+/// `g1_bytes` and `g2_bytes` must point to `num_g1` and `num_g2` compressed points, `out` must be valid for
+/// writes
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn new_fk20_multi_settings<B: Backend>(
+    out: *mut *mut FK20MultiSettingsHandle<B>,
+    g1_bytes: *const u8,
+    num_g1: usize,
+    g2_bytes: *const u8,
+    num_g2: usize,
+    n2: usize,
+    chunk_len: usize,
+    fs: &FFTSettingsHandle<B>,
+) -> C_KZG_RET {
+    if out.is_null() {
+        return C_KZG_RET_BADARGS;
+    }
+
+    let (Some(g1_bytes), Some(g2_bytes)) = (
+        points_from_raw(g1_bytes, num_g1, BYTES_PER_G1),
+        points_from_raw(g2_bytes, num_g2, BYTES_PER_G2),
+    ) else {
+        return C_KZG_RET_BADARGS;
+    };
+
+    let secret_g1 = handle_ckzg_error!(g1_bytes
+        .chunks(BYTES_PER_G1)
+        .map(B::G1::from_bytes)
+        .collect::<Result<Vec<_>, Error>>());
+    let secret_g2 = handle_ckzg_error!(g2_bytes
+        .chunks(BYTES_PER_G2)
+        .map(B::G2::from_bytes)
+        .collect::<Result<Vec<_>, Error>>());
+
+    let ks = handle_ckzg_error!(B::KZGSettings::new(&secret_g1, &secret_g2, num_g1, fs));
+    let fk = handle_ckzg_error!(B::FK20MultiSettings::new(&ks, n2, chunk_len));
+
+    *out = Box::into_raw(Box::new(fk));
+    C_KZG_RET_OK
+}
+
+/// Releases FK20 settings. Null is ignored
+///
+/// # Safety
+///
+/// `fk` must come from [`new_fk20_multi_settings`], and must not be used afterwards
+pub unsafe fn free_fk20_multi_settings<B: Backend>(fk: *mut FK20MultiSettingsHandle<B>) {
+    if !fk.is_null() {
+        drop(Box::from_raw(fk));
+    }
+}
+
+/// Computes FK20 multi-proofs of polynomial with `n` coefficients, writing `out_len` proofs to `out`. There is a
+/// proof for every chunk of the extended data, so `out_len` has to be `2 * n / chunk_len`.
+///
+/// # Safety
+///
+/// `coeffs` must point to `n` elements, `out` to `out_len` elements
+pub unsafe fn data_availability_optimized<B: Backend>(
+    out: *mut KZGProof,
+    out_len: usize,
+    coeffs: *const Bytes32,
+    n: usize,
+    fk: &FK20MultiSettingsHandle<B>,
+) -> C_KZG_RET {
+    let coeffs = handle_ckzg_error!(read_field_elements::<B>(coeffs, n));
+    let proofs = handle_ckzg_error!(fk.data_availability_optimized(&B::Poly::from_coeffs(&coeffs)));
+    if proofs.len() != out_len {
+        return C_KZG_RET_BADARGS;
+    }
+
+    for (i, proof) in proofs.iter().enumerate() {
+        *out.add(i) = KZGProof {
+            bytes: proof.to_bytes(),
+        };
+    }
+    C_KZG_RET_OK
+}
+
+#[cfg(feature = "std")]
+mod lock {
+    /// Lock, which is released when its guard is dropped
+    pub struct Mutex<T>(std::sync::Mutex<T>);
+
+    impl<T> Mutex<T> {
+        pub const fn new(value: T) -> Self {
+            Self(std::sync::Mutex::new(value))
+        }
+
+        pub fn lock(&self) -> std::sync::MutexGuard<'_, T> {
+            // Holders only insert, remove or replace whole values, so data stays consistent after a panic
+            self.0
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod lock {
+    use core::cell::UnsafeCell;
+    use core::ops::{Deref, DerefMut};
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    /// Spin lock, which is released when its guard is dropped, also while unwinding
+    pub struct Mutex<T> {
+        locked: AtomicBool,
+        value: UnsafeCell<T>,
+    }
+
+    // Value is only accessed through the guard, which is held by one thread at a time
+    unsafe impl<T: Send> Sync for Mutex<T> {}
+
+    pub struct MutexGuard<'a, T> {
+        mutex: &'a Mutex<T>,
+    }
+
+    impl<T> Mutex<T> {
+        pub const fn new(value: T) -> Self {
+            Self {
+                locked: AtomicBool::new(false),
+                value: UnsafeCell::new(value),
+            }
+        }
+
+        pub fn lock(&self) -> MutexGuard<'_, T> {
+            while self
+                .locked
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                core::hint::spin_loop();
+            }
+
+            MutexGuard { mutex: self }
+        }
+    }
+
+    impl<T> Deref for MutexGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            unsafe { &*self.mutex.value.get() }
+        }
+    }
+
+    impl<T> DerefMut for MutexGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            unsafe { &mut *self.mutex.value.get() }
+        }
+    }
+
+    impl<T> Drop for MutexGuard<'_, T> {
+        fn drop(&mut self) {
+            self.mutex.locked.store(false, Ordering::Release);
+        }
+    }
+}
+
+use lock::Mutex;
+
+/// Settings loaded through c-kzg-4844 compatible functions. Thread pool is kept apart from the settings, so that
+/// it can be replaced while calls are using them
+struct CKZGHandle<B: Backend> {
+    handle: KzgHandle<B>,
+    thread_pool: Mutex<ThreadPoolConfig>,
+}
+
+/// Call, which is moved onto a thread of the settings' pool. Calling thread waits for it to return, so pointers
+/// it captures stay valid for the whole call
+struct PendingCall<F>(F);
+
+// Call is finished before `ThreadPoolConfig::install` returns to the thread, which owns its captures
+unsafe impl<F> Send for PendingCall<F> {}
+
+impl<F: FnOnce() -> C_KZG_RET> PendingCall<F> {
+    fn run(self) -> C_KZG_RET {
+        (self.0)()
+    }
+}
+
+/// Handles of settings loaded through c-kzg-4844 compatible functions, keyed by address of G1 points of the
+/// settings. Points are allocated by the load functions and released by `free_trusted_setup`, so the address is
+/// unique for as long as the settings are loaded. Settings filled in by other code have no handle, and are
+/// rejected.
+///
+/// Lookups take a short lock and return a shared reference to the handle, so settings can be used from many
+/// threads while other settings are loaded or released.
+pub struct CKZGHandles<B: Backend> {
+    handles: Mutex<BTreeMap<usize, Arc<CKZGHandle<B>>>>,
+}
+
+impl<B: Backend> Default for CKZGHandles<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> CKZGHandles<B> {
+    pub const fn new() -> Self {
+        Self {
+            handles: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn insert(&self, key: usize, handle: KzgHandle<B>) {
+        let handle = Arc::new(CKZGHandle {
+            handle,
+            thread_pool: Mutex::new(ThreadPoolConfig::global()),
+        });
+        let previous = self.handles.lock().insert(key, handle);
+        drop(previous);
+    }
+
+    pub fn contains(&self, key: usize) -> bool {
+        self.handles.lock().contains_key(&key)
+    }
+
+    /// Forgets handle. It is released once calls, which still use it, return
+    pub fn remove(&self, key: usize) {
+        let handle = self.handles.lock().remove(&key);
+        drop(handle);
+    }
+
+    fn get(&self, key: usize) -> Option<Arc<CKZGHandle<B>>> {
+        self.handles.lock().get(&key).cloned()
+    }
+
+    /// Replaces thread pool of a loaded handle. Returns false, if there is no handle under `key`
+    pub fn set_thread_pool(&self, key: usize, thread_pool: ThreadPoolConfig) -> bool {
+        match self.get(key) {
+            Some(handle) => {
+                *handle.thread_pool.lock() = thread_pool;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Runs `f` with handle under `key` on its thread pool. Returns `C_KZG_RET_BADARGS`, if there is no handle
+    pub fn call(&self, key: usize, f: impl FnOnce(&KzgHandle<B>) -> C_KZG_RET) -> C_KZG_RET {
+        let Some(handle) = self.get(key) else {
+            return C_KZG_RET_BADARGS;
+        };
+
+        let thread_pool = handle.thread_pool.lock().clone();
+        let call = PendingCall(move || f(&handle.handle));
+        thread_pool.install(move || call.run())
+    }
+}
+
+/// Exports handle-based C ABI of `$backend` as `kzg_abi_version` and `kzg_v1_*` functions. Handles are opaque
+/// to C code, e.g. `typedef struct KzgHandle KzgHandle;`.
+#[macro_export]
+macro_rules! export_c_api {
+    ($backend:ty) => {
+        /// Version of the handle-based C ABI, see [`kzg::c_api::KZG_ABI_VERSION`]
+        #[no_mangle]
+        pub extern "C" fn kzg_abi_version() -> u32 {
+            $crate::c_api::KZG_ABI_VERSION
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::load_trusted_setup`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_load_trusted_setup(
+            out: *mut *mut $crate::c_api::KzgHandle<$backend>,
+            g1_bytes: *const u8,
+            n1: usize,
+            g2_bytes: *const u8,
+            n2: usize,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            $crate::c_api::load_trusted_setup(out, g1_bytes, n1, g2_bytes, n2)
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::load_trusted_setup_contents`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_load_trusted_setup_contents(
+            out: *mut *mut $crate::c_api::KzgHandle<$backend>,
+            contents: *const u8,
+            len: usize,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            $crate::c_api::load_trusted_setup_contents(out, contents, len)
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::free_trusted_setup`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_free_trusted_setup(
+            handle: *mut $crate::c_api::KzgHandle<$backend>,
+        ) {
+            $crate::c_api::free_trusted_setup(handle)
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::set_num_threads`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_set_num_threads(
+            handle: *mut $crate::c_api::KzgHandle<$backend>,
+            num_threads: usize,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            $crate::c_api::set_num_threads(handle, num_threads)
+        }
+
+        /// Returns zero for null handle
+        ///
+        /// # Safety
+        ///
+        /// `handle` must be null or valid
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_field_elements_per_blob(
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> usize {
+            handle
+                .as_ref()
+                .map_or(0, |handle| handle.field_elements_per_blob())
+        }
+
+        /// # Safety
+        ///
+        /// All pointers must be valid
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_blob_to_kzg_commitment(
+            out: *mut $crate::eip_4844::KZGCommitment,
+            blob: *const $crate::eip_4844::Blob,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => $crate::c_api::blob_to_kzg_commitment(out, blob, s),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// All pointers must be valid
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_compute_kzg_proof(
+            proof_out: *mut $crate::eip_4844::KZGProof,
+            y_out: *mut $crate::eip_4844::Bytes32,
+            blob: *const $crate::eip_4844::Blob,
+            z_bytes: *const $crate::eip_4844::Bytes32,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => $crate::c_api::compute_kzg_proof(proof_out, y_out, blob, z_bytes, s),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// All pointers must be valid
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_compute_blob_kzg_proof(
+            out: *mut $crate::eip_4844::KZGProof,
+            blob: *const $crate::eip_4844::Blob,
+            commitment_bytes: *const $crate::eip_4844::Bytes48,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => $crate::c_api::compute_blob_kzg_proof(out, blob, commitment_bytes, s),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// All pointers must be valid
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_verify_kzg_proof(
+            ok: *mut bool,
+            commitment_bytes: *const $crate::eip_4844::Bytes48,
+            z_bytes: *const $crate::eip_4844::Bytes32,
+            y_bytes: *const $crate::eip_4844::Bytes32,
+            proof_bytes: *const $crate::eip_4844::Bytes48,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => $crate::c_api::verify_kzg_proof(
+                    ok,
+                    commitment_bytes,
+                    z_bytes,
+                    y_bytes,
+                    proof_bytes,
+                    s,
+                ),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// All pointers must be valid
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_verify_blob_kzg_proof(
+            ok: *mut bool,
+            blob: *const $crate::eip_4844::Blob,
+            commitment_bytes: *const $crate::eip_4844::Bytes48,
+            proof_bytes: *const $crate::eip_4844::Bytes48,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => {
+                    $crate::c_api::verify_blob_kzg_proof(ok, blob, commitment_bytes, proof_bytes, s)
+                }
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::verify_blob_kzg_proof_batch`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_verify_blob_kzg_proof_batch(
+            ok: *mut bool,
+            blobs: *const $crate::eip_4844::Blob,
+            commitments_bytes: *const $crate::eip_4844::Bytes48,
+            proofs_bytes: *const $crate::eip_4844::Bytes48,
+            n: usize,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => $crate::c_api::verify_blob_kzg_proof_batch(
+                    ok,
+                    blobs,
+                    commitments_bytes,
+                    proofs_bytes,
+                    n,
+                    s,
+                ),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::verify_blob_kzg_proof_batch_per_item`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_verify_blob_kzg_proof_batch_per_item(
+            results: *mut bool,
+            blobs: *const $crate::eip_4844::Blob,
+            commitments_bytes: *const $crate::eip_4844::Bytes48,
+            proofs_bytes: *const $crate::eip_4844::Bytes48,
+            n: usize,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => $crate::c_api::verify_blob_kzg_proof_batch_per_item(
+                    results,
+                    blobs,
+                    commitments_bytes,
+                    proofs_bytes,
+                    n,
+                    s,
+                ),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::point_evaluation_precompile`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_point_evaluation_precompile(
+            out: *mut u8,
+            input: *const u8,
+            input_len: usize,
+            handle: *const $crate::c_api::KzgHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match handle.as_ref() {
+                Some(s) => $crate::c_api::point_evaluation_precompile(out, input, input_len, s),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::new_fft_settings`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_new_fft_settings(
+            out: *mut *mut $crate::c_api::FFTSettingsHandle<$backend>,
+            max_scale: usize,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            $crate::c_api::new_fft_settings::<$backend>(out, max_scale)
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::free_fft_settings`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_free_fft_settings(
+            fs: *mut $crate::c_api::FFTSettingsHandle<$backend>,
+        ) {
+            $crate::c_api::free_fft_settings::<$backend>(fs)
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::fft_fr`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_fft_fr(
+            out: *mut $crate::eip_4844::Bytes32,
+            data: *const $crate::eip_4844::Bytes32,
+            n: usize,
+            inverse: bool,
+            fs: *const $crate::c_api::FFTSettingsHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match fs.as_ref() {
+                Some(fs) => $crate::c_api::fft_fr::<$backend>(out, data, n, inverse, fs),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::das_fft_extension`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_das_fft_extension(
+            out: *mut $crate::eip_4844::Bytes32,
+            evens: *const $crate::eip_4844::Bytes32,
+            n: usize,
+            fs: *const $crate::c_api::FFTSettingsHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match fs.as_ref() {
+                Some(fs) => $crate::c_api::das_fft_extension::<$backend>(out, evens, n, fs),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::recover_poly_from_samples`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_recover_poly_from_samples(
+            out: *mut $crate::eip_4844::Bytes32,
+            samples: *const $crate::eip_4844::Bytes32,
+            present: *const bool,
+            n: usize,
+            fs: *const $crate::c_api::FFTSettingsHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match fs.as_ref() {
+                Some(fs) => $crate::c_api::recover_poly_from_samples::<$backend>(
+                    out, samples, present, n, fs,
+                ),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::new_fk20_multi_settings`]
+        #[no_mangle]
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe extern "C" fn kzg_v1_new_fk20_multi_settings(
+            out: *mut *mut $crate::c_api::FK20MultiSettingsHandle<$backend>,
+            g1_bytes: *const u8,
+            num_g1: usize,
+            g2_bytes: *const u8,
+            num_g2: usize,
+            n2: usize,
+            chunk_len: usize,
+            fs: *const $crate::c_api::FFTSettingsHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match fs.as_ref() {
+                Some(fs) => $crate::c_api::new_fk20_multi_settings::<$backend>(
+                    out, g1_bytes, num_g1, g2_bytes, num_g2, n2, chunk_len, fs,
+                ),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::free_fk20_multi_settings`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_free_fk20_multi_settings(
+            fk: *mut $crate::c_api::FK20MultiSettingsHandle<$backend>,
+        ) {
+            $crate::c_api::free_fk20_multi_settings::<$backend>(fk)
+        }
+
+        /// # Safety
+        ///
+        /// See [`kzg::c_api::data_availability_optimized`]
+        #[no_mangle]
+        pub unsafe extern "C" fn kzg_v1_data_availability_optimized(
+            out: *mut $crate::eip_4844::KZGProof,
+            out_len: usize,
+            coeffs: *const $crate::eip_4844::Bytes32,
+            n: usize,
+            fk: *const $crate::c_api::FK20MultiSettingsHandle<$backend>,
+        ) -> $crate::eip_4844::C_KZG_RET {
+            match fk.as_ref() {
+                Some(fk) => $crate::c_api::data_availability_optimized::<$backend>(
+                    out, out_len, coeffs, n, fk,
+                ),
+                None => $crate::eip_4844::C_KZG_RET_BADARGS,
+            }
+        }
+    };
+}
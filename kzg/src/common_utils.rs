@@ -3,12 +3,14 @@ extern crate alloc;
 use alloc::string::String;
 use core::mem;
 
-pub fn reverse_bit_order<T>(vals: &mut [T]) -> Result<(), String>
+use crate::Error;
+
+pub fn reverse_bit_order<T>(vals: &mut [T]) -> Result<(), Error>
 where
     T: Clone,
 {
     if vals.is_empty() {
-        return Err(String::from("Values can not be empty"));
+        return Err(Error::BadArgs(String::from("Values can not be empty")));
     }
 
     // required for tests
@@ -17,7 +19,9 @@ where
     }
 
     if !vals.len().is_power_of_two() {
-        return Err(String::from("Values length has to be a power of 2"));
+        return Err(Error::BadArgs(String::from(
+            "Values length has to be a power of 2",
+        )));
     }
 
     let unused_bit_len = vals.len().leading_zeros() + 1;
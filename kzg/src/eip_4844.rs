@@ -1,34 +1,34 @@
 #![allow(non_camel_case_types)]
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
-use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
 
+#[cfg(feature = "blst")]
 pub use blst::{blst_fr, blst_p1, blst_p2};
 use core::ffi::c_uint;
-use core::hash::Hash;
-use core::hash::Hasher;
+use core::marker::PhantomData;
 use sha2::{Digest, Sha256};
-use siphasher::sip::SipHasher;
 
 use crate::common_utils::reverse_bit_order;
-use crate::msm::precompute::PrecomputationTable;
 use crate::G1Affine;
 use crate::G1Fp;
 use crate::G1GetFp;
 use crate::G1LinComb;
-use crate::{FFTSettings, Fr, G1Mul, KZGSettings, PairingVerify, Poly, G1, G2};
+use crate::{
+    Error, FFTFr, FFTSettings, Fr, G1Mul, KZGSettings, PairingVerify, Poly, FFTG1, G1, G2,
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 ////////////////////////////// Constant values for EIP-4844 //////////////////////////////
 
+/// Blob width used on mainnet. Other widths are supported by loading a trusted setup
+/// with a different number of G1 points.
 pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
 
 pub const BYTES_PER_G1: usize = 48;
@@ -40,10 +40,6 @@ pub const BYTES_PER_COMMITMENT: usize = 48;
 
 pub const TRUSTED_SETUP_PATH: &str = "src/trusted_setup.txt";
 
-// Currently, we only support fixed amount of G1 and G2 points contained in trusted setups.
-// Issue arises when a binding using the C API loads different G1 point quantities each time.
-pub static mut TRUSTED_SETUP_NUM_G1_POINTS: usize = 0;
-
 pub const TRUSTED_SETUP_NUM_G2_POINTS: usize = 65;
 
 pub const CHALLENGE_INPUT_SIZE: usize =
@@ -57,6 +53,21 @@ pub const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: [u8; 16] = [
     82, 67, 75, 90, 71, 66, 65, 84, 67, 72, 95, 95, 95, 86, 49, 95,
 ]; // "RCKZGBATCH___V1_"
 
+/// Order of the scalar field, big-endian
+pub const BLS_MODULUS: [u8; BYTES_PER_FIELD_ELEMENT] = [
+    0x73, 0xED, 0xA7, 0x53, 0x29, 0x9D, 0x7D, 0x48, 0x33, 0x39, 0xD8, 0x08, 0x09, 0xA1, 0xD8, 0x05,
+    0x53, 0xBD, 0xA4, 0x02, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01,
+];
+
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+pub const BYTES_PER_VERSIONED_HASH: usize = 32;
+
+/// Input of the point evaluation precompile: versioned hash, z, y, commitment and proof
+pub const POINT_EVALUATION_INPUT_LENGTH: usize =
+    BYTES_PER_VERSIONED_HASH + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_COMMITMENT + BYTES_PER_PROOF;
+/// Output of the point evaluation precompile: blob width and BLS modulus as 32-byte integers
+pub const POINT_EVALUATION_OUTPUT_LENGTH: usize = 64;
+
 ////////////////////////////// C API for EIP-4844 //////////////////////////////
 
 pub type C_KZG_RET = c_uint;
@@ -65,6 +76,20 @@ pub const C_KZG_RET_OK: C_KZG_RET = 0;
 pub const C_KZG_RET_BADARGS: C_KZG_RET = 1;
 pub const C_KZG_RET_ERROR: C_KZG_RET = 2;
 pub const C_KZG_RET_MALLOC: C_KZG_RET = 3;
+/// Inputs are well-formed, but the proof does not verify. Only returned by the point evaluation
+/// precompile, other verification functions report this through their boolean output
+pub const C_KZG_RET_PROOF_MISMATCH: C_KZG_RET = 4;
+
+impl Error {
+    /// Return code reported through the C API for this error
+    pub fn c_kzg_ret(&self) -> C_KZG_RET {
+        match self {
+            Error::Internal(_) | Error::ThreadPool(_) => C_KZG_RET_ERROR,
+            Error::ProofMismatch => C_KZG_RET_PROOF_MISMATCH,
+            _ => C_KZG_RET_BADARGS,
+        }
+    }
+}
 
 #[repr(C)]
 pub struct Bytes32 {
@@ -96,6 +121,9 @@ pub struct KZGProof {
     pub bytes: [u8; BYTES_PER_PROOF],
 }
 
+/// Settings in the layout of c-kzg-4844. When loaded by a backend, points are a copy of settings owned by a
+/// handle, see [`CKZGHandles`](crate::c_api::CKZGHandles).
+#[cfg(feature = "blst")]
 #[repr(C)]
 pub struct CKZGSettings {
     pub max_width: u64,
@@ -104,114 +132,52 @@ pub struct CKZGSettings {
     pub g2_values: *mut blst_p2,
 }
 
-pub struct PrecomputationTableManager<TFr, TG1, TG1Fp, TG1Affine>
-where
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
-    TG1Fp: G1Fp,
-    TG1Affine: G1Affine<TG1, TG1Fp>,
-{
-    tables: BTreeMap<u64, Arc<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>>,
-}
-
-impl<TFr, TG1, TG1Fp, TG1Affine> Default for PrecomputationTableManager<TFr, TG1, TG1Fp, TG1Affine>
-where
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
-    TG1Fp: G1Fp,
-    TG1Affine: G1Affine<TG1, TG1Fp>,
-{
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl<TFr, TG1, TG1Fp, TG1Affine> PrecomputationTableManager<TFr, TG1, TG1Fp, TG1Affine>
-where
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
-    TG1Fp: G1Fp,
-    TG1Affine: G1Affine<TG1, TG1Fp>,
-{
-    pub const fn new() -> Self {
-        Self {
-            tables: BTreeMap::new(),
-        }
-    }
-
-    pub fn save_precomputation(
-        &mut self,
-        precomputation: Option<Arc<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>>,
-        c_settings: &CKZGSettings,
-    ) {
-        if let Some(precomputation) = precomputation {
-            self.tables
-                .insert(Self::get_key(c_settings), precomputation);
-        }
-    }
-
-    pub fn remove_precomputation(&mut self, c_settings: &CKZGSettings) {
-        self.tables.remove(&Self::get_key(c_settings));
-    }
-
-    pub fn get_precomputation(
-        &self,
-        c_settings: &CKZGSettings,
-    ) -> Option<Arc<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>> {
-        self.tables.get(&Self::get_key(c_settings)).cloned()
-    }
-
-    fn get_key(settings: &CKZGSettings) -> u64 {
-        let mut hasher = SipHasher::new();
-
-        settings.g1_values.hash(&mut hasher);
-        hasher.finish()
-    }
-}
-
 ////////////////////////////// Utility functions for EIP-4844 //////////////////////////////
 
-pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>), String> {
+pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>), Error> {
     let mut offset = 0;
 
     const TRUSTED_SETUP_ERROR: &str = "Incorrect trusted setup format";
 
     #[inline(always)]
-    fn scan_number(offset: &mut usize, contents: &str) -> Result<usize, String> {
+    fn scan_number(offset: &mut usize, contents: &str) -> Result<usize, Error> {
         *offset += contents[(*offset)..]
             .find(|c: char| !c.is_whitespace())
-            .ok_or_else(|| String::from(TRUSTED_SETUP_ERROR))?;
+            .ok_or_else(|| Error::InvalidTrustedSetup(String::from(TRUSTED_SETUP_ERROR)))?;
         let start = *offset;
         *offset += contents[(*offset)..]
             .find(|c: char| !c.is_ascii_digit())
-            .ok_or_else(|| String::from(TRUSTED_SETUP_ERROR))?;
+            .ok_or_else(|| Error::InvalidTrustedSetup(String::from(TRUSTED_SETUP_ERROR)))?;
         let end = *offset;
         contents[start..end]
             .parse::<usize>()
-            .map_err(|_| String::from(TRUSTED_SETUP_ERROR))
+            .map_err(|_| Error::InvalidTrustedSetup(String::from(TRUSTED_SETUP_ERROR)))
     }
 
     let g1_point_count = scan_number(&mut offset, contents)?;
 
-    // FIXME: must be TRUSTED_SETUP_NUM_G1_POINTS
-    if g1_point_count != FIELD_ELEMENTS_PER_BLOB {
-        return Err(String::from(TRUSTED_SETUP_ERROR));
+    if !is_valid_blob_width(g1_point_count) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            TRUSTED_SETUP_ERROR,
+        )));
     }
 
     let g2_point_count = scan_number(&mut offset, contents)?;
 
     if g2_point_count != TRUSTED_SETUP_NUM_G2_POINTS {
-        return Err(String::from(TRUSTED_SETUP_ERROR));
+        return Err(Error::InvalidTrustedSetup(String::from(
+            TRUSTED_SETUP_ERROR,
+        )));
     }
 
     let mut g1_bytes = vec![0u8; g1_point_count * BYTES_PER_G1];
     let mut g2_bytes = vec![0u8; g2_point_count * BYTES_PER_G2];
 
     #[inline(always)]
-    fn scan_hex_byte(offset: &mut usize, contents: &str) -> Result<u8, String> {
+    fn scan_hex_byte(offset: &mut usize, contents: &str) -> Result<u8, Error> {
         *offset += contents[(*offset)..]
             .find(|c: char| !c.is_whitespace())
-            .ok_or_else(|| String::from(TRUSTED_SETUP_ERROR))?;
+            .ok_or_else(|| Error::InvalidTrustedSetup(String::from(TRUSTED_SETUP_ERROR)))?;
         let start = *offset;
 
         let end = if contents
@@ -231,7 +197,8 @@ pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>), S
             *offset
         };
 
-        u8::from_str_radix(&contents[start..end], 16).map_err(|_| String::from(TRUSTED_SETUP_ERROR))
+        u8::from_str_radix(&contents[start..end], 16)
+            .map_err(|_| Error::InvalidTrustedSetup(String::from(TRUSTED_SETUP_ERROR)))
     }
 
     for byte in &mut g1_bytes {
@@ -245,6 +212,39 @@ pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>), S
     Ok((g1_bytes, g2_bytes))
 }
 
+/// Transform G1 points of the trusted setup from bit-reversed Lagrange form into monomial form, i.e. `[s^i]G1`.
+/// Fails with [`Error::InvalidTrustedSetup`] if the points can't be transformed, e.g. because of their count
+pub fn g1_lagrange_brp_to_monomial<
+    TFr: Fr,
+    TG1: G1,
+    TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>,
+>(
+    g1_lagrange_brp: &[TG1],
+    fs: &TFFTSettings,
+) -> Result<Vec<TG1>, Error> {
+    let mut g1_lagrange = g1_lagrange_brp.to_vec();
+    reverse_bit_order(&mut g1_lagrange)
+        .and_then(|_| fs.fft_g1(&g1_lagrange, false))
+        .map_err(|err| {
+            Error::InvalidTrustedSetup(format!("Failed to derive monomial G1 points: {}", err))
+        })
+}
+
+/// Interpolate polynomial coefficients from its evaluations over roots of unity, in bit-reversed order
+pub fn eval_form_to_coeff_form<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    evals: &[TFr],
+    fs: &TFFTSettings,
+) -> Result<Vec<TFr>, Error> {
+    let mut evals = evals.to_vec();
+    reverse_bit_order(&mut evals)?;
+    fs.fft_fr(&evals, true)
+}
+
+/// Blob widths must be a power of two, so that the evaluation domain is a multiplicative subgroup
+pub fn is_valid_blob_width(field_elements_per_blob: usize) -> bool {
+    field_elements_per_blob > 1 && field_elements_per_blob.is_power_of_two()
+}
+
 pub fn bytes_of_uint64(out: &mut [u8], mut n: u64) {
     for byte in out.iter_mut().rev().take(8) {
         *byte = (n & 0xff) as u8;
@@ -283,13 +283,20 @@ fn poly_to_kzg_commitment<
 >(
     p: &TPoly,
     s: &TKZGSettings,
-) -> TG1 {
-    TG1::g1_lincomb(
+) -> Result<TG1, Error> {
+    let field_elements_per_blob = s.get_field_elements_per_blob();
+    if p.len() != field_elements_per_blob {
+        return Err(Error::InvalidLength(String::from(
+            "Blob length must match the trusted setup",
+        )));
+    }
+
+    Ok(TG1::g1_lincomb(
         s.get_g1_secret(),
         p.get_coeffs(),
-        FIELD_ELEMENTS_PER_BLOB,
+        field_elements_per_blob,
         s.get_precomputation(),
-    )
+    ))
 }
 
 pub fn blob_to_kzg_commitment_rust<
@@ -298,16 +305,160 @@ pub fn blob_to_kzg_commitment_rust<
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
-    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
     blob: &[TFr],
     settings: &TKZGSettings,
-) -> Result<TG1, String> {
-    let polynomial = blob_to_polynomial(blob)?;
+) -> Result<TG1, Error> {
+    settings.get_thread_pool().install(|| {
+        let polynomial = blob_to_polynomial(blob)?;
 
-    Ok(poly_to_kzg_commitment(&polynomial, settings))
+        poly_to_kzg_commitment(&polynomial, settings)
+    })
+}
+
+/// Computes a blob commitment incrementally, while the blob is being assembled.
+///
+/// Each pushed chunk of field elements is committed right away against the matching range of
+/// Lagrange G1 points, and the partial commitments are summed up. Elements that were never pushed
+/// are treated as zero, so finalizing a partially filled blob gives the commitment to the
+/// zero-padded blob.
+pub struct BlobCommitter<'a, TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    settings: &'a TKZGSettings,
+    commitment: TG1,
+    len: usize,
+    pending: Vec<u8>,
+    _marker: PhantomData<(TFr, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine)>,
+}
+
+impl<'a, TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+    BlobCommitter<'a, TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    pub fn new(settings: &'a TKZGSettings) -> Self {
+        Self {
+            settings,
+            commitment: TG1::identity(),
+            len: 0,
+            pending: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of field elements pushed so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0 && self.pending.is_empty()
+    }
+
+    /// Appends field elements to the blob
+    pub fn push_field_elements(&mut self, elements: &[TFr]) -> Result<(), Error> {
+        if !self.pending.is_empty() {
+            return Err(Error::BadArgs(String::from(
+                "Cannot push field elements while a partial field element is pending",
+            )));
+        }
+
+        self.commit_chunk(elements)
+    }
+
+    /// Appends serialized field elements to the blob. Chunks do not need to be aligned to field
+    /// element boundaries: trailing bytes are kept until the rest of the field element arrives.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let mut elements =
+            Vec::with_capacity((self.pending.len() + bytes.len()) / BYTES_PER_FIELD_ELEMENT);
+
+        let missing = ((BYTES_PER_FIELD_ELEMENT - self.pending.len()) % BYTES_PER_FIELD_ELEMENT)
+            .min(bytes.len());
+        let (head, rest) = bytes.split_at(missing);
+        if !self.pending.is_empty() {
+            if self.pending.len() + head.len() < BYTES_PER_FIELD_ELEMENT {
+                self.pending.extend_from_slice(head);
+                return Ok(());
+            }
+
+            let mut element = self.pending.clone();
+            element.extend_from_slice(head);
+            elements.push(TFr::from_bytes(&element)?);
+        }
+
+        let mut chunks = rest.chunks_exact(BYTES_PER_FIELD_ELEMENT);
+        for chunk in chunks.by_ref() {
+            elements.push(TFr::from_bytes(chunk)?);
+        }
+
+        self.commit_chunk(&elements)?;
+        self.pending.clear();
+        self.pending.extend_from_slice(chunks.remainder());
+
+        Ok(())
+    }
+
+    /// Returns the commitment to the blob
+    pub fn finalize(self) -> Result<TG1, Error> {
+        if !self.pending.is_empty() {
+            return Err(Error::InvalidLength(format!(
+                "Blob ends with a partial field element of {} bytes",
+                self.pending.len()
+            )));
+        }
+
+        Ok(self.commitment)
+    }
+
+    fn commit_chunk(&mut self, elements: &[TFr]) -> Result<(), Error> {
+        if elements.is_empty() {
+            return Ok(());
+        }
+
+        let points = self.settings.get_g1_secret();
+        let end = self.len + elements.len();
+        if end > points.len() {
+            return Err(Error::InvalidLength(String::from(
+                "Blob length must match the trusted setup",
+            )));
+        }
+
+        // Precomputation table only covers all points at once
+        let precomputation = if elements.len() == points.len() {
+            self.settings.get_precomputation()
+        } else {
+            None
+        };
+        let chunk_commitment = TG1::g1_lincomb(
+            &points[self.len..end],
+            elements,
+            elements.len(),
+            precomputation,
+        );
+        self.commitment = self.commitment.add_or_dbl(&chunk_commitment);
+        self.len = end;
+
+        Ok(())
+    }
 }
 
 pub fn compute_powers<TFr: Fr>(base: &TFr, num_powers: usize) -> Vec<TFr> {
@@ -327,7 +478,8 @@ fn compute_r_powers<TG1: G1, TFr: Fr>(
     zs_fr: &[TFr],
     ys_fr: &[TFr],
     proofs_g1: &[TG1],
-) -> Result<Vec<TFr>, String> {
+    field_elements_per_blob: usize,
+) -> Result<Vec<TFr>, Error> {
     let n = commitments_g1.len();
     let input_size =
         32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
@@ -336,7 +488,7 @@ fn compute_r_powers<TG1: G1, TFr: Fr>(
 
     // Copy domain separator
     bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
+    bytes_of_uint64(&mut bytes[16..24], field_elements_per_blob as u64);
     bytes_of_uint64(&mut bytes[24..32], n as u64);
     let mut offset = 32;
 
@@ -364,7 +516,9 @@ fn compute_r_powers<TG1: G1, TFr: Fr>(
 
     // Make sure we wrote the entire buffer
     if offset != input_size {
-        return Err(String::from("Error while copying commitments"));
+        return Err(Error::Internal(String::from(
+            "Error while copying commitments",
+        )));
     }
 
     // Now let's create the challenge!
@@ -389,13 +543,19 @@ fn verify_kzg_proof_batch<
     ys_fr: &[TFr],
     proofs_g1: &[TG1],
     ts: &TKZGSettings,
-) -> Result<bool, String> {
+) -> Result<bool, Error> {
     let n = commitments_g1.len();
     let mut c_minus_y: Vec<TG1> = Vec::with_capacity(n);
     let mut r_times_z: Vec<TFr> = Vec::with_capacity(n);
 
     // Compute the random lincomb challenges
-    let r_powers = compute_r_powers(commitments_g1, zs_fr, ys_fr, proofs_g1)?;
+    let r_powers = compute_r_powers(
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        ts.get_field_elements_per_blob(),
+    )?;
 
     // Compute \sum r^i * Proof_i
     let proof_lincomb = TG1::g1_lincomb(proofs_g1, &r_powers, n, None);
@@ -418,12 +578,7 @@ fn verify_kzg_proof_batch<
     let rhs_g1 = c_minus_y_lincomb.add_or_dbl(&proof_z_lincomb);
 
     // Do the pairing check!
-    Ok(TG1::verify(
-        &proof_lincomb,
-        &ts.get_g2_secret()[1],
-        &rhs_g1,
-        &TG2::generator(),
-    ))
+    Ok(ts.verify_pairing_with_g2_secret(&proof_lincomb, &rhs_g1))
 }
 
 pub fn compute_kzg_proof_rust<
@@ -432,81 +587,84 @@ pub fn compute_kzg_proof_rust<
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
-    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
     blob: &[TFr],
     z: &TFr,
     s: &TKZGSettings,
-) -> Result<(TG1, TFr), String> {
-    let polynomial = blob_to_polynomial(blob)?;
-    let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, s)?;
-
-    let mut tmp: TFr;
+) -> Result<(TG1, TFr), Error> {
+    s.get_thread_pool().install(|| {
+        let polynomial = blob_to_polynomial(blob)?;
+        let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, s)?;
 
-    let mut m: usize = 0;
-    let mut q: TPoly = TPoly::new(FIELD_ELEMENTS_PER_BLOB);
+        let field_elements_per_blob = s.get_field_elements_per_blob();
+        let mut tmp: TFr;
 
-    let mut inverses_in: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
+        let mut m: usize = 0;
+        let mut q: TPoly = TPoly::new(field_elements_per_blob);
 
-    let roots_of_unity = s.get_fft_settings().get_roots_of_unity();
-    let poly_coeffs = polynomial.get_coeffs();
-
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        if z.equals(&roots_of_unity[i]) {
-            // We are asked to compute a KZG proof inside the domain
-            m = i + 1;
-            inverses_in[i] = TFr::one();
-            continue;
-        }
-        // (p_i - y) / (ω_i - z)
-        q.set_coeff_at(i, &poly_coeffs[i].sub(&y));
-        inverses_in[i] = roots_of_unity[i].sub(z);
-    }
+        let mut inverses_in: Vec<TFr> = vec![TFr::default(); field_elements_per_blob];
+        let mut inverses: Vec<TFr> = vec![TFr::default(); field_elements_per_blob];
 
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB)?;
-
-    for (i, inverse) in inverses.iter().enumerate().take(FIELD_ELEMENTS_PER_BLOB) {
-        q.set_coeff_at(i, &q.get_coeff_at(i).mul(inverse));
-    }
+        let roots_of_unity = s.get_fft_settings().get_roots_of_unity();
+        let poly_coeffs = polynomial.get_coeffs();
 
-    if m != 0 {
-        // ω_{m-1} == z
-        m -= 1;
-        q.set_coeff_at(m, &TFr::zero());
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
+        for i in 0..field_elements_per_blob {
+            if z.equals(&roots_of_unity[i]) {
+                // We are asked to compute a KZG proof inside the domain
+                m = i + 1;
+                inverses_in[i] = TFr::one();
                 continue;
             }
-            // Build denominator: z * (z - ω_i)
-            tmp = z.sub(&roots_of_unity[i]);
-            inverses_in[i] = tmp.mul(z);
+            // (p_i - y) / (ω_i - z)
+            q.set_coeff_at(i, &poly_coeffs[i].sub(&y));
+            inverses_in[i] = roots_of_unity[i].sub(z);
         }
 
-        fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB)?;
+        fr_batch_inv(&mut inverses, &inverses_in, field_elements_per_blob)?;
 
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
-                continue;
+        for (i, inverse) in inverses.iter().enumerate().take(field_elements_per_blob) {
+            q.set_coeff_at(i, &q.get_coeff_at(i).mul(inverse));
+        }
+
+        if m != 0 {
+            // ω_{m-1} == z
+            m -= 1;
+            q.set_coeff_at(m, &TFr::zero());
+            for i in 0..field_elements_per_blob {
+                if i == m {
+                    continue;
+                }
+                // Build denominator: z * (z - ω_i)
+                tmp = z.sub(&roots_of_unity[i]);
+                inverses_in[i] = tmp.mul(z);
+            }
+
+            fr_batch_inv(&mut inverses, &inverses_in, field_elements_per_blob)?;
+
+            for i in 0..field_elements_per_blob {
+                if i == m {
+                    continue;
+                }
+                // Build numerator: ω_i * (p_i - y)
+                tmp = poly_coeffs[i].sub(&y);
+                tmp = tmp.mul(&roots_of_unity[i]);
+                // Do the division: (p_i - y) * ω_i / (z * (z - ω_i))
+                tmp = tmp.mul(&inverses[i]);
+                q.set_coeff_at(m, &q.get_coeff_at(m).add(&tmp))
             }
-            // Build numerator: ω_i * (p_i - y)
-            tmp = poly_coeffs[i].sub(&y);
-            tmp = tmp.mul(&roots_of_unity[i]);
-            // Do the division: (p_i - y) * ω_i / (z * (z - ω_i))
-            tmp = tmp.mul(&inverses[i]);
-            q.set_coeff_at(m, &q.get_coeff_at(m).add(&tmp))
         }
-    }
 
-    let proof = TG1::g1_lincomb(
-        s.get_g1_secret(),
-        q.get_coeffs(),
-        FIELD_ELEMENTS_PER_BLOB,
-        s.get_precomputation(),
-    );
-    Ok((proof, y))
+        let proof = TG1::g1_lincomb(
+            s.get_g1_secret(),
+            q.get_coeffs(),
+            field_elements_per_blob,
+            s.get_precomputation(),
+        );
+        Ok((proof, y))
+    })
 }
 
 pub fn compute_blob_kzg_proof_rust<
@@ -515,21 +673,23 @@ pub fn compute_blob_kzg_proof_rust<
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
-    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
     blob: &[TFr],
     commitment: &TG1,
     ts: &TKZGSettings,
-) -> Result<TG1, String> {
-    if !commitment.is_inf() && !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
+) -> Result<TG1, Error> {
+    ts.get_thread_pool().install(|| {
+        if !commitment.is_inf() && !commitment.is_valid() {
+            return Err(Error::InvalidCommitment);
+        }
 
-    let evaluation_challenge_fr = compute_challenge(blob, commitment);
-    let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts)?;
-    Ok(proof)
+        let evaluation_challenge_fr = compute_challenge(blob, commitment);
+        let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts)?;
+        Ok(proof)
+    })
 }
 
 pub fn verify_kzg_proof_rust<
@@ -538,7 +698,7 @@ pub fn verify_kzg_proof_rust<
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
-    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
@@ -547,15 +707,74 @@ pub fn verify_kzg_proof_rust<
     y: &TFr,
     proof: &TG1,
     s: &TKZGSettings,
-) -> Result<bool, String> {
-    if !commitment.is_inf() && !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
+) -> Result<bool, Error> {
+    s.get_thread_pool().install(|| {
+        if !commitment.is_inf() && !commitment.is_valid() {
+            return Err(Error::InvalidCommitment);
+        }
+        if !proof.is_inf() && !proof.is_valid() {
+            return Err(Error::InvalidProof);
+        }
+
+        s.check_proof_single(commitment, proof, z, y)
+    })
+}
+
+/// Versioned hash of a commitment, as used in blob transactions: sha256 of the commitment with
+/// the first byte replaced by [`VERSIONED_HASH_VERSION_KZG`]
+pub fn kzg_to_versioned_hash_rust(
+    commitment_bytes: &[u8; BYTES_PER_COMMITMENT],
+) -> [u8; BYTES_PER_VERSIONED_HASH] {
+    let mut versioned_hash = hash(commitment_bytes);
+    versioned_hash[0] = VERSIONED_HASH_VERSION_KZG;
+    versioned_hash
+}
+
+/// Executes the point evaluation precompile. Returns the precompile output if the proof is
+/// valid, and [`Error::ProofMismatch`] if it does not verify
+pub fn point_evaluation_precompile_rust<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    input: &[u8],
+    s: &TKZGSettings,
+) -> Result<[u8; POINT_EVALUATION_OUTPUT_LENGTH], Error> {
+    if input.len() != POINT_EVALUATION_INPUT_LENGTH {
+        return Err(Error::InvalidLength(format!(
+            "Point evaluation input must be {} bytes, got {}",
+            POINT_EVALUATION_INPUT_LENGTH,
+            input.len()
+        )));
     }
-    if !proof.is_inf() && !proof.is_valid() {
-        return Err("Invalid proof".to_string());
+
+    let (versioned_hash, rest) = input.split_at(BYTES_PER_VERSIONED_HASH);
+    let (z_bytes, rest) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+    let (y_bytes, rest) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+    let (commitment_bytes, proof_bytes) = rest.split_at(BYTES_PER_COMMITMENT);
+
+    if kzg_to_versioned_hash_rust(commitment_bytes.try_into().unwrap()) != versioned_hash {
+        return Err(Error::VersionedHashMismatch);
     }
 
-    s.check_proof_single(commitment, proof, z, y)
+    let z = TFr::from_bytes(z_bytes)?;
+    let y = TFr::from_bytes(y_bytes)?;
+    let commitment = TG1::from_bytes(commitment_bytes).map_err(|_| Error::InvalidCommitment)?;
+    let proof = TG1::from_bytes(proof_bytes).map_err(|_| Error::InvalidProof)?;
+
+    if !verify_kzg_proof_rust(&commitment, &z, &y, &proof, s)? {
+        return Err(Error::ProofMismatch);
+    }
+
+    let mut output = [0u8; POINT_EVALUATION_OUTPUT_LENGTH];
+    bytes_of_uint64(&mut output[..32], s.get_field_elements_per_blob() as u64);
+    output[32..].copy_from_slice(&BLS_MODULUS);
+    Ok(output)
 }
 
 pub fn verify_blob_kzg_proof_rust<
@@ -564,7 +783,7 @@ pub fn verify_blob_kzg_proof_rust<
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
-    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
@@ -572,18 +791,21 @@ pub fn verify_blob_kzg_proof_rust<
     commitment_g1: &TG1,
     proof_g1: &TG1,
     ts: &TKZGSettings,
-) -> Result<bool, String> {
-    if !commitment_g1.is_inf() && !commitment_g1.is_valid() {
-        return Err("Invalid commitment".to_string());
-    }
-    if !proof_g1.is_inf() && !proof_g1.is_valid() {
-        return Err("Invalid proof".to_string());
-    }
+) -> Result<bool, Error> {
+    ts.get_thread_pool().install(|| {
+        if !commitment_g1.is_inf() && !commitment_g1.is_valid() {
+            return Err(Error::InvalidCommitment);
+        }
+        if !proof_g1.is_inf() && !proof_g1.is_valid() {
+            return Err(Error::InvalidProof);
+        }
 
-    let polynomial = blob_to_polynomial(blob)?;
-    let evaluation_challenge_fr = compute_challenge(blob, commitment_g1);
-    let y_fr = evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts)?;
-    verify_kzg_proof_rust(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, ts)
+        let polynomial = blob_to_polynomial(blob)?;
+        let evaluation_challenge_fr = compute_challenge(blob, commitment_g1);
+        let y_fr =
+            evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts)?;
+        verify_kzg_proof_rust(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, ts)
+    })
 }
 
 fn compute_challenges_and_evaluate_polynomial<
@@ -599,7 +821,7 @@ fn compute_challenges_and_evaluate_polynomial<
     blobs: &[Vec<TFr>],
     commitments_g1: &[TG1],
     ts: &TKZGSettings,
-) -> Result<(Vec<TFr>, Vec<TFr>), String> {
+) -> Result<(Vec<TFr>, Vec<TFr>), Error> {
     let mut evaluation_challenges_fr = Vec::with_capacity(blobs.len());
     let mut ys_fr = Vec::with_capacity(blobs.len());
 
@@ -616,16 +838,19 @@ fn compute_challenges_and_evaluate_polynomial<
     Ok((evaluation_challenges_fr, ys_fr))
 }
 
-fn validate_batched_input<TG1: G1>(commitments: &[TG1], proofs: &[TG1]) -> Result<(), String> {
+pub(crate) fn validate_batched_input<TG1: G1>(
+    commitments: &[TG1],
+    proofs: &[TG1],
+) -> Result<(), Error> {
     let invalid_commitment = cfg_into_iter!(commitments)
         .any(|commitment| !commitment.is_inf() && !commitment.is_valid());
     let invalid_proof = cfg_into_iter!(proofs).any(|proof| !proof.is_inf() && !proof.is_valid());
 
     if invalid_commitment {
-        return Err("Invalid commitment".to_string());
+        return Err(Error::InvalidCommitment);
     }
     if invalid_proof {
-        return Err("Invalid proof".to_string());
+        return Err(Error::InvalidProof);
     }
 
     Ok(())
@@ -645,93 +870,353 @@ pub fn verify_blob_kzg_proof_batch_rust<
     commitments_g1: &[TG1],
     proofs_g1: &[TG1],
     ts: &TKZGSettings,
-) -> Result<bool, String> {
-    // Exit early if we are given zero blobs
-    if blobs.is_empty() {
-        return Ok(true);
+) -> Result<bool, Error> {
+    ts.get_thread_pool().install(|| {
+        // Exit early if we are given zero blobs
+        if blobs.is_empty() {
+            return Ok(true);
+        }
+
+        // For a single blob, just do a regular single verification
+        if blobs.len() == 1 {
+            return verify_blob_kzg_proof_rust(&blobs[0], &commitments_g1[0], &proofs_g1[0], ts);
+        }
+
+        if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
+            return Err(Error::InvalidLength(
+                "Invalid amount of arguments".to_string(),
+            ));
+        }
+
+        #[cfg(feature = "parallel")]
+        {
+            let num_blobs = blobs.len();
+            let num_cores = rayon::current_num_threads();
+
+            if num_blobs > num_cores {
+                validate_batched_input(commitments_g1, proofs_g1)?;
+
+                // Process blobs in parallel subgroups
+                let blobs_per_group = num_blobs / num_cores;
+
+                blobs
+                    .par_chunks(blobs_per_group)
+                    .enumerate()
+                    .map(|(i, blob_group)| {
+                        let num_blobs_in_group = blob_group.len();
+                        let commitment_group = &commitments_g1
+                            [blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
+                        let proof_group = &proofs_g1
+                            [blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
+                        let (evaluation_challenges_fr, ys_fr) =
+                            compute_challenges_and_evaluate_polynomial(
+                                blob_group,
+                                commitment_group,
+                                ts,
+                            )?;
+
+                        verify_kzg_proof_batch(
+                            commitment_group,
+                            &evaluation_challenges_fr,
+                            &ys_fr,
+                            proof_group,
+                            ts,
+                        )
+                    })
+                    .try_reduce(|| true, |a, b| Ok(a && b))
+            } else {
+                // Each group contains either one or zero blobs, so iterate
+                // over the single blob verification function in parallel
+                (blobs, commitments_g1, proofs_g1)
+                    .into_par_iter()
+                    .map(|(blob, commitment, proof)| {
+                        verify_blob_kzg_proof_rust(blob, commitment, proof, ts)
+                    })
+                    .try_reduce(|| true, |a, b| Ok(a && b))
+            }
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            validate_batched_input(commitments_g1, proofs_g1)?;
+            let (evaluation_challenges_fr, ys_fr) =
+                compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts)?;
+
+            verify_kzg_proof_batch(
+                commitments_g1,
+                &evaluation_challenges_fr,
+                &ys_fr,
+                proofs_g1,
+                ts,
+            )
+        }
+    })
+}
+
+/// Collects KZG proofs that arrive in separate calls and verifies all of them at once.
+///
+/// Checks are not performed when proofs are added, except for point validation. At
+/// [`finalize`](Self::finalize), each proof check `e(proof, [s - z]) == e(C - [y], [1])` is
+/// weighted by a power of a Fiat-Shamir challenge over all collected proofs, and the sums are
+/// checked with a single pairing check, so the cost of the final exponentiation is paid once.
+pub struct PairingAccumulator<
+    'a,
+    TFr,
+    TG1,
+    TG2,
+    TFFTSettings,
+    TPoly,
+    TKZGSettings,
+    TG1Fp,
+    TG1Affine,
+> where
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    settings: &'a TKZGSettings,
+    commitments: Vec<TG1>,
+    zs: Vec<TFr>,
+    ys: Vec<TFr>,
+    proofs: Vec<TG1>,
+    _marker: PhantomData<(TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine)>,
+}
+
+impl<'a, TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+    PairingAccumulator<'a, TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+where
+    TFr: Fr + Copy,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    pub fn new(settings: &'a TKZGSettings) -> Self {
+        Self {
+            settings,
+            commitments: Vec::new(),
+            zs: Vec::new(),
+            ys: Vec::new(),
+            proofs: Vec::new(),
+            _marker: PhantomData,
+        }
     }
 
-    // For a single blob, just do a regular single verification
-    if blobs.len() == 1 {
-        return verify_blob_kzg_proof_rust(&blobs[0], &commitments_g1[0], &proofs_g1[0], ts);
+    /// Number of proofs collected so far
+    pub fn len(&self) -> usize {
+        self.proofs.len()
     }
 
-    if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
-        return Err("Invalid amount of arguments".to_string());
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
     }
 
-    #[cfg(feature = "parallel")]
-    {
-        let num_blobs = blobs.len();
-        let num_cores = num_cpus::get_physical();
+    /// Adds a proof, which would be checked by [`verify_kzg_proof_rust`]
+    pub fn add_kzg_proof(
+        &mut self,
+        commitment: &TG1,
+        z: &TFr,
+        y: &TFr,
+        proof: &TG1,
+    ) -> Result<(), Error> {
+        if !commitment.is_inf() && !commitment.is_valid() {
+            return Err(Error::InvalidCommitment);
+        }
+        if !proof.is_inf() && !proof.is_valid() {
+            return Err(Error::InvalidProof);
+        }
 
-        if num_blobs > num_cores {
-            validate_batched_input(commitments_g1, proofs_g1)?;
+        self.commitments.push(commitment.clone());
+        self.zs.push(*z);
+        self.ys.push(*y);
+        self.proofs.push(proof.clone());
 
-            // Process blobs in parallel subgroups
-            let blobs_per_group = num_blobs / num_cores;
-
-            blobs
-                .par_chunks(blobs_per_group)
-                .enumerate()
-                .map(|(i, blob_group)| {
-                    let num_blobs_in_group = blob_group.len();
-                    let commitment_group = &commitments_g1
-                        [blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
-                    let proof_group =
-                        &proofs_g1[blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
-                    let (evaluation_challenges_fr, ys_fr) =
-                        compute_challenges_and_evaluate_polynomial(
-                            blob_group,
-                            commitment_group,
-                            ts,
-                        )?;
-
-                    verify_kzg_proof_batch(
-                        commitment_group,
-                        &evaluation_challenges_fr,
-                        &ys_fr,
-                        proof_group,
-                        ts,
-                    )
-                })
-                .try_reduce(|| true, |a, b| Ok(a && b))
-        } else {
-            // Each group contains either one or zero blobs, so iterate
-            // over the single blob verification function in parallel
-            (blobs, commitments_g1, proofs_g1)
-                .into_par_iter()
-                .map(|(blob, commitment, proof)| {
-                    verify_blob_kzg_proof_rust(blob, commitment, proof, ts)
-                })
-                .try_reduce(|| true, |a, b| Ok(a && b))
+        Ok(())
+    }
+
+    /// Adds a proof, which would be checked by [`verify_blob_kzg_proof_rust`]
+    pub fn add_blob_kzg_proof(
+        &mut self,
+        blob: &[TFr],
+        commitment: &TG1,
+        proof: &TG1,
+    ) -> Result<(), Error> {
+        let polynomial = blob_to_polynomial(blob)?;
+        let evaluation_challenge_fr = compute_challenge(blob, commitment);
+        let y_fr = evaluate_polynomial_in_evaluation_form(
+            &polynomial,
+            &evaluation_challenge_fr,
+            self.settings,
+        )?;
+
+        self.add_kzg_proof(commitment, &evaluation_challenge_fr, &y_fr, proof)
+    }
+
+    /// Returns whether all collected proofs are valid
+    pub fn finalize(self) -> Result<bool, Error> {
+        if self.is_empty() {
+            return Ok(true);
         }
+
+        verify_kzg_proof_batch(
+            &self.commitments,
+            &self.zs,
+            &self.ys,
+            &self.proofs,
+            self.settings,
+        )
     }
+}
+
+/// Verifies a batch of blob proofs like [`verify_blob_kzg_proof_batch_rust`], but returns indices
+/// of blobs whose proofs do not verify, or an empty list if all of them do.
+///
+/// Failing sub-batches are bisected, so when only a few proofs are invalid, the cost stays close to
+/// a single batch check.
+pub fn verify_blob_kzg_proof_batch_failures_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blobs: &[Vec<TFr>],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> Result<Vec<usize>, Error> {
+    ts.get_thread_pool().install(|| {
+        if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
+            return Err(Error::InvalidLength(
+                "Invalid amount of arguments".to_string(),
+            ));
+        }
+
+        if blobs.is_empty() {
+            return Ok(Vec::new());
+        }
 
-    #[cfg(not(feature = "parallel"))]
-    {
         validate_batched_input(commitments_g1, proofs_g1)?;
         let (evaluation_challenges_fr, ys_fr) =
             compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts)?;
 
-        verify_kzg_proof_batch(
+        let mut failures = Vec::new();
+        bisect_kzg_proof_batch(
             commitments_g1,
             &evaluation_challenges_fr,
             &ys_fr,
             proofs_g1,
+            0,
             ts,
-        )
+            &mut failures,
+        )?;
+
+        Ok(failures)
+    })
+}
+
+/// Pushes indices of invalid proofs in a batch into `failures`, `offset` is the index of the first
+/// proof in the batch
+fn bisect_kzg_proof_batch<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    offset: usize,
+    ts: &TKZGSettings,
+    failures: &mut Vec<usize>,
+) -> Result<(), Error> {
+    if verify_kzg_proof_batch(commitments_g1, zs_fr, ys_fr, proofs_g1, ts)? {
+        return Ok(());
+    }
+
+    if commitments_g1.len() == 1 {
+        failures.push(offset);
+        return Ok(());
+    }
+
+    let mid = commitments_g1.len() / 2;
+    let (commitments_l, commitments_r) = commitments_g1.split_at(mid);
+    let (zs_l, zs_r) = zs_fr.split_at(mid);
+    let (ys_l, ys_r) = ys_fr.split_at(mid);
+    let (proofs_l, proofs_r) = proofs_g1.split_at(mid);
+
+    #[cfg(feature = "parallel")]
+    {
+        let mut failures_r = Vec::new();
+        let (res_l, res_r) = rayon::join(
+            || bisect_kzg_proof_batch(commitments_l, zs_l, ys_l, proofs_l, offset, ts, failures),
+            || {
+                bisect_kzg_proof_batch(
+                    commitments_r,
+                    zs_r,
+                    ys_r,
+                    proofs_r,
+                    offset + mid,
+                    ts,
+                    &mut failures_r,
+                )
+            },
+        );
+        res_l?;
+        res_r?;
+        failures.append(&mut failures_r);
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        bisect_kzg_proof_batch(commitments_l, zs_l, ys_l, proofs_l, offset, ts, failures)?;
+        bisect_kzg_proof_batch(
+            commitments_r,
+            zs_r,
+            ys_r,
+            proofs_r,
+            offset + mid,
+            ts,
+            failures,
+        )?;
     }
+
+    Ok(())
 }
 
 #[allow(clippy::useless_conversion)]
-pub fn bytes_to_blob<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, String> {
-    if bytes.len() != BYTES_PER_BLOB {
-        return Err(format!(
-            "Invalid byte length. Expected {} got {}",
-            BYTES_PER_BLOB,
+pub fn bytes_to_blob<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, Error> {
+    if bytes.len() % BYTES_PER_FIELD_ELEMENT != 0
+        || !is_valid_blob_width(bytes.len() / BYTES_PER_FIELD_ELEMENT)
+    {
+        return Err(Error::InvalidLength(format!(
+            "Invalid byte length. Expected a power of two number of field elements, got {} bytes",
             bytes.len(),
-        ));
+        )));
     }
 
     bytes
@@ -744,13 +1229,15 @@ fn fr_batch_inv<TFr: Fr + PartialEq + Copy>(
     out: &mut [TFr],
     a: &[TFr],
     len: usize,
-) -> Result<(), String> {
+) -> Result<(), Error> {
     if len == 0 {
-        return Err(String::from("Length is less than 0."));
+        return Err(Error::BadArgs(String::from("Length is less than 0.")));
     }
 
     if a == out {
-        return Err(String::from("Destination is the same as source."));
+        return Err(Error::BadArgs(String::from(
+            "Destination is the same as source.",
+        )));
     }
 
     let mut accumulator = TFr::one();
@@ -761,7 +1248,7 @@ fn fr_batch_inv<TFr: Fr + PartialEq + Copy>(
     }
 
     if accumulator.is_zero() {
-        return Err(String::from("Zero input"));
+        return Err(Error::BadArgs(String::from("Zero input")));
     }
 
     accumulator = accumulator.eucl_inverse();
@@ -779,13 +1266,15 @@ pub fn hash_to_bls_field<TFr: Fr>(x: &[u8; BYTES_PER_FIELD_ELEMENT]) -> TFr {
 }
 
 fn compute_challenge<TFr: Fr, TG1: G1>(blob: &[TFr], commitment: &TG1) -> TFr {
-    let mut bytes: Vec<u8> = vec![0; CHALLENGE_INPUT_SIZE];
+    let bytes_per_blob = blob.len() * BYTES_PER_FIELD_ELEMENT;
+    let mut bytes: Vec<u8> =
+        vec![0; FIAT_SHAMIR_PROTOCOL_DOMAIN.len() + 16 + bytes_per_blob + BYTES_PER_COMMITMENT];
 
     // Copy domain separator
     bytes[..16].copy_from_slice(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
     // Set all other bytes of this 16-byte (big-endian) field to zero
     bytes_of_uint64(&mut bytes[16..24], 0);
-    bytes_of_uint64(&mut bytes[24..32], FIELD_ELEMENTS_PER_BLOB as u64);
+    bytes_of_uint64(&mut bytes[24..32], blob.len() as u64);
 
     for (i, field) in blob.iter().enumerate() {
         let v = field.to_bytes();
@@ -797,7 +1286,7 @@ fn compute_challenge<TFr: Fr, TG1: G1>(blob: &[TFr], commitment: &TG1) -> TFr {
     // Copy commitment
     let v = commitment.to_bytes();
     for i in 0..v.len() {
-        bytes[32 + BYTES_PER_BLOB + i] = v[i];
+        bytes[32 + bytes_per_blob + i] = v[i];
     }
 
     // Now let's create the challenge!
@@ -805,9 +1294,11 @@ fn compute_challenge<TFr: Fr, TG1: G1>(blob: &[TFr], commitment: &TG1) -> TFr {
     hash_to_bls_field(&eval_challenge)
 }
 
-pub fn blob_to_polynomial<TFr: Fr, TPoly: Poly<TFr>>(blob: &[TFr]) -> Result<TPoly, String> {
-    if blob.len() != FIELD_ELEMENTS_PER_BLOB {
-        return Err(String::from("Blob length must be FIELD_ELEMENTS_PER_BLOB"));
+pub fn blob_to_polynomial<TFr: Fr, TPoly: Poly<TFr>>(blob: &[TFr]) -> Result<TPoly, Error> {
+    if !is_valid_blob_width(blob.len()) {
+        return Err(Error::InvalidLength(String::from(
+            "Blob length must be a power of two",
+        )));
     }
     Ok(TPoly::from_coeffs(blob))
 }
@@ -825,41 +1316,44 @@ pub fn evaluate_polynomial_in_evaluation_form<
     p: &TPoly,
     x: &TFr,
     s: &TKZGSettings,
-) -> Result<TFr, String> {
-    if p.len() != FIELD_ELEMENTS_PER_BLOB {
-        return Err(String::from("Incorrect field elements count."));
+) -> Result<TFr, Error> {
+    let field_elements_per_blob = s.get_field_elements_per_blob();
+    if p.len() != field_elements_per_blob {
+        return Err(Error::InvalidLength(String::from(
+            "Incorrect field elements count.",
+        )));
     }
 
-    let mut inverses_in: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
+    let mut inverses_in: Vec<TFr> = vec![TFr::default(); field_elements_per_blob];
+    let mut inverses: Vec<TFr> = vec![TFr::default(); field_elements_per_blob];
 
     let roots_of_unity = s.get_fft_settings().get_roots_of_unity();
     let poly_coeffs = p.get_coeffs();
 
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
+    for i in 0..field_elements_per_blob {
         if x == &roots_of_unity[i] {
             return Ok(poly_coeffs[i]);
         }
         inverses_in[i] = x.sub(&roots_of_unity[i]);
     }
 
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB)?;
+    fr_batch_inv(&mut inverses, &inverses_in, field_elements_per_blob)?;
 
     let mut tmp: TFr;
     let mut out = TFr::zero();
 
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
+    for i in 0..field_elements_per_blob {
         tmp = inverses[i].mul(&roots_of_unity[i]);
         tmp = tmp.mul(&poly_coeffs[i]);
         out = out.add(&tmp);
     }
 
-    tmp = TFr::from_u64(FIELD_ELEMENTS_PER_BLOB as u64);
+    tmp = TFr::from_u64(field_elements_per_blob as u64);
     out = match out.div(&tmp) {
         Ok(value) => value,
         Err(err) => return Err(err),
     };
-    tmp = x.pow(FIELD_ELEMENTS_PER_BLOB);
+    tmp = x.pow(field_elements_per_blob);
     tmp = tmp.sub(&TFr::one());
     out = out.mul(&tmp);
     Ok(out)
@@ -878,7 +1372,49 @@ fn is_trusted_setup_in_lagrange_form<TG1: G1 + PairingVerify<TG1, TG2>, TG2: G2>
     !is_monotomial_form
 }
 
-#[allow(clippy::useless_conversion)]
+/// Validate parsed trusted setup points. Returns G1 points in bit-reversed order, G2 points and FFT settings,
+/// ready to be passed to [`KZGSettings::new`].
+#[allow(clippy::type_complexity)]
+pub fn load_trusted_setup_values_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TG1Fp: G1Fp,
+>(
+    mut g1_values: Vec<TG1>,
+    g2_values: Vec<TG2>,
+) -> Result<(Vec<TG1>, Vec<TG2>, usize, TFFTSettings), Error> {
+    let num_g1_points = g1_values.len();
+    if !is_valid_blob_width(num_g1_points) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G1 points",
+        )));
+    }
+
+    if g2_values.len() != TRUSTED_SETUP_NUM_G2_POINTS {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G2 points",
+        )));
+    }
+
+    // Sanity check, that user is not trying to load old trusted setup file
+    if !is_trusted_setup_in_lagrange_form::<TG1, TG2>(&g1_values, &g2_values) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Trusted setup is not in Lagrange form",
+        )));
+    }
+
+    let mut max_scale: usize = 0;
+    while (1 << max_scale) < num_g1_points {
+        max_scale += 1;
+    }
+
+    let fs = TFFTSettings::new(max_scale)?;
+    reverse_bit_order(&mut g1_values)?;
+    Ok((g1_values, g2_values, max_scale, fs))
+}
+
 pub fn load_trusted_setup_rust<
     TFr: Fr,
     TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2>,
@@ -891,38 +1427,30 @@ pub fn load_trusted_setup_rust<
 >(
     g1_bytes: &[u8],
     g2_bytes: &[u8],
-) -> Result<TKZGSettings, String> {
-    let num_g1_points = g1_bytes.len() / BYTES_PER_G1;
-    if num_g1_points != FIELD_ELEMENTS_PER_BLOB {
-        return Err(String::from("Invalid number of G1 points"));
+) -> Result<TKZGSettings, Error> {
+    if g1_bytes.len() % BYTES_PER_G1 != 0 || !is_valid_blob_width(g1_bytes.len() / BYTES_PER_G1) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G1 points",
+        )));
     }
 
-    let num_g2_points = g2_bytes.len() / BYTES_PER_G2;
-    if num_g2_points != TRUSTED_SETUP_NUM_G2_POINTS {
-        return Err(String::from("Invalid number of G2 points"));
+    if g2_bytes.len() != TRUSTED_SETUP_NUM_G2_POINTS * BYTES_PER_G2 {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G2 points",
+        )));
     }
 
-    let mut g1_values = g1_bytes
+    let g1_values = g1_bytes
         .chunks(BYTES_PER_G1)
         .map(TG1::from_bytes)
-        .collect::<Result<Vec<TG1>, String>>()?;
+        .collect::<Result<Vec<TG1>, Error>>()?;
 
     let g2_values = g2_bytes
         .chunks(BYTES_PER_G2)
         .map(TG2::from_bytes)
-        .collect::<Result<Vec<TG2>, String>>()?;
+        .collect::<Result<Vec<TG2>, Error>>()?;
 
-    // Sanity check, that user is not trying to load old trusted setup file
-    if !is_trusted_setup_in_lagrange_form::<TG1, TG2>(&g1_values, &g2_values) {
-        return Err(String::from("Trusted setup is not in Lagrange form"));
-    }
-
-    let mut max_scale: usize = 0;
-    while (1 << max_scale) < num_g1_points {
-        max_scale += 1;
-    }
-
-    let fs = TFFTSettings::new(max_scale)?;
-    reverse_bit_order(&mut g1_values)?;
-    TKZGSettings::new(g1_values.as_slice(), g2_values.as_slice(), max_scale, &fs)
+    let (g1_values, g2_values, max_scale, fs) =
+        load_trusted_setup_values_rust::<TFr, TG1, TG2, TFFTSettings, TG1Fp>(g1_values, g2_values)?;
+    TKZGSettings::new_from_lagrange(g1_values.as_slice(), g2_values.as_slice(), max_scale, &fs)
 }
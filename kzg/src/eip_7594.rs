@@ -0,0 +1,474 @@
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::common_utils::reverse_bit_order;
+use crate::eip_4844::{
+    bytes_of_uint64, compute_powers, hash, hash_to_bls_field, is_valid_blob_width,
+    validate_batched_input, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+    FIELD_ELEMENTS_PER_BLOB,
+};
+use crate::{
+    Error, FFTFr, FFTSettings, FK20MultiSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul,
+    KZGSettings, PairingVerify, Poly, PolyRecover, DAS, G1, G2,
+};
+
+////////////////////////////// Constant values for EIP-7594 //////////////////////////////
+
+// Extended blob sizes of the mainnet setup, settings of other widths scale them accordingly
+pub const FIELD_ELEMENTS_PER_EXT_BLOB: usize = 2 * FIELD_ELEMENTS_PER_BLOB;
+pub const FIELD_ELEMENTS_PER_CELL: usize = 64;
+pub const BYTES_PER_CELL: usize = FIELD_ELEMENTS_PER_CELL * BYTES_PER_FIELD_ELEMENT;
+pub const CELLS_PER_EXT_BLOB: usize = FIELD_ELEMENTS_PER_EXT_BLOB / FIELD_ELEMENTS_PER_CELL;
+
+pub const RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN: [u8; 16] = [
+    82, 67, 75, 90, 71, 67, 66, 65, 84, 67, 72, 95, 95, 86, 49, 95,
+]; // "RCKZGCBATCH__V1_"
+
+////////////////////////////// Settings for EIP-7594 //////////////////////////////
+
+/// Settings needed to compute, verify and recover cells of an extended blob.
+///
+/// `kzg_settings` holds the trusted setup in monomial form together with FFT settings
+/// spanning the extended blob, and `fk20_settings` is built from it to compute all cell
+/// proofs at once.
+#[derive(Debug, Clone, Default)]
+pub struct CellKZGSettings<TKZGSettings, TFK20MultiSettings> {
+    pub kzg_settings: TKZGSettings,
+    pub fk20_settings: TFK20MultiSettings,
+}
+
+pub fn load_cell_kzg_settings_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    settings: &TKZGSettings,
+) -> Result<CellKZGSettings<TKZGSettings, TFK20MultiSettings>, Error> {
+    // Every blob has to span at least one cell, so that the extended blob splits into two or more
+    let field_elements_per_blob = settings.get_field_elements_per_blob();
+    if !is_valid_blob_width(field_elements_per_blob)
+        || field_elements_per_blob < FIELD_ELEMENTS_PER_CELL
+    {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G1 points, blob must hold at least FIELD_ELEMENTS_PER_CELL elements",
+        )));
+    }
+
+    let field_elements_per_ext_blob = 2 * field_elements_per_blob;
+    let mut max_scale: usize = 0;
+    while (1 << max_scale) < field_elements_per_ext_blob {
+        max_scale += 1;
+    }
+
+    let fs = TFFTSettings::new(max_scale)?;
+    let kzg_settings = TKZGSettings::new(
+        settings.get_g1_monomial(),
+        settings.get_g2_secret(),
+        max_scale,
+        &fs,
+    )?;
+    let fk20_settings = TFK20MultiSettings::new(
+        &kzg_settings,
+        field_elements_per_ext_blob,
+        FIELD_ELEMENTS_PER_CELL,
+    )?;
+
+    Ok(CellKZGSettings {
+        kzg_settings,
+        fk20_settings,
+    })
+}
+
+////////////////////////////// Utility functions for EIP-7594 //////////////////////////////
+
+#[allow(clippy::useless_conversion)]
+pub fn bytes_to_cell<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, Error> {
+    if bytes.len() != BYTES_PER_CELL {
+        return Err(Error::InvalidLength(format!(
+            "Invalid byte length. Expected {} got {}",
+            BYTES_PER_CELL,
+            bytes.len(),
+        )));
+    }
+
+    bytes
+        .chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(TFr::from_bytes)
+        .collect()
+}
+
+/// Number of cells in an extended blob of settings with the given blob width
+pub fn cells_per_ext_blob(field_elements_per_blob: usize) -> usize {
+    2 * field_elements_per_blob / FIELD_ELEMENTS_PER_CELL
+}
+
+fn validate_cells<TFr: Fr>(
+    cell_indices: &[usize],
+    cells: &[Vec<TFr>],
+    cells_per_ext_blob: usize,
+) -> Result<(), Error> {
+    if cell_indices
+        .iter()
+        .any(|&index| index >= cells_per_ext_blob)
+    {
+        return Err(Error::BadArgs(String::from("Invalid cell index")));
+    }
+    if cells
+        .iter()
+        .any(|cell| cell.len() != FIELD_ELEMENTS_PER_CELL)
+    {
+        return Err(Error::InvalidLength(String::from(
+            "Cell length must be FIELD_ELEMENTS_PER_CELL",
+        )));
+    }
+
+    Ok(())
+}
+
+/// Splits extended blob evaluations given in bit-reversed order into cells.
+fn evaluations_to_cells<TFr: Fr>(evaluations: &[TFr]) -> Vec<Vec<TFr>> {
+    evaluations
+        .chunks(FIELD_ELEMENTS_PER_CELL)
+        .map(|cell| cell.to_vec())
+        .collect()
+}
+
+////////////////////////////// Trait based implementations of functions for EIP-7594 //////////////////////////////
+
+pub fn compute_cells_and_kzg_proofs_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + DAS<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blob: &[TFr],
+    s: &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error> {
+    s.kzg_settings.get_thread_pool().install(|| {
+        let field_elements_per_blob = s.kzg_settings.get_field_elements_per_blob();
+        if blob.len() != field_elements_per_blob {
+            return Err(Error::InvalidLength(format!(
+                "Blob length must be {}",
+                field_elements_per_blob
+            )));
+        }
+
+        let fs = s.kzg_settings.get_fft_settings();
+
+        // Blob holds evaluations over the bit-reversed domain, which are the even members of the
+        // extended domain once put into natural order
+        let mut evens = blob.to_vec();
+        reverse_bit_order(&mut evens)?;
+        let odds = fs.das_fft_extension(&evens)?;
+
+        let mut extended = Vec::with_capacity(2 * field_elements_per_blob);
+        for (even, odd) in evens.iter().zip(odds) {
+            extended.push(even.clone());
+            extended.push(odd);
+        }
+        reverse_bit_order(&mut extended)?;
+
+        let poly = TPoly::from_coeffs(&fs.fft_fr(&evens, true)?);
+        let proofs = s.fk20_settings.data_availability(&poly)?;
+
+        Ok((evaluations_to_cells(&extended), proofs))
+    })
+}
+
+pub fn recover_cells_and_kzg_proofs_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    cell_indices: &[usize],
+    cells: &[Vec<TFr>],
+    s: &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+) -> Result<(Vec<Vec<TFr>>, Vec<TG1>), Error> {
+    s.kzg_settings.get_thread_pool().install(|| {
+        let field_elements_per_blob = s.kzg_settings.get_field_elements_per_blob();
+        let cells_per_ext_blob = cells_per_ext_blob(field_elements_per_blob);
+        if cell_indices.len() != cells.len() {
+            return Err(Error::InvalidLength(String::from(
+                "Invalid amount of arguments",
+            )));
+        }
+        if cells.len() < cells_per_ext_blob / 2 {
+            return Err(Error::BadArgs(String::from(
+                "Not enough cells to recover the blob",
+            )));
+        }
+        if cells.len() > cells_per_ext_blob {
+            return Err(Error::BadArgs(String::from("Too many cells")));
+        }
+        validate_cells(cell_indices, cells, cells_per_ext_blob)?;
+
+        let mut samples: Vec<Option<TFr>> = vec![None; 2 * field_elements_per_blob];
+        for (&index, cell) in cell_indices.iter().zip(cells) {
+            let offset = index * FIELD_ELEMENTS_PER_CELL;
+            if samples[offset].is_some() {
+                return Err(Error::BadArgs(String::from("Duplicate cell index")));
+            }
+
+            for (sample, fr) in samples[offset..(offset + FIELD_ELEMENTS_PER_CELL)]
+                .iter_mut()
+                .zip(cell)
+            {
+                *sample = Some(fr.clone());
+            }
+        }
+
+        let fs = s.kzg_settings.get_fft_settings();
+
+        // Cells hold evaluations over the bit-reversed extended domain
+        reverse_bit_order(&mut samples)?;
+        let mut extended = if cells.len() == cells_per_ext_blob {
+            samples.into_iter().map(Option::unwrap).collect()
+        } else {
+            TPoly::recover_poly_from_samples(&samples, fs)?
+                .get_coeffs()
+                .to_vec()
+        };
+
+        let mut coeffs = fs.fft_fr(&extended, true)?;
+        coeffs.truncate(field_elements_per_blob);
+        let poly = TPoly::from_coeffs(&coeffs);
+        let proofs = s.fk20_settings.data_availability(&poly)?;
+
+        reverse_bit_order(&mut extended)?;
+
+        Ok((evaluations_to_cells(&extended), proofs))
+    })
+}
+
+fn compute_cell_r_powers<TG1: G1, TFr: Fr>(
+    commitments_g1: &[TG1],
+    commitment_indices: &[usize],
+    cell_indices: &[usize],
+    cells: &[Vec<TFr>],
+    proofs_g1: &[TG1],
+    field_elements_per_blob: usize,
+) -> Result<Vec<TFr>, Error> {
+    let num_commitments = commitments_g1.len();
+    let n = cells.len();
+    let input_size = 48
+        + num_commitments * BYTES_PER_COMMITMENT
+        + n * (16 + FIELD_ELEMENTS_PER_CELL * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
+
+    let mut bytes: Vec<u8> = vec![0; input_size];
+
+    // Copy domain separator
+    bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN);
+    bytes_of_uint64(&mut bytes[16..24], field_elements_per_blob as u64);
+    bytes_of_uint64(&mut bytes[24..32], FIELD_ELEMENTS_PER_CELL as u64);
+    bytes_of_uint64(&mut bytes[32..40], num_commitments as u64);
+    bytes_of_uint64(&mut bytes[40..48], n as u64);
+    let mut offset = 48;
+
+    for commitment in commitments_g1 {
+        let v = commitment.to_bytes();
+        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
+        offset += BYTES_PER_COMMITMENT;
+    }
+
+    for i in 0..n {
+        // Copy index of the commitment the cell belongs to
+        bytes_of_uint64(
+            &mut bytes[offset..(offset + 8)],
+            commitment_indices[i] as u64,
+        );
+        offset += 8;
+
+        // Copy cell index
+        bytes_of_uint64(&mut bytes[offset..(offset + 8)], cell_indices[i] as u64);
+        offset += 8;
+
+        // Copy cell evaluations
+        for fr in &cells[i] {
+            let v = fr.to_bytes();
+            bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
+            offset += BYTES_PER_FIELD_ELEMENT;
+        }
+
+        // Copy proof
+        let v = proofs_g1[i].to_bytes();
+        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
+        offset += BYTES_PER_PROOF;
+    }
+
+    // Make sure we wrote the entire buffer
+    if offset != input_size {
+        return Err(Error::Internal(String::from(
+            "Error while copying commitments",
+        )));
+    }
+
+    // Now let's create the challenge!
+    let eval_challenge = hash(&bytes);
+    let r = hash_to_bls_field(&eval_challenge);
+
+    Ok(compute_powers(&r, n))
+}
+
+pub fn verify_cell_kzg_proof_batch_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitments_g1: &[TG1],
+    cell_indices: &[usize],
+    cells: &[Vec<TFr>],
+    proofs_g1: &[TG1],
+    s: &CellKZGSettings<TKZGSettings, TFK20MultiSettings>,
+) -> Result<bool, Error> {
+    s.kzg_settings.get_thread_pool().install(|| {
+        let field_elements_per_blob = s.kzg_settings.get_field_elements_per_blob();
+        let cells_per_ext_blob = cells_per_ext_blob(field_elements_per_blob);
+        let n = cells.len();
+        if commitments_g1.len() != n || cell_indices.len() != n || proofs_g1.len() != n {
+            return Err(Error::InvalidLength(String::from(
+                "Invalid amount of arguments",
+            )));
+        }
+
+        // Exit early if we are given zero cells
+        if n == 0 {
+            return Ok(true);
+        }
+
+        validate_batched_input(commitments_g1, proofs_g1)?;
+        validate_cells(cell_indices, cells, cells_per_ext_blob)?;
+
+        // Deduplicate commitments, so each of them gets multiplied only once
+        let mut unique_commitments: Vec<TG1> = Vec::new();
+        let mut commitment_indices: Vec<usize> = Vec::with_capacity(n);
+        let mut commitment_lookup: BTreeMap<[u8; BYTES_PER_COMMITMENT], usize> = BTreeMap::new();
+        for commitment in commitments_g1 {
+            let index = *commitment_lookup
+                .entry(commitment.to_bytes())
+                .or_insert_with(|| {
+                    unique_commitments.push(commitment.clone());
+                    unique_commitments.len() - 1
+                });
+            commitment_indices.push(index);
+        }
+
+        // Compute the random lincomb challenges
+        let r_powers = compute_cell_r_powers(
+            &unique_commitments,
+            &commitment_indices,
+            cell_indices,
+            cells,
+            proofs_g1,
+            field_elements_per_blob,
+        )?;
+
+        // Compute \sum r^k * Proof_k
+        let proof_lincomb = TG1::g1_lincomb(proofs_g1, &r_powers, n, None);
+
+        // Compute \sum r^k * C_k, summing up the weights of repeated commitments
+        let mut commitment_weights = vec![TFr::zero(); unique_commitments.len()];
+        for (weight_index, r_power) in commitment_indices.iter().zip(&r_powers) {
+            commitment_weights[*weight_index] = commitment_weights[*weight_index].add(r_power);
+        }
+        let commitment_lincomb = TG1::g1_lincomb(
+            &unique_commitments,
+            &commitment_weights,
+            unique_commitments.len(),
+            None,
+        );
+
+        // Aggregate cell evaluations by the coset they belong to
+        let mut aggregated_cells: Vec<Option<Vec<TFr>>> = vec![None; cells_per_ext_blob];
+        for ((&cell_index, cell), r_power) in cell_indices.iter().zip(cells).zip(&r_powers) {
+            let aggregated =
+                aggregated_cells[cell_index].get_or_insert_with(|| vec![TFr::zero(); cell.len()]);
+            for (sum, fr) in aggregated.iter_mut().zip(cell) {
+                *sum = sum.add(&fr.mul(r_power));
+            }
+        }
+
+        // Compute \sum r^k * I_k(x), where I_k interpolates cell k over its coset
+        let fs = s.kzg_settings.get_fft_settings();
+        let mut interpolation_poly = vec![TFr::zero(); FIELD_ELEMENTS_PER_CELL];
+        for (cell_index, aggregated) in aggregated_cells.into_iter().enumerate() {
+            let mut evaluations = match aggregated {
+                Some(evaluations) => evaluations,
+                None => continue,
+            };
+
+            reverse_bit_order(&mut evaluations)?;
+            let coeffs = fs.fft_fr(&evaluations, true)?;
+
+            // Move the interpolation polynomial from the subgroup onto the coset
+            let inv_coset_shift = get_coset_shift(fs, cell_index).inverse();
+            let mut shift_power = TFr::one();
+            for (sum, coeff) in interpolation_poly.iter_mut().zip(coeffs) {
+                *sum = sum.add(&coeff.mul(&shift_power));
+                shift_power = shift_power.mul(&inv_coset_shift);
+            }
+        }
+        let interpolation_commitment = TG1::g1_lincomb(
+            s.kzg_settings.get_g1_secret(),
+            &interpolation_poly,
+            FIELD_ELEMENTS_PER_CELL,
+            None,
+        );
+
+        // Compute \sum r^k * h_k^n * Proof_k
+        let weighted_r_powers = cell_indices
+            .iter()
+            .zip(&r_powers)
+            .map(|(&cell_index, r_power)| {
+                r_power.mul(&get_coset_shift(fs, cell_index).pow(FIELD_ELEMENTS_PER_CELL))
+            })
+            .collect::<Vec<TFr>>();
+        let weighted_proof_lincomb = TG1::g1_lincomb(proofs_g1, &weighted_r_powers, n, None);
+
+        let rhs_g1 = commitment_lincomb
+            .sub(&interpolation_commitment)
+            .add_or_dbl(&weighted_proof_lincomb);
+
+        // Do the pairing check!
+        Ok(TG1::verify(
+            &proof_lincomb,
+            &s.kzg_settings.get_g2_secret()[FIELD_ELEMENTS_PER_CELL],
+            &rhs_g1,
+            &TG2::generator(),
+        ))
+    })
+}
+
+fn get_coset_shift<TFr: Fr, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    cell_index: usize,
+) -> TFr {
+    fs.get_roots_of_unity_at(cell_index * FIELD_ELEMENTS_PER_CELL)
+}
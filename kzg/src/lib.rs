@@ -4,14 +4,79 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display, Formatter};
 use msm::precompute::PrecomputationTable;
+use msm::strategy::MsmStrategy;
+use thread_pool::ThreadPoolConfig;
 
+pub mod backend;
+pub mod blob_codec;
+pub mod c_api;
 pub mod common_utils;
 pub mod eip_4844;
+pub mod eip_7594;
 pub mod msm;
+pub mod thread_pool;
+#[cfg(feature = "blst")]
+pub mod trusted_setup;
+
+/// Error returned by all fallible operations, shared by every backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Input has an unexpected number of bytes or elements
+    InvalidLength(String),
+    /// Bytes do not represent a canonical field element
+    NonCanonicalFieldElement,
+    /// Bytes do not represent a point, or the point is not in the correct subgroup
+    InvalidPoint(String),
+    /// Commitment is not a valid point in the correct subgroup
+    InvalidCommitment,
+    /// Proof is not a valid point in the correct subgroup
+    InvalidProof,
+    /// Proof is a valid point, but does not prove the claimed evaluation
+    ProofMismatch,
+    /// Versioned hash was not derived from the given commitment
+    VersionedHashMismatch,
+    /// Trusted setup could not be read or is malformed
+    InvalidTrustedSetup(String),
+    /// Arguments are invalid in any other way, e.g. size is not a power of two
+    BadArgs(String),
+    /// Operation needs a cargo feature, which was not enabled for this build
+    FeatureNotEnabled(&'static str),
+    /// No backend is registered under this name
+    UnknownBackend(String),
+    /// Thread pool could not be created
+    ThreadPool(String),
+    /// Unexpected failure, which is not caused by the inputs
+    Internal(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength(msg)
+            | Self::InvalidPoint(msg)
+            | Self::InvalidTrustedSetup(msg)
+            | Self::BadArgs(msg)
+            | Self::ThreadPool(msg)
+            | Self::Internal(msg) => f.write_str(msg),
+            Self::NonCanonicalFieldElement => f.write_str("Invalid scalar"),
+            Self::InvalidCommitment => f.write_str("Invalid commitment"),
+            Self::InvalidProof => f.write_str("Invalid proof"),
+            Self::ProofMismatch => f.write_str("Proof does not match the claimed evaluation"),
+            Self::VersionedHashMismatch => f.write_str("Versioned hash does not match commitment"),
+            Self::FeatureNotEnabled(feature) => {
+                write!(f, "Operation requires `{}` feature", feature)
+            }
+            Self::UnknownBackend(name) => write!(f, "Unknown backend: {}", name),
+        }
+    }
+}
 
-pub trait Fr: Default + Clone + PartialEq + Sync {
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+pub trait Fr: Default + Clone + PartialEq + Sync + Send {
     fn null() -> Self;
 
     fn zero() -> Self;
@@ -21,13 +86,13 @@ pub trait Fr: Default + Clone + PartialEq + Sync {
     #[cfg(feature = "rand")]
     fn rand() -> Self;
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
 
-    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
         Self::from_bytes(bytes)
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String>;
+    fn from_hex(hex: &str) -> Result<Self, Error>;
 
     fn from_u64_arr(u: &[u64; 4]) -> Self;
 
@@ -59,7 +124,7 @@ pub trait Fr: Default + Clone + PartialEq + Sync {
 
     fn pow(&self, n: usize) -> Self;
 
-    fn div(&self, b: &Self) -> Result<Self, String>;
+    fn div(&self, b: &Self) -> Result<Self, Error>;
 
     fn equals(&self, b: &Self) -> bool;
 
@@ -82,9 +147,9 @@ pub trait G1: Clone + Default + PartialEq + Sync + Debug + Send {
     #[cfg(feature = "rand")]
     fn rand() -> Self;
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
 
-    fn from_hex(hex: &str) -> Result<Self, String>;
+    fn from_hex(hex: &str) -> Result<Self, Error>;
 
     fn to_bytes(&self) -> [u8; 48];
 
@@ -161,6 +226,8 @@ pub trait G1Fp: Clone + Default + Sync + Copy + PartialEq + Debug + Send {
 
     fn from_underlying_arr(arr: &[u64; 6]) -> Self;
 
+    fn to_underlying_arr(&self) -> [u64; 6];
+
     fn neg_assign(&mut self);
 
     fn mul_assign_fp(&mut self, b: &Self);
@@ -315,10 +382,24 @@ impl Scalar256 {
         unsafe { core::slice::from_raw_parts(&*(ptr as *const [u64; N]), 1)[0] }
     }
 
-    fn is_zero(&self) -> bool {
+    pub fn is_zero(&self) -> bool {
         self.data == Self::ZERO.data
     }
 
+    pub fn is_one(&self) -> bool {
+        self.data == Self::ONE.data
+    }
+
+    /// Number of bits needed to represent the scalar, zero for zero scalar
+    pub fn num_bits(&self) -> usize {
+        self.data
+            .iter()
+            .rposition(|&limb| limb != 0)
+            .map_or(0, |i| {
+                64 * i + (u64::BITS - self.data[i].leading_zeros()) as usize
+            })
+    }
+
     fn divn(&mut self, mut n: u32) {
         const N: usize = 4;
         if n >= (64 * N) as u32 {
@@ -353,7 +434,7 @@ pub trait G2: Clone + Default {
 
     fn negative_generator() -> Self;
 
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error>;
 
     fn to_bytes(&self) -> [u8; 96];
 
@@ -374,16 +455,22 @@ pub trait PairingVerify<TG1: G1, TG2: G2> {
     fn verify(a1: &TG1, a2: &TG2, b1: &TG1, b2: &TG2) -> bool;
 }
 
+pub trait MultiPairing<TG1: G1, TG2: G2> {
+    /// Checks that `e(ps[0], qs[0]) * ... * e(ps[n - 1], qs[n - 1]) == 1`, using a shared Miller
+    /// loop and a single final exponentiation. Returns false if slice lengths differ.
+    fn multi_pairing_is_one(ps: &[TG1], qs: &[TG2]) -> bool;
+}
+
 pub trait FFTFr<Coeff: Fr> {
-    fn fft_fr(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>;
+    fn fft_fr(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, Error>;
 }
 
 pub trait FFTG1<Coeff: G1> {
-    fn fft_g1(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>;
+    fn fft_g1(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, Error>;
 }
 
 pub trait DAS<Coeff: Fr> {
-    fn das_fft_extension(&self, evens: &[Coeff]) -> Result<Vec<Coeff>, String>;
+    fn das_fft_extension(&self, evens: &[Coeff]) -> Result<Vec<Coeff>, Error>;
 }
 
 pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
@@ -393,8 +480,7 @@ pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
     ///
     /// Uses straightforward long multiplication to calculate the product of `(x - r^i)` where `r`
     /// is a root of unity and the `i`s are the indices at which it must evaluate to zero.
-    fn do_zero_poly_mul_partial(&self, idxs: &[usize], stride: usize)
-        -> Result<Polynomial, String>;
+    fn do_zero_poly_mul_partial(&self, idxs: &[usize], stride: usize) -> Result<Polynomial, Error>;
 
     /// Reduce partials using a specified domain size.
     /// Calculates the product of all polynomials via FFT and then applies an inverse FFT to produce
@@ -403,7 +489,7 @@ pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
         &self,
         domain_size: usize,
         partials: &[Polynomial],
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
 
     /// Calculate the minimal polynomial that evaluates to zero for powers of roots of unity that
     /// correspond to missing indices.
@@ -415,11 +501,11 @@ pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
         &self,
         domain_size: usize,
         idxs: &[usize],
-    ) -> Result<(Vec<Coeff>, Polynomial), String>;
+    ) -> Result<(Vec<Coeff>, Polynomial), Error>;
 }
 
 pub trait FFTSettings<Coeff: Fr>: Default + Clone {
-    fn new(scale: usize) -> Result<Self, String>;
+    fn new(scale: usize) -> Result<Self, Error>;
 
     fn get_max_width(&self) -> usize;
 
@@ -442,7 +528,7 @@ pub trait FFTSettingsPoly<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSett
         b: &Polynomial,
         len: usize,
         fs: Option<&FSettings>,
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
 }
 
 pub trait Poly<Coeff: Fr>: Default + Clone {
@@ -477,27 +563,27 @@ pub trait Poly<Coeff: Fr>: Default + Clone {
 
     fn unscale(&mut self);
 
-    fn inverse(&mut self, new_len: usize) -> Result<Self, String>;
+    fn inverse(&mut self, new_len: usize) -> Result<Self, Error>;
 
-    fn div(&mut self, x: &Self) -> Result<Self, String>;
+    fn div(&mut self, x: &Self) -> Result<Self, Error>;
 
-    fn long_div(&mut self, x: &Self) -> Result<Self, String>;
+    fn long_div(&mut self, x: &Self) -> Result<Self, Error>;
 
-    fn fast_div(&mut self, x: &Self) -> Result<Self, String>;
+    fn fast_div(&mut self, x: &Self) -> Result<Self, Error>;
 
-    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String>;
+    fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, Error>;
 }
 
 pub trait PolyRecover<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSettings<Coeff>> {
     fn recover_poly_coeffs_from_samples(
         samples: &[Option<Coeff>],
         fs: &FSettings,
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
 
     fn recover_poly_from_samples(
         samples: &[Option<Coeff>],
         fs: &FSettings,
-    ) -> Result<Polynomial, String>;
+    ) -> Result<Polynomial, Error>;
 }
 
 pub trait KZGSettings<
@@ -515,11 +601,26 @@ pub trait KZGSettings<
         secret_g2: &[Coeff3],
         length: usize,
         fs: &Fs,
-    ) -> Result<Self, String>;
+    ) -> Result<Self, Error>;
+
+    /// Same as [`Self::new`], but `secret_g1` holds G1 points in bit-reversed Lagrange form, as stored in
+    /// EIP-4844 trusted setup. Points in monomial form are derived from them on first use
+    fn new_from_lagrange(
+        secret_g1: &[Coeff2],
+        secret_g2: &[Coeff3],
+        length: usize,
+        fs: &Fs,
+    ) -> Result<Self, Error>;
 
-    fn commit_to_poly(&self, p: &Polynomial) -> Result<Coeff2, String>;
+    fn commit_to_poly(&self, p: &Polynomial) -> Result<Coeff2, Error>;
 
-    fn compute_proof_single(&self, p: &Polynomial, x: &Coeff1) -> Result<Coeff2, String>;
+    /// Commit to polynomial given by its coefficients
+    fn commit_coeff_form(&self, p: &Polynomial) -> Result<Coeff2, Error>;
+
+    /// Commit to polynomial given by its evaluations over roots of unity, in bit-reversed order
+    fn commit_eval_form(&self, p: &Polynomial) -> Result<Coeff2, Error>;
+
+    fn compute_proof_single(&self, p: &Polynomial, x: &Coeff1) -> Result<Coeff2, Error>;
 
     fn check_proof_single(
         &self,
@@ -527,9 +628,9 @@ pub trait KZGSettings<
         proof: &Coeff2,
         x: &Coeff1,
         value: &Coeff1,
-    ) -> Result<bool, String>;
+    ) -> Result<bool, Error>;
 
-    fn compute_proof_multi(&self, p: &Polynomial, x: &Coeff1, n: usize) -> Result<Coeff2, String>;
+    fn compute_proof_multi(&self, p: &Polynomial, x: &Coeff1, n: usize) -> Result<Coeff2, Error>;
 
     fn check_proof_multi(
         &self,
@@ -538,7 +639,7 @@ pub trait KZGSettings<
         x: &Coeff1,
         values: &[Coeff1],
         n: usize,
-    ) -> Result<bool, String>;
+    ) -> Result<bool, Error>;
 
     fn get_expanded_roots_of_unity_at(&self, i: usize) -> Coeff1;
 
@@ -548,9 +649,50 @@ pub trait KZGSettings<
 
     fn get_g1_secret(&self) -> &[Coeff2];
 
+    /// G1 points in monomial form, i.e. `[s^i]G1`
+    fn get_g1_monomial(&self) -> &[Coeff2];
+
     fn get_g2_secret(&self) -> &[Coeff3];
 
+    /// Checks that `e(a, [s]G2) == e(b, G2)`, where `[s]G2` is `get_g2_secret()[1]`. Miller loop
+    /// data of both G2 points is prepared once and reused across calls
+    fn verify_pairing_with_g2_secret(&self, a: &Coeff2, b: &Coeff2) -> bool;
+
     fn get_precomputation(&self) -> Option<&PrecomputationTable<Coeff1, Coeff2, TG1Fp, TG1Affine>>;
+
+    /// Thread pool, which functions taking these settings run their parallel work on
+    fn get_thread_pool(&self) -> &ThreadPoolConfig;
+
+    fn set_thread_pool(&mut self, thread_pool: ThreadPoolConfig);
+
+    /// Selects MSM algorithm used for commitments and proofs computed with these settings
+    fn set_msm_strategy(&self, strategy: MsmStrategy) -> Result<(), Error> {
+        self.get_precomputation()
+            .ok_or_else(|| {
+                Error::BadArgs(String::from(
+                    "Settings have no precomputation table to select MSM strategy for",
+                ))
+            })?
+            .set_msm_strategy(strategy)
+    }
+
+    /// Lets MSM algorithm be chosen by benchmarking all usable ones on first use for each input size
+    #[cfg(feature = "std")]
+    fn enable_msm_auto_tuning(&self) -> Result<(), Error> {
+        self.get_precomputation()
+            .ok_or_else(|| {
+                Error::BadArgs(String::from(
+                    "Settings have no precomputation table to select MSM strategy for",
+                ))
+            })?
+            .enable_msm_auto_tuning();
+        Ok(())
+    }
+
+    /// Number of field elements in a blob, which equals the number of G1 points in the setup
+    fn get_field_elements_per_blob(&self) -> usize {
+        self.get_g1_secret().len()
+    }
 }
 
 pub trait FK20SingleSettings<
@@ -564,11 +706,11 @@ pub trait FK20SingleSettings<
     TG1Affine: G1Affine<Coeff2, TG1Fp>,
 >: Default + Clone
 {
-    fn new(ks: &Ks, n2: usize) -> Result<Self, String>;
+    fn new(ks: &Ks, n2: usize) -> Result<Self, Error>;
 
-    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 
-    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 }
 
 pub trait FK20MultiSettings<
@@ -582,9 +724,9 @@ pub trait FK20MultiSettings<
     TG1Affine: G1Affine<Coeff2, TG1Fp>,
 >: Default + Clone
 {
-    fn new(ks: &Ks, n2: usize, chunk_len: usize) -> Result<Self, String>;
+    fn new(ks: &Ks, n2: usize, chunk_len: usize) -> Result<Self, Error>;
 
-    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 
-    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, String>;
+    fn data_availability_optimized(&self, p: &Polynomial) -> Result<Vec<Coeff2>, Error>;
 }
@@ -1,6 +1,8 @@
 use core::marker::PhantomData;
 
-use crate::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, Scalar256, G1};
+use sha2::{Digest, Sha256};
+
+use crate::{Error, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, Scalar256, G1};
 
 use super::pippenger_utils::{
     booth_decode, booth_encode, get_wval_limb, is_zero, num_bits, p1_dadd, p1_to_jacobian,
@@ -60,6 +62,28 @@ const fn get_table_dimensions(window: BgmwWindow) -> (usize, usize) {
     (window_width, h)
 }
 
+/// Widest window, which [`BgmwTable::new_with_memory_budget`] falls back to
+pub const MAX_BUDGET_WINDOW: usize = 24;
+
+/// Same window configuration, with rows of `window_width` bits
+const fn with_window_width(window: BgmwWindow, window_width: usize) -> BgmwWindow {
+    #[cfg(not(feature = "parallel"))]
+    {
+        let _ = window;
+        window_width
+    }
+
+    #[cfg(feature = "parallel")]
+    {
+        match window {
+            BgmwWindow::Sync(_) => BgmwWindow::Sync(window_width),
+            BgmwWindow::Parallel((nx, _, _)) => {
+                BgmwWindow::Parallel((nx, (NBITS + window_width - 1) / window_width, window_width))
+            }
+        }
+    }
+}
+
 #[inline]
 const fn get_sequential_window_size(window: BgmwWindow) -> usize {
     #[cfg(not(feature = "parallel"))]
@@ -163,9 +187,41 @@ impl<
         TG1Affine: G1Affine<TG1, TG1Fp>,
     > BgmwTable<TFr, TG1, TG1Fp, TG1Affine>
 {
-    pub fn new(points: &[TG1]) -> Result<Option<Self>, String> {
-        let window = Self::window(points.len());
+    pub fn new(points: &[TG1]) -> Result<Option<Self>, Error> {
+        Self::with_window(points, Self::window(points.len()))
+    }
 
+    /// Same as [`Self::new`], but widens the window until the table takes at most `max_bytes` of memory. Wider
+    /// windows need fewer rows, but more buckets, so `None` is returned if no window up to
+    /// [`MAX_BUDGET_WINDOW`] bits fits.
+    pub fn new_with_memory_budget(points: &[TG1], max_bytes: usize) -> Result<Option<Self>, Error> {
+        let mut window = Self::window(points.len());
+        let (mut window_width, _) = get_table_dimensions(window);
+
+        while Self::table_size(points.len(), window) > max_bytes {
+            window_width += 1;
+            if window_width > MAX_BUDGET_WINDOW {
+                return Ok(None);
+            }
+            window = with_window_width(window, window_width);
+        }
+
+        Self::with_window(points, window)
+    }
+
+    /// Size of precomputed points in bytes
+    pub fn memory_usage(&self) -> usize {
+        self.points.len() * core::mem::size_of::<TG1Affine>()
+    }
+
+    fn table_size(npoints: usize, window: BgmwWindow) -> usize {
+        let (_, h) = get_table_dimensions(window);
+        npoints
+            .saturating_mul(h)
+            .saturating_mul(core::mem::size_of::<TG1Affine>())
+    }
+
+    fn with_window(points: &[TG1], window: BgmwWindow) -> Result<Option<Self>, Error> {
         let (window_width, h) = get_table_dimensions(window);
 
         let mut table: Vec<TG1Affine> = Vec::new();
@@ -173,7 +229,7 @@ impl<
 
         table
             .try_reserve_exact(points.len() * h)
-            .map_err(|_| "BGMW precomputation table is too large".to_string())?;
+            .map_err(|_| Error::BadArgs("BGMW precomputation table is too large".to_string()))?;
 
         unsafe { table.set_len(points.len() * h) };
 
@@ -198,9 +254,12 @@ impl<
         }))
     }
 
-    pub fn multiply_sequential(&self, scalars: &[TFr]) -> TG1 {
-        let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
-        let scalars = &scalars[..];
+    /// Number of points, for which this table was built
+    pub fn numpoints(&self) -> usize {
+        self.numpoints
+    }
+
+    pub fn multiply_sequential(&self, scalars: &[Scalar256]) -> TG1 {
         let window = get_sequential_window_size(self.window);
         let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
 
@@ -245,17 +304,13 @@ impl<
     }
 
     #[cfg(feature = "parallel")]
-    pub fn multiply_parallel(&self, scalars: &[TFr]) -> TG1 {
-        use super::{
-            cell::Cell,
-            thread_pool::{da_pool, ThreadPoolExt},
-        };
+    pub fn multiply_parallel(&self, scalars: &[Scalar256]) -> TG1 {
+        use super::cell::Cell;
         use core::sync::atomic::{AtomicUsize, Ordering};
-        use std::sync::{mpsc, Arc};
+        use std::sync::mpsc;
 
         let npoints = scalars.len();
-        let pool = da_pool();
-        let ncpus = pool.max_count();
+        let ncpus = rayon::current_num_threads();
 
         struct Tile {
             x: usize,
@@ -269,8 +324,9 @@ impl<
             BgmwWindow::Parallel(values) => values,
         };
 
-        let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
-        let scalars = &scalars[..];
+        // Columns were chosen for the whole table, but every tile needs at least two points
+        assert!(npoints > 1, "BGMW multiplication needs at least two points");
+        let nx = core::cmp::min(nx, npoints / 2);
 
         // |grid[]| holds "coordinates" and place for result
         let mut grid: Vec<(Tile, Cell<TG1>)> = Vec::with_capacity(nx * ny);
@@ -304,7 +360,7 @@ impl<
 
         let mut row_sync: Vec<AtomicUsize> = Vec::with_capacity(ny);
         row_sync.resize_with(ny, Default::default);
-        let counter = Arc::new(AtomicUsize::new(0));
+        let counter = &AtomicUsize::new(0);
         let (tx, rx) = mpsc::channel();
         let n_workers = core::cmp::min(ncpus, total);
 
@@ -316,44 +372,45 @@ impl<
 
         let results = &results[..];
 
-        #[allow(clippy::needless_range_loop)]
-        for worker_index in 0..n_workers {
-            let tx = tx.clone();
-            let counter = counter.clone();
-
-            pool.joined_execute(move || {
-                let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
-                loop {
-                    let work = counter.fetch_add(1, Ordering::Relaxed);
-                    if work >= total {
-                        integrate_buckets(
-                            unsafe { results[worker_index].as_ptr().as_mut() }.unwrap(),
-                            &buckets,
-                            window - 1,
-                        );
-                        tx.send(worker_index).expect("disaster");
-
-                        break;
+        rayon::scope(|scope| {
+            #[allow(clippy::needless_range_loop)]
+            for worker_index in 0..n_workers {
+                let tx = tx.clone();
+
+                scope.spawn(move |_| {
+                    let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
+                    loop {
+                        let work = counter.fetch_add(1, Ordering::Relaxed);
+                        if work >= total {
+                            integrate_buckets(
+                                unsafe { results[worker_index].as_ptr().as_mut() }.unwrap(),
+                                &buckets,
+                                window - 1,
+                            );
+                            tx.send(worker_index).expect("disaster");
+
+                            break;
+                        }
+
+                        let x = grid[work].0.x;
+                        let y = grid[work].0.y;
+                        let dx = grid[work].0.dx;
+
+                        let row_start = (y / window) * self.numpoints + x;
+                        let points = &self.points[row_start..(row_start + dx)];
+
+                        let (wbits, cbits) = if y + window > NBITS {
+                            let wbits = NBITS - y;
+                            (wbits, wbits + 1)
+                        } else {
+                            (window, window)
+                        };
+
+                        p1_tile_bgmw(points, &scalars[x..(x + dx)], &mut buckets, y, wbits, cbits);
                     }
-
-                    let x = grid[work].0.x;
-                    let y = grid[work].0.y;
-                    let dx = grid[work].0.dx;
-
-                    let row_start = (y / window) * self.numpoints + x;
-                    let points = &self.points[row_start..(row_start + dx)];
-
-                    let (wbits, cbits) = if y + window > NBITS {
-                        let wbits = NBITS - y;
-                        (wbits, wbits + 1)
-                    } else {
-                        (window, window)
-                    };
-
-                    p1_tile_bgmw(points, &scalars[x..(x + dx)], &mut buckets, y, wbits, cbits);
-                }
-            });
-        }
+                });
+            }
+        });
 
         let mut ret = <TG1>::default();
         for _ in 0..n_workers {
@@ -367,10 +424,7 @@ impl<
     fn window(npoints: usize) -> BgmwWindow {
         #[cfg(feature = "parallel")]
         {
-            use super::thread_pool::da_pool;
-
-            let pool = da_pool();
-            let ncpus = pool.max_count();
+            let ncpus = rayon::current_num_threads();
 
             if npoints >= 32 && ncpus >= 2 {
                 BgmwWindow::Parallel(bgmw_parallel_window_size(npoints, ncpus))
@@ -384,6 +438,253 @@ impl<
             bgmw_window_size(npoints)
         }
     }
+
+    /// Key, which identifies table built for `points` by this backend with current window configuration.
+    /// Serialized table can be reused only when its key matches.
+    pub fn cache_key(points: &[TG1]) -> [u8; 32] {
+        Self::cache_key_for_affines(&TG1Affine::into_affines(points), Self::window(points.len()))
+    }
+
+    fn cache_key_for_affines(points: &[TG1Affine], window: BgmwWindow) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(core::any::type_name::<TG1Affine>().as_bytes());
+        for value in encode_window(window) {
+            hasher.update(value.to_le_bytes());
+        }
+        hasher.update((points.len() as u64).to_le_bytes());
+        let mut buf = [0u8; SERIALIZED_POINT_SIZE];
+        for point in points {
+            write_point(&mut buf, point);
+            hasher.update(buf);
+        }
+        hasher.finalize().into()
+    }
+
+    /// Serialize table into versioned binary format, which can be loaded with [`Self::from_bytes`]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let key = Self::cache_key_for_affines(&self.points[..self.numpoints], self.window);
+
+        let mut out = Vec::with_capacity(
+            HEADER_SIZE + self.points.len() * SERIALIZED_POINT_SIZE + CHECKSUM_SIZE,
+        );
+        out.extend_from_slice(&TABLE_MAGIC);
+        out.extend_from_slice(&TABLE_VERSION.to_le_bytes());
+        out.extend_from_slice(&key);
+        for value in encode_window(self.window) {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.numpoints as u64).to_le_bytes());
+        out.extend_from_slice(&(self.h as u64).to_le_bytes());
+
+        let mut buf = [0u8; SERIALIZED_POINT_SIZE];
+        for point in self.points.iter() {
+            write_point(&mut buf, point);
+            out.extend_from_slice(&buf);
+        }
+
+        let checksum: [u8; CHECKSUM_SIZE] = Sha256::digest(&out).into();
+        out.extend_from_slice(&checksum);
+        out
+    }
+
+    /// Deserialize table, previously serialized with [`Self::to_bytes`]. Fails if the data is corrupted, or if
+    /// it is not exactly the table this backend would build for `points`.
+    pub fn from_bytes(bytes: &[u8], points: &[TG1]) -> Result<Self, Error> {
+        if bytes.len() < HEADER_SIZE + CHECKSUM_SIZE {
+            return Err(Error::InvalidLength(
+                "Precomputation table is too short".to_string(),
+            ));
+        }
+
+        let (data, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        if Sha256::digest(data).as_slice() != checksum {
+            return Err(Error::BadArgs(
+                "Precomputation table checksum mismatch".to_string(),
+            ));
+        }
+
+        if data[0..8] != TABLE_MAGIC || read_u32(&data[8..12]) != TABLE_VERSION {
+            return Err(Error::BadArgs(
+                "Unsupported precomputation table format".to_string(),
+            ));
+        }
+
+        if data[12..44] != Self::cache_key(points)[..] {
+            return Err(Error::BadArgs(
+                "Precomputation table was built for different points".to_string(),
+            ));
+        }
+
+        let mut encoded_window = [0u64; 4];
+        for (i, value) in encoded_window.iter_mut().enumerate() {
+            *value = read_u64(&data[44 + i * 8..52 + i * 8]);
+        }
+        let window = decode_window(encoded_window)?;
+        let numpoints = read_u64(&data[76..84]) as usize;
+        let h = read_u64(&data[84..92]) as usize;
+
+        if numpoints != points.len()
+            || numpoints.checked_mul(h) != Some((data.len() - HEADER_SIZE) / SERIALIZED_POINT_SIZE)
+            || (data.len() - HEADER_SIZE) % SERIALIZED_POINT_SIZE != 0
+            || get_table_dimensions(window).1 != h
+        {
+            return Err(Error::InvalidLength(
+                "Precomputation table size does not match its header".to_string(),
+            ));
+        }
+
+        // Affine points at infinity are backend-specific, so finite points are created from some other finite point
+        let finite = TG1Affine::into_affine(&TG1::generator());
+        let table_points = data[HEADER_SIZE..]
+            .chunks_exact(SERIALIZED_POINT_SIZE)
+            .map(|chunk| read_point(chunk, &finite))
+            .collect::<Vec<_>>();
+
+        let table = Self {
+            numpoints,
+            points: table_points,
+            window,
+            h,
+
+            fr_marker: PhantomData,
+            g1_fp_marker: PhantomData,
+            g1_marker: PhantomData,
+        };
+
+        // Checksum only detects accidental corruption, so every precomputed point is checked as well
+        if !table.is_built_for(points) {
+            return Err(Error::BadArgs(
+                "Precomputation table does not match its points".to_string(),
+            ));
+        }
+
+        Ok(table)
+    }
+
+    /// Whether first row holds `points`, and every next row holds points of the previous one multiplied by
+    /// 2^window_width. Doublings are much cheaper than multiplications used to build the table.
+    fn is_built_for(&self, points: &[TG1]) -> bool {
+        let (window_width, _) = get_table_dimensions(self.window);
+        let column_is_valid = |i: usize| {
+            let mut expected = points[i].clone();
+            for j in 0..self.h {
+                if j > 0 {
+                    for _ in 0..window_width {
+                        expected.dbl_assign();
+                    }
+                }
+
+                if !self.points[j * self.numpoints + i]
+                    .to_proj()
+                    .equals(&expected)
+                {
+                    return false;
+                }
+            }
+
+            true
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            (0..self.numpoints).into_par_iter().all(column_is_valid)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            (0..self.numpoints).all(column_is_valid)
+        }
+    }
+}
+
+const TABLE_MAGIC: [u8; 8] = *b"KZGBGMW\0";
+const TABLE_VERSION: u32 = 1;
+/// magic + version + key + window + number of points + number of rows
+const HEADER_SIZE: usize = 8 + 4 + 32 + 4 * 8 + 8 + 8;
+const CHECKSUM_SIZE: usize = 32;
+/// x and y limbs, followed by point at infinity flag
+const SERIALIZED_POINT_SIZE: usize = 2 * 48 + 1;
+
+#[cfg(feature = "parallel")]
+fn encode_window(window: BgmwWindow) -> [u64; 4] {
+    match window {
+        BgmwWindow::Sync(wnd) => [0, wnd as u64, 0, 0],
+        BgmwWindow::Parallel((nx, ny, wnd)) => [1, nx as u64, ny as u64, wnd as u64],
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn encode_window(window: BgmwWindow) -> [u64; 4] {
+    [0, window as u64, 0, 0]
+}
+
+#[cfg(feature = "parallel")]
+fn decode_window(encoded: [u64; 4]) -> Result<BgmwWindow, Error> {
+    match encoded {
+        [0, wnd, 0, 0] if (1..=NBITS as u64).contains(&wnd) => Ok(BgmwWindow::Sync(wnd as usize)),
+        [1, nx, ny, wnd] if (1..=NBITS as u64).contains(&wnd) && nx > 0 && ny > 0 => Ok(
+            BgmwWindow::Parallel((nx as usize, ny as usize, wnd as usize)),
+        ),
+        _ => Err(Error::BadArgs(
+            "Invalid precomputation table window".to_string(),
+        )),
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn decode_window(encoded: [u64; 4]) -> Result<BgmwWindow, Error> {
+    match encoded {
+        [0, wnd, 0, 0] if (1..=NBITS as u64).contains(&wnd) => Ok(wnd as usize),
+        _ => Err(Error::BadArgs(
+            "Invalid precomputation table window".to_string(),
+        )),
+    }
+}
+
+fn write_point<TG1: G1, TG1Fp: G1Fp, TG1Affine: G1Affine<TG1, TG1Fp>>(
+    out: &mut [u8; SERIALIZED_POINT_SIZE],
+    point: &TG1Affine,
+) {
+    let limbs = point
+        .x()
+        .to_underlying_arr()
+        .into_iter()
+        .chain(point.y().to_underlying_arr());
+    for (i, limb) in limbs.enumerate() {
+        out[i * 8..(i + 1) * 8].copy_from_slice(&limb.to_le_bytes());
+    }
+    out[SERIALIZED_POINT_SIZE - 1] = point.is_infinity() as u8;
+}
+
+fn read_point<TG1: G1, TG1Fp: G1Fp, TG1Affine: G1Affine<TG1, TG1Fp>>(
+    bytes: &[u8],
+    finite: &TG1Affine,
+) -> TG1Affine {
+    if bytes[SERIALIZED_POINT_SIZE - 1] != 0 {
+        return TG1Affine::zero();
+    }
+
+    let mut x = [0u64; 6];
+    let mut y = [0u64; 6];
+    for i in 0..6 {
+        x[i] = read_u64(&bytes[i * 8..(i + 1) * 8]);
+        y[i] = read_u64(&bytes[48 + i * 8..48 + (i + 1) * 8]);
+    }
+
+    let mut point = *finite;
+    *point.x_mut() = TG1Fp::from_underlying_arr(&x);
+    *point.y_mut() = TG1Fp::from_underlying_arr(&y);
+    point
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().unwrap())
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes.try_into().unwrap())
 }
 
 #[allow(clippy::too_many_arguments)]
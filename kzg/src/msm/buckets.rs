@@ -0,0 +1,212 @@
+//! Bucket accumulation for tiling Pippenger.
+//!
+//! Points can be accumulated into buckets either one by one in XYZZ coordinates, or in batches of affine additions,
+//! which share a single field inversion (Montgomery's trick). Affine addition is cheaper than mixed XYZZ addition
+//! once inversion is amortized, so batching pays off for inputs, which are large compared to the number of buckets.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{G1Affine, G1Fp, G1GetFp, G1};
+
+use super::arkmsm::batch_adder::BatchAdder;
+use super::pippenger_utils::{
+    booth_decode, p1_dadd, p1_dadd_affine, p1_to_jacobian, type_is_zero, type_zero, P1XYZZ,
+};
+
+/// Inputs, which have at least this many points per bucket, are accumulated with batched affine additions
+const BATCH_AFFINE_MIN_POINTS_PER_BUCKET: usize = 8;
+/// Batch affine additions are not used for windows narrower than this
+const BATCH_AFFINE_MIN_WINDOW: usize = 10;
+const MIN_BATCH_SIZE: usize = 32;
+const MAX_BATCH_SIZE: usize = 512;
+
+/// How points are added into buckets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BucketAccumulation {
+    /// Each point is added to its bucket in XYZZ coordinates
+    Xyzz,
+    /// Points are added to affine buckets in batches, using single inversion per batch. Points, which hit a bucket
+    /// already present in current batch, are added in XYZZ coordinates.
+    BatchAffine,
+}
+
+impl BucketAccumulation {
+    pub const ALL: [Self; 2] = [Self::Xyzz, Self::BatchAffine];
+
+    /// Faster accumulation for `npoints` points split over windows of `window` bits
+    pub const fn default_for(npoints: usize, window: usize) -> Self {
+        if window >= BATCH_AFFINE_MIN_WINDOW
+            && npoints >= BATCH_AFFINE_MIN_POINTS_PER_BUCKET << (window - 1)
+        {
+            Self::BatchAffine
+        } else {
+            Self::Xyzz
+        }
+    }
+}
+
+/// Bucket storage for one window of tiling Pippenger
+pub enum Buckets<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp>> {
+    Xyzz(Vec<P1XYZZ<TFp>>),
+    BatchAffine(BatchAffineBuckets<TG1, TFp, TG1Affine>),
+}
+
+impl<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp>>
+    Buckets<TG1, TFp, TG1Affine>
+{
+    /// Buckets for windows of at most `window` bits
+    pub fn new(accumulation: BucketAccumulation, window: usize) -> Self {
+        let nbuckets = 1 << (window - 1);
+        match accumulation {
+            BucketAccumulation::Xyzz => Self::Xyzz(vec![P1XYZZ::<TFp>::default(); nbuckets]),
+            BucketAccumulation::BatchAffine => Self::BatchAffine(BatchAffineBuckets::new(nbuckets)),
+        }
+    }
+
+    /// Decode bucket index and move point to corresponding bucket, see [`booth_decode`]
+    #[inline(always)]
+    pub fn booth_decode(&mut self, booth_idx: u64, wbits: usize, p: &TG1Affine) {
+        match self {
+            Self::Xyzz(buckets) => booth_decode(buckets, booth_idx, wbits, p),
+            Self::BatchAffine(buckets) => buckets.booth_decode(booth_idx, wbits, p),
+        }
+    }
+
+    /// Multiply point in each of first `2^wbits` buckets by its index and sum all results. Buckets are cleared
+    /// afterwards.
+    pub fn integrate(&mut self, out: &mut TG1, wbits: usize) {
+        match self {
+            Self::Xyzz(buckets) => p1_integrate_buckets(out, buckets, wbits),
+            Self::BatchAffine(buckets) => buckets.integrate(out, wbits),
+        }
+    }
+}
+
+pub struct BatchAffineBuckets<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp>> {
+    buckets: Vec<TG1Affine>,
+    /// Points, which hit a bucket already present in current batch
+    conflicts: Vec<P1XYZZ<TFp>>,
+    in_batch: Vec<bool>,
+    batch_buckets: Vec<usize>,
+    batch_points: Vec<TG1Affine>,
+    batch_size: usize,
+    adder: BatchAdder<TG1, TFp, TG1Affine>,
+}
+
+impl<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp>>
+    BatchAffineBuckets<TG1, TFp, TG1Affine>
+{
+    pub fn new(nbuckets: usize) -> Self {
+        // Larger batches amortize inversion better, but more points hit buckets already present in the batch
+        let batch_size = (nbuckets / 4).clamp(MIN_BATCH_SIZE, MAX_BATCH_SIZE);
+
+        Self {
+            buckets: vec![TG1Affine::zero(); nbuckets],
+            conflicts: vec![P1XYZZ::<TFp>::default(); nbuckets],
+            in_batch: vec![false; nbuckets],
+            batch_buckets: Vec::with_capacity(batch_size),
+            batch_points: Vec::with_capacity(batch_size),
+            batch_size,
+            adder: BatchAdder::new(batch_size),
+        }
+    }
+
+    #[inline(always)]
+    fn booth_decode(&mut self, mut booth_idx: u64, wbits: usize, p: &TG1Affine) {
+        let booth_sign: bool = ((booth_idx >> wbits) & 1) != 0;
+        booth_idx &= (1 << wbits) - 1;
+        if booth_idx == 0 || p.is_zero() {
+            return;
+        }
+
+        let idx = (booth_idx - 1) as usize;
+        if self.in_batch[idx] {
+            p1_dadd_affine(&mut self.conflicts[idx], p, booth_sign);
+            return;
+        }
+
+        let mut p = *p;
+        if booth_sign {
+            p.y_mut().neg_assign();
+        }
+
+        self.in_batch[idx] = true;
+        self.batch_buckets.push(idx);
+        self.batch_points.push(p);
+        if self.batch_points.len() == self.batch_size {
+            self.flush();
+        }
+    }
+
+    /// Adds all points of current batch into their buckets
+    fn flush(&mut self) {
+        self.adder.reset();
+        for (i, (&idx, p)) in self
+            .batch_buckets
+            .iter()
+            .zip(self.batch_points.iter())
+            .enumerate()
+        {
+            self.adder.batch_add_phase_one(&self.buckets[idx], p, i);
+        }
+        self.adder.inverse();
+        for (i, (&idx, p)) in self
+            .batch_buckets
+            .iter()
+            .zip(self.batch_points.iter())
+            .enumerate()
+            .rev()
+        {
+            self.adder.batch_add_phase_two(&mut self.buckets[idx], p, i);
+            self.in_batch[idx] = false;
+        }
+
+        self.batch_buckets.clear();
+        self.batch_points.clear();
+    }
+
+    fn integrate(&mut self, out: &mut TG1, wbits: usize) {
+        self.flush();
+
+        for (bucket, conflicts) in self.buckets[..1 << wbits]
+            .iter_mut()
+            .zip(self.conflicts.iter_mut())
+        {
+            if !bucket.is_zero() {
+                p1_dadd_affine(conflicts, bucket, false);
+                bucket.set_zero();
+            }
+        }
+
+        p1_integrate_buckets(out, &mut self.conflicts, wbits);
+    }
+}
+
+pub fn p1_integrate_buckets<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp>(
+    out: &mut TG1,
+    buckets: &mut [P1XYZZ<TFp>],
+    wbits: usize,
+) {
+    let mut n = (1usize << wbits) - 1;
+    let mut ret = buckets[n];
+    let mut acc = buckets[n];
+
+    type_zero(&mut buckets[n]);
+    loop {
+        if n == 0 {
+            break;
+        }
+        n -= 1;
+
+        if type_is_zero(&buckets[n]) == 0 {
+            p1_dadd(&mut acc, &buckets[n]);
+            type_zero(&mut buckets[n]);
+        }
+        p1_dadd(&mut ret, &acc);
+    }
+
+    p1_to_jacobian(out, &ret);
+}
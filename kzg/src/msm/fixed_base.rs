@@ -0,0 +1,100 @@
+//! Multi-scalar multiplication over a fixed set of bases.
+//!
+//! Settings objects precompute tables only for their own setup points. [`FixedBaseMsm`] does the same for any
+//! other set of points, which is multiplied repeatedly, such as FK20 columns or Pedersen generators.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::{Error, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, G1};
+
+use super::precompute::{precompute, precompute_with_memory_budget, PrecomputationTable};
+use super::strategy::MsmStrategy;
+
+/// Bases together with their precomputation table
+#[derive(Debug, Clone)]
+pub struct FixedBaseMsm<TFr, TG1, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    points: Vec<TG1>,
+    precomputation: PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>,
+}
+
+impl<TFr, TG1, TG1Fp, TG1Affine> FixedBaseMsm<TFr, TG1, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    pub fn new(points: &[TG1]) -> Result<Self, Error> {
+        Ok(Self {
+            points: points.to_vec(),
+            precomputation: precompute(points)?,
+        })
+    }
+
+    /// Same as [`Self::new`], but precomputed table takes at most `max_bytes` of memory. If no table fits, MSM is
+    /// computed without precomputation.
+    pub fn with_memory_budget(points: &[TG1], max_bytes: usize) -> Result<Self, Error> {
+        Ok(Self {
+            points: points.to_vec(),
+            precomputation: precompute_with_memory_budget(points, max_bytes)?,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[TG1] {
+        &self.points
+    }
+
+    /// Table to pass to [`G1LinComb::g1_lincomb`] together with [`Self::points`]
+    pub fn precomputation(&self) -> &PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine> {
+        &self.precomputation
+    }
+
+    /// Size of precomputed table in bytes, not counting the bases themselves
+    pub fn memory_usage(&self) -> usize {
+        self.precomputation.memory_usage()
+    }
+
+    /// Same as [`PrecomputationTable::set_msm_strategy`]
+    pub fn set_msm_strategy(&self, strategy: MsmStrategy) -> Result<(), Error> {
+        self.precomputation.set_msm_strategy(strategy)
+    }
+
+    /// Computes `scalars[0] * points[0] + ... + scalars[n - 1] * points[n - 1]`. Scalars may be fewer than bases,
+    /// in which case only the first bases are used.
+    pub fn msm(&self, scalars: &[TFr]) -> Result<TG1, Error>
+    where
+        TG1: G1LinComb<TFr, TG1Fp, TG1Affine>,
+    {
+        if scalars.len() > self.points.len() {
+            return Err(Error::InvalidLength(format!(
+                "Expected at most {} scalars, got {}",
+                self.points.len(),
+                scalars.len()
+            )));
+        }
+
+        Ok(TG1::g1_lincomb(
+            &self.points,
+            scalars,
+            scalars.len(),
+            Some(&self.precomputation),
+        ))
+    }
+}
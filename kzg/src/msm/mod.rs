@@ -1,9 +1,11 @@
 pub mod arkmsm;
+pub mod buckets;
 pub mod cell;
+pub mod fixed_base;
 pub mod msm_impls;
 pub mod precompute;
-#[cfg(feature = "parallel")]
-pub mod thread_pool;
+pub mod sparse;
+pub mod strategy;
 #[cfg(feature = "parallel")]
 pub mod tiling_parallel_pippenger;
 pub mod tiling_pippenger_ops;
@@ -15,6 +17,3 @@ mod pippenger_utils;
 
 #[cfg(feature = "bgmw")]
 mod bgmw;
-
-#[cfg(feature = "sppark")]
-mod sppark;
@@ -1,69 +1,89 @@
-use crate::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1ProjAddAffine, G1};
+use crate::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1ProjAddAffine, Scalar256, G1};
 use alloc::vec::Vec;
 
-#[cfg(all(feature = "arkmsm", not(feature = "parallel")))]
+#[cfg(feature = "arkmsm")]
 use super::arkmsm::arkmsm_msm::VariableBaseMSM;
 use super::precompute::PrecomputationTable;
+use super::sparse::SparseScalars;
+use super::strategy::{default_msm_strategy, MsmStrategy};
 
-#[cfg(all(not(feature = "arkmsm"), not(feature = "parallel")))]
+#[cfg(not(feature = "parallel"))]
 use super::tiling_pippenger_ops::tiling_pippenger;
 
 #[cfg(feature = "parallel")]
 use super::tiling_parallel_pippenger::{parallel_affine_conv, tiling_parallel_pippenger};
 
-#[cfg(feature = "parallel")]
-fn msm_parallel<
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+/// Computes MSM with given strategy. Strategies, which were not compiled in or cannot be used with given
+/// precomputation table, fall back to tiling Pippenger.
+pub fn msm_with_strategy<
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
+    TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+    TFr: Fr,
 >(
+    strategy: MsmStrategy,
     points: &[TG1],
     scalars: &[TFr],
     precomputation: Option<&PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>,
 ) -> TG1 {
-    if let Some(precomputation) = precomputation {
-        precomputation.multiply_parallel(scalars)
-    } else {
-        let points = batch_convert::<TG1, TG1Fp, TG1Affine>(points);
-        let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
-        tiling_parallel_pippenger(&points, &scalars)
-    }
+    let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+    msm_with_strategy_scalars::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+        strategy,
+        points,
+        &scalars,
+        precomputation,
+    )
 }
 
-#[cfg(not(feature = "parallel"))]
+/// Same as [`msm_with_strategy`], but for scalars already converted out of Montgomery form
 #[allow(clippy::extra_unused_type_parameters)]
 #[allow(unused_variables)]
-fn msm_sequential<
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+fn msm_with_strategy_scalars<
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
     TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+    TFr: Fr,
 >(
+    strategy: MsmStrategy,
     points: &[TG1],
-    scalars: &[TFr],
+    scalars: &[Scalar256],
     precomputation: Option<&PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>,
 ) -> TG1 {
-    #[cfg(not(feature = "arkmsm"))]
-    {
-        assert!(core::cmp::min(points.len(), scalars.len()) > 1);
-        if let Some(precomputation) = precomputation {
-            precomputation.multiply_sequential(scalars)
-        } else {
+    assert!(core::cmp::min(points.len(), scalars.len()) > 1);
+
+    match strategy {
+        #[cfg(feature = "bgmw")]
+        MsmStrategy::Bgmw
+            if precomputation.is_some_and(|p| p.is_usable(strategy, scalars.len())) =>
+        {
+            let table = precomputation.and_then(PrecomputationTable::bgmw).unwrap();
+
+            #[cfg(feature = "parallel")]
+            return table.multiply_parallel(scalars);
+
+            #[cfg(not(feature = "parallel"))]
+            return table.multiply_sequential(scalars);
+        }
+
+        #[cfg(feature = "arkmsm")]
+        MsmStrategy::ArkMsm => {
             let points = batch_convert::<TG1, TG1Fp, TG1Affine>(points);
-            let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
-            tiling_pippenger(&points, &scalars)
+            VariableBaseMSM::multi_scalar_mul::<TG1, TG1Fp, TG1Affine, TProjAddAffine>(
+                &points, scalars,
+            )
         }
-    }
 
-    #[cfg(feature = "arkmsm")]
-    {
-        let points = batch_convert::<TG1, TG1Fp, TG1Affine>(points);
-        let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
-        VariableBaseMSM::multi_scalar_mul::<TG1, TG1Fp, TG1Affine, TProjAddAffine>(
-            &points, &scalars,
-        )
+        _ => {
+            let points = batch_convert::<TG1, TG1Fp, TG1Affine>(points);
+
+            #[cfg(feature = "parallel")]
+            return tiling_parallel_pippenger(&points, scalars);
+
+            #[cfg(not(feature = "parallel"))]
+            return tiling_pippenger(&points, scalars);
+        }
     }
 }
 
@@ -99,17 +119,36 @@ pub fn msm<
         return out;
     }
 
-    #[cfg(feature = "parallel")]
-    return msm_parallel::<TFr, TG1, TG1Fp, TG1Affine>(
-        &points[0..len],
-        &scalars[0..len],
-        precomputation,
-    );
+    let points = &points[0..len];
+    let scalars = &scalars[0..len];
 
-    #[cfg(not(feature = "parallel"))]
-    return msm_sequential::<TFr, TG1, TG1Fp, TG1Affine, TProjAddAffine>(
-        &points[0..len],
-        &scalars[0..len],
-        precomputation,
-    );
+    // Scalars are converted once, both sparse check and full MSM below work on converted values
+    let converted = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+
+    // Precomputed tables are several times faster than Pippenger, so input must be sparser to skip them
+    let has_fixed_base_table = precomputation.is_some_and(|p| {
+        p.is_usable(MsmStrategy::Bgmw, len) || p.is_usable(MsmStrategy::Accelerator, len)
+    });
+    let speedup = if has_fixed_base_table { 4 } else { 2 };
+    let sparse = SparseScalars::from_scalars(&converted);
+    if sparse.is_cheaper(len, speedup) {
+        return sparse.msm::<TG1, TG1Fp, TG1Affine, TFr>(points, scalars);
+    }
+
+    match precomputation {
+        Some(precomputation) => precomputation.run_msm(len, |strategy| {
+            msm_with_strategy_scalars::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+                strategy,
+                points,
+                &converted,
+                Some(precomputation),
+            )
+        }),
+        None => msm_with_strategy_scalars::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(
+            default_msm_strategy(),
+            points,
+            &converted,
+            None,
+        ),
+    }
 }
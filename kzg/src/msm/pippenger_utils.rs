@@ -2,6 +2,9 @@ use core::mem::size_of;
 
 use crate::{G1Affine, G1Fp, G1GetFp, Scalar256, G1};
 
+/// Bit length of BLS12-381 scalar field modulus
+pub const SCALAR_BITS: usize = 255;
+
 #[repr(C)]
 #[derive(Default, Clone, Copy, Debug)]
 pub struct P1XYZZ<TFp: G1Fp> {
@@ -87,7 +90,7 @@ pub fn p1_to_jacobian<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp>(out: &mut TG1, input: &
     *out.z_mut() = input.zz;
 }
 
-fn p1_dadd_affine<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp>>(
+pub fn p1_dadd_affine<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp>>(
     out: &mut P1XYZZ<TFp>,
     p2: &TG1Affine,
     subtract: bool, // Need to replace this somehow
@@ -309,6 +312,11 @@ pub const fn pippenger_window_size(npoints: usize) -> usize {
     2
 }
 
+/// Same as [`pippenger_window_size`], but for scalars of `nbits` bits. Windows wider than scalars only add buckets.
+pub fn pippenger_window_size_for_bits(npoints: usize, nbits: usize) -> usize {
+    pippenger_window_size(npoints).min(nbits).max(2)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
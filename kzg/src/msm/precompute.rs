@@ -1,67 +1,245 @@
 extern crate alloc;
 
-use alloc::string::String;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 
-use crate::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1};
+#[cfg(feature = "sppark")]
+use core::ffi::c_void;
 
-#[cfg(any(
-    all(feature = "arkmsm", feature = "bgmw"),
-    all(feature = "arkmsm", feature = "sppark"),
-    all(feature = "bgmw", feature = "sppark")
-))]
-compile_error!("incompatible features, please select only one: `arkmsm`, `bgmw` or `sppark`");
+use crate::{Error, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1};
 
 #[cfg(feature = "bgmw")]
-pub type PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine> =
-    super::bgmw::BgmwTable<TFr, TG1, TG1Fp, TG1Affine>;
+use super::bgmw::BgmwTable;
+use super::strategy::{default_msm_strategy, MsmSelector, MsmStrategy};
+
+/// Precomputed data for multiplying fixed set of points, together with MSM strategy selected for them
+#[derive(Debug, Clone)]
+pub struct PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    selector: MsmSelector,
+
+    #[cfg(feature = "bgmw")]
+    bgmw: Option<BgmwTable<TFr, TG1, TG1Fp, TG1Affine>>,
+
+    // Table initialized in C land by external accelerator. It should be never dereferenced
+    #[cfg(feature = "sppark")]
+    accelerator: *mut c_void,
+
+    fr_marker: PhantomData<TFr>,
+    g1_marker: PhantomData<TG1>,
+    g1_fp_marker: PhantomData<TG1Fp>,
+    g1_affine_marker: PhantomData<TG1Affine>,
+}
 
 #[cfg(feature = "sppark")]
-pub type PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine> =
-    super::sppark::SpparkPrecomputation<TFr, TG1, TG1Fp, TG1Affine>;
+unsafe impl<TFr, TG1, TG1Fp, TG1Affine> Sync for PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+}
 
-#[cfg(all(not(feature = "bgmw"), not(feature = "sppark")))]
-#[derive(Debug, Clone)]
-pub struct EmptyTable<TFr, TG1, TG1Fp, TG1Affine>
+#[cfg(feature = "sppark")]
+unsafe impl<TFr, TG1, TG1Fp, TG1Affine> Send for PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>
 where
     TFr: Fr,
     TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 {
-    fr_marker: core::marker::PhantomData<TFr>,
-    g1_marker: core::marker::PhantomData<TG1>,
-    g1_fp_marker: core::marker::PhantomData<TG1Fp>,
-    g1_affine_marker: core::marker::PhantomData<TG1Affine>,
 }
 
-#[cfg(all(not(feature = "bgmw"), not(feature = "sppark")))]
-impl<TFr, TG1, TG1Fp, TG1Affine> EmptyTable<TFr, TG1, TG1Fp, TG1Affine>
+impl<TFr, TG1, TG1Fp, TG1Affine> PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>
 where
     TFr: Fr,
     TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 {
-    fn new(_: &[TG1]) -> Result<Option<Self>, String> {
-        Ok(None)
+    #[allow(unused_variables)]
+    fn new(points: &[TG1]) -> Result<Self, Error> {
+        Ok(Self {
+            #[cfg(feature = "bgmw")]
+            bgmw: BgmwTable::new(points)?,
+            ..Self::empty()
+        })
+    }
+
+    #[allow(unused_variables)]
+    fn with_memory_budget(points: &[TG1], max_bytes: usize) -> Result<Self, Error> {
+        Ok(Self {
+            #[cfg(feature = "bgmw")]
+            bgmw: BgmwTable::new_with_memory_budget(points, max_bytes)?,
+            ..Self::empty()
+        })
     }
 
-    pub fn multiply_sequential(&self, _: &[TFr]) -> TG1 {
-        panic!("This function must not be called")
+    fn empty() -> Self {
+        Self {
+            selector: MsmSelector::new(),
+
+            #[cfg(feature = "bgmw")]
+            bgmw: None,
+
+            #[cfg(feature = "sppark")]
+            accelerator: core::ptr::null_mut(),
+
+            fr_marker: PhantomData,
+            g1_marker: PhantomData,
+            g1_fp_marker: PhantomData,
+            g1_affine_marker: PhantomData,
+        }
     }
 
-    #[cfg(feature = "parallel")]
-    pub fn multiply_parallel(&self, _: &[TFr]) -> TG1 {
-        panic!("This function must not be called")
+    /// Wraps table prepared by external accelerator
+    #[cfg(feature = "sppark")]
+    pub fn from_ptr(table: *mut c_void) -> Self {
+        Self {
+            accelerator: table,
+            ..Self::empty()
+        }
     }
-}
 
-#[cfg(all(not(feature = "bgmw"), not(feature = "sppark")))]
-pub type PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine> = EmptyTable<TFr, TG1, TG1Fp, TG1Affine>;
+    /// Table prepared by external accelerator, if any
+    #[cfg(feature = "sppark")]
+    pub fn accelerator_table(&self) -> Option<*mut c_void> {
+        (!self.accelerator.is_null()).then_some(self.accelerator)
+    }
+
+    #[cfg(feature = "bgmw")]
+    pub(crate) fn bgmw(&self) -> Option<&BgmwTable<TFr, TG1, TG1Fp, TG1Affine>> {
+        self.bgmw.as_ref()
+    }
+
+    /// Size of precomputed points in bytes. Tables prepared by external accelerator are not accounted for.
+    pub fn memory_usage(&self) -> usize {
+        #[cfg(feature = "bgmw")]
+        if let Some(bgmw) = &self.bgmw {
+            return bgmw.memory_usage();
+        }
+
+        0
+    }
+
+    /// Whether `strategy` can compute MSM of first `len` points of this table
+    #[allow(unused_variables)]
+    pub fn is_usable(&self, strategy: MsmStrategy, len: usize) -> bool {
+        match strategy {
+            #[cfg(feature = "bgmw")]
+            MsmStrategy::Bgmw => self
+                .bgmw
+                .as_ref()
+                .is_some_and(|table| len <= table.numpoints()),
+            #[cfg(not(feature = "bgmw"))]
+            MsmStrategy::Bgmw => false,
+            #[cfg(feature = "sppark")]
+            MsmStrategy::Accelerator => !self.accelerator.is_null(),
+            #[cfg(not(feature = "sppark"))]
+            MsmStrategy::Accelerator => false,
+            strategy => strategy.is_compiled(),
+        }
+    }
+
+    /// Strategy used for MSM of `len` elements, unless other strategy was selected
+    pub fn default_strategy(&self, len: usize) -> MsmStrategy {
+        if self.is_usable(MsmStrategy::Accelerator, len) {
+            MsmStrategy::Accelerator
+        } else if self.is_usable(MsmStrategy::Bgmw, len) {
+            MsmStrategy::Bgmw
+        } else {
+            default_msm_strategy()
+        }
+    }
+
+    /// Strategy selected with [`Self::set_msm_strategy`], or `None` when default strategy or auto-tuning is used
+    pub fn msm_strategy(&self) -> Option<MsmStrategy> {
+        self.selector.strategy()
+    }
+
+    /// Always use `strategy`. Fails if support for it was not compiled in, or if this table cannot be used with it.
+    pub fn set_msm_strategy(&self, strategy: MsmStrategy) -> Result<(), Error> {
+        if !self.is_usable(strategy, 0) {
+            return Err(Error::BadArgs(format!(
+                "MSM strategy {:?} is not available",
+                strategy
+            )));
+        }
+
+        self.selector.set_strategy(strategy);
+        Ok(())
+    }
+
+    pub fn use_default_msm_strategy(&self) {
+        self.selector.use_default_strategy();
+    }
 
+    /// Benchmark usable strategies the first time an input of given size is seen, and use the fastest one
+    #[cfg(feature = "std")]
+    pub fn enable_msm_auto_tuning(&self) {
+        self.selector.enable_auto_tuning();
+    }
+
+    /// Strategy, which auto-tuner found fastest for inputs of `len` elements
+    pub fn tuned_msm_strategy(&self, len: usize) -> Option<MsmStrategy> {
+        self.selector.tuned_strategy(len)
+    }
+
+    /// Serialize fixed-base table into binary format, which can be loaded with [`Self::from_bytes`]. Fails if
+    /// there is no such table.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        #[cfg(feature = "bgmw")]
+        if let Some(bgmw) = &self.bgmw {
+            return Ok(bgmw.to_bytes());
+        }
+
+        Err(Error::BadArgs(
+            "Precomputation table has no fixed-base table to serialize".to_string(),
+        ))
+    }
+
+    /// Deserialize table, previously serialized with [`Self::to_bytes`]. Fails if the data is corrupted, or if it
+    /// is not exactly the table, which would be built for `points`.
+    #[allow(unused_variables)]
+    pub fn from_bytes(bytes: &[u8], points: &[TG1]) -> Result<Self, Error> {
+        #[cfg(feature = "bgmw")]
+        {
+            Ok(Self {
+                bgmw: Some(BgmwTable::from_bytes(bytes, points)?),
+                ..Self::empty()
+            })
+        }
+
+        #[cfg(not(feature = "bgmw"))]
+        {
+            Err(Error::FeatureNotEnabled("bgmw"))
+        }
+    }
+
+    /// Computes MSM of first `len` points of this table, by calling `run` with selected strategy
+    pub fn run_msm(&self, len: usize, run: impl FnMut(MsmStrategy) -> TG1) -> TG1 {
+        self.selector.run(
+            len,
+            |strategy| self.is_usable(strategy, len),
+            self.default_strategy(len),
+            run,
+        )
+    }
+}
+
+/// Builds precomputation table for `points`. Table is built even if no fixed-base table is compiled in or fits, as
+/// it also holds MSM strategy selected for the points - see [`PrecomputationTable::is_usable`] for what it supports.
 pub fn precompute<TFr, TG1, TG1Fp, TG1Affine>(
     points: &[TG1],
-) -> Result<Option<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>, String>
+) -> Result<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>, Error>
 where
     TFr: Fr,
     TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
@@ -70,3 +248,100 @@ where
 {
     PrecomputationTable::<TFr, TG1, TG1Fp, TG1Affine>::new(points)
 }
+
+/// Same as [`precompute`], but fixed-base tables are shrunk to take at most `max_bytes` of memory, or skipped
+/// altogether if they cannot fit
+pub fn precompute_with_memory_budget<TFr, TG1, TG1Fp, TG1Affine>(
+    points: &[TG1],
+    max_bytes: usize,
+) -> Result<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>, Error>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    PrecomputationTable::<TFr, TG1, TG1Fp, TG1Affine>::with_memory_budget(points, max_bytes)
+}
+
+/// Environment variable, which opts trusted setup loaders into caching precomputation tables in the given directory
+#[cfg(feature = "std")]
+pub const PRECOMPUTATION_DIR_ENV: &str = "RUST_KZG_PRECOMPUTATION_DIR";
+
+/// Same as [`precompute`], but reuses table cached in `dir` if one was already built for the same points, and caches
+/// newly built table there otherwise. Only tables, which match `points` exactly, are loaded, but `dir` should still
+/// not be writable by other users. Failing to read or write the cache is not an error - table is then recomputed.
+#[cfg(feature = "std")]
+#[allow(unused_variables)]
+pub fn precompute_with_cache<TFr, TG1, TG1Fp, TG1Affine>(
+    points: &[TG1],
+    dir: &std::path::Path,
+) -> Result<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>, Error>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    #[cfg(feature = "bgmw")]
+    {
+        use core::fmt::Write;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static TMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let key = BgmwTable::<TFr, TG1, TG1Fp, TG1Affine>::cache_key(points);
+        let hex_key = key.iter().fold(String::new(), |mut out, b| {
+            let _ = write!(out, "{:02x}", b);
+            out
+        });
+        let path = dir.join(format!("rust-kzg-precomputation-{}.bin", hex_key));
+
+        if let Ok(bytes) = std::fs::read(&path) {
+            if let Ok(table) = PrecomputationTable::from_bytes(&bytes, points) {
+                return Ok(table);
+            }
+        }
+
+        let table = precompute(points)?;
+        if let Ok(bytes) = table.to_bytes() {
+            // Write to temporary file first, so concurrent loaders never observe partially written table
+            let tmp_path = dir.join(format!(
+                "rust-kzg-precomputation-{}.{}.{}.tmp",
+                hex_key,
+                std::process::id(),
+                TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            if std::fs::write(&tmp_path, bytes).is_err()
+                || std::fs::rename(&tmp_path, &path).is_err()
+            {
+                let _ = std::fs::remove_file(&tmp_path);
+            }
+        }
+
+        Ok(table)
+    }
+
+    #[cfg(not(feature = "bgmw"))]
+    {
+        precompute(points)
+    }
+}
+
+/// Uses [`precompute_with_cache`] if [`PRECOMPUTATION_DIR_ENV`] names the cache directory, or [`precompute`]
+/// otherwise. Nothing is written to disk unless caching is enabled this way.
+#[cfg(feature = "std")]
+pub fn precompute_with_env_cache<TFr, TG1, TG1Fp, TG1Affine>(
+    points: &[TG1],
+) -> Result<PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>, Error>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    match std::env::var_os(PRECOMPUTATION_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => precompute_with_cache(points, std::path::Path::new(&dir)),
+        _ => precompute(points),
+    }
+}
@@ -0,0 +1,130 @@
+//! Fast paths for sparse and small scalars.
+//!
+//! Blobs are often padded with zeros or hold small values, while full MSM spends the same time on every scalar. Here
+//! zero scalars are dropped, points multiplied by one are added directly, and Pippenger over remaining scalars skips
+//! windows above their bit length, so cost is proportional to the content of the input.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, Scalar256, G1};
+
+use super::msm_impls::batch_convert;
+use super::pippenger_utils::SCALAR_BITS;
+
+#[cfg(not(feature = "parallel"))]
+use super::tiling_pippenger_ops::tiling_pippenger_with_bits;
+
+#[cfg(feature = "parallel")]
+use super::tiling_parallel_pippenger::tiling_parallel_pippenger_with_bits;
+
+/// Adding a point multiplied by one costs about as much as Pippenger spends on this many bits of a scalar
+const ONE_SCALAR_BITS: usize = 8;
+
+/// Below this many scalars, points are multiplied one by one
+const MIN_PIPPENGER_POINTS: usize = 8;
+
+/// Scalars of MSM with zeros and ones filtered out
+pub struct SparseScalars {
+    /// Indices of points multiplied by one
+    ones: Vec<usize>,
+    /// Indices of points multiplied by other non-zero scalars
+    indices: Vec<usize>,
+    scalars: Vec<Scalar256>,
+    /// Bit length of the largest scalar in `scalars`
+    nbits: usize,
+}
+
+impl SparseScalars {
+    pub fn new<TFr: Fr>(scalars: &[TFr]) -> Self {
+        let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+        Self::from_scalars(&scalars)
+    }
+
+    /// Same as [`Self::new`], but for scalars already converted out of Montgomery form
+    pub fn from_scalars(scalars: &[Scalar256]) -> Self {
+        let mut ones = Vec::new();
+        let mut indices = Vec::new();
+        let mut filtered = Vec::new();
+        let mut nbits = 0;
+
+        for (i, scalar) in scalars.iter().enumerate() {
+            if scalar.is_zero() {
+                continue;
+            }
+
+            if scalar.is_one() {
+                ones.push(i);
+            } else {
+                nbits = core::cmp::max(nbits, scalar.num_bits());
+                indices.push(i);
+                filtered.push(*scalar);
+            }
+        }
+
+        Self {
+            ones,
+            indices,
+            scalars: filtered,
+            nbits,
+        }
+    }
+
+    /// Number of scalars, which are neither zero nor one
+    pub fn len(&self) -> usize {
+        self.scalars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scalars.is_empty() && self.ones.is_empty()
+    }
+
+    /// Bit length of the largest scalar, which is not one
+    pub fn num_bits(&self) -> usize {
+        self.nbits
+    }
+
+    /// Whether MSM over filtered scalars is at least `speedup` times cheaper than full MSM of `len` scalars
+    pub fn is_cheaper(&self, len: usize, speedup: usize) -> bool {
+        let cost = self.scalars.len() * self.nbits + self.ones.len() * ONE_SCALAR_BITS;
+        cost * speedup <= len * SCALAR_BITS
+    }
+
+    /// Computes MSM of `points` and original scalars, which were filtered into `self`
+    pub fn msm<TG1, TG1Fp, TG1Affine, TFr>(&self, points: &[TG1], scalars: &[TFr]) -> TG1
+    where
+        TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+        TG1Fp: G1Fp,
+        TG1Affine: G1Affine<TG1, TG1Fp>,
+        TFr: Fr,
+    {
+        let mut out = TG1::identity();
+        for &i in &self.ones {
+            out.add_or_dbl_assign(&points[i]);
+        }
+
+        if self.indices.len() < MIN_PIPPENGER_POINTS {
+            for &i in &self.indices {
+                out.add_or_dbl_assign(&points[i].mul(&scalars[i]));
+            }
+            return out;
+        }
+
+        let filtered = self
+            .indices
+            .iter()
+            .map(|&i| points[i].clone())
+            .collect::<Vec<_>>();
+        let filtered = batch_convert::<TG1, TG1Fp, TG1Affine>(&filtered);
+
+        #[cfg(feature = "parallel")]
+        let result = tiling_parallel_pippenger_with_bits(&filtered, &self.scalars, self.nbits);
+
+        #[cfg(not(feature = "parallel"))]
+        let result = tiling_pippenger_with_bits(&filtered, &self.scalars, self.nbits);
+
+        out.add_or_dbl_assign(&result);
+        out
+    }
+}
@@ -0,0 +1,190 @@
+//! Runtime selection of multi-scalar multiplication algorithm.
+//!
+//! Every algorithm, which was compiled in, can be selected per settings object. Alternatively, the auto-tuner
+//! benchmarks all usable algorithms the first time an input of given size is seen, and remembers the fastest one.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Multi-scalar multiplication algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum MsmStrategy {
+    /// Tiling Pippenger, always available
+    TilingPippenger = 0,
+    /// Batch-affine Pippenger with GLV decomposition, requires `arkmsm` feature
+    ArkMsm = 1,
+    /// Pippenger over BGMW fixed-base precomputation table, requires `bgmw` feature and a table built for the
+    /// multiplied points
+    Bgmw = 2,
+    /// External accelerator, such as sppark, requires `sppark` feature and backend support
+    Accelerator = 3,
+}
+
+impl MsmStrategy {
+    pub const ALL: [Self; 4] = [
+        Self::TilingPippenger,
+        Self::ArkMsm,
+        Self::Bgmw,
+        Self::Accelerator,
+    ];
+
+    /// Whether support for this strategy was compiled in
+    pub const fn is_compiled(self) -> bool {
+        match self {
+            Self::TilingPippenger => true,
+            Self::ArkMsm => cfg!(feature = "arkmsm"),
+            Self::Bgmw => cfg!(feature = "bgmw"),
+            Self::Accelerator => cfg!(feature = "sppark"),
+        }
+    }
+
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::TilingPippenger),
+            1 => Some(Self::ArkMsm),
+            2 => Some(Self::Bgmw),
+            3 => Some(Self::Accelerator),
+            _ => None,
+        }
+    }
+}
+
+/// Strategy used for inputs without precomputation table
+pub const fn default_msm_strategy() -> MsmStrategy {
+    if cfg!(all(feature = "arkmsm", not(feature = "parallel"))) {
+        MsmStrategy::ArkMsm
+    } else {
+        MsmStrategy::TilingPippenger
+    }
+}
+
+const MODE_DEFAULT: u8 = 0xfe;
+const MODE_AUTO_TUNE: u8 = 0xff;
+const NOT_TUNED: u8 = 0xff;
+/// Inputs are grouped by bit length of their size for auto-tuning
+const SIZE_CLASSES: usize = usize::BITS as usize + 1;
+
+/// Choice of [`MsmStrategy`] - either fixed, default, or auto-tuned for each input size. Can be changed through
+/// shared reference, as settings objects are usually shared between threads.
+#[derive(Debug)]
+pub struct MsmSelector {
+    mode: AtomicU8,
+    tuned: [AtomicU8; SIZE_CLASSES],
+}
+
+impl MsmSelector {
+    pub fn new() -> Self {
+        Self {
+            mode: AtomicU8::new(MODE_DEFAULT),
+            tuned: core::array::from_fn(|_| AtomicU8::new(NOT_TUNED)),
+        }
+    }
+
+    /// Fixed strategy, or `None` when default strategy or auto-tuning is used
+    pub fn strategy(&self) -> Option<MsmStrategy> {
+        MsmStrategy::from_u8(self.mode.load(Ordering::Relaxed))
+    }
+
+    pub fn set_strategy(&self, strategy: MsmStrategy) {
+        self.mode.store(strategy as u8, Ordering::Relaxed);
+    }
+
+    pub fn use_default_strategy(&self) {
+        self.mode.store(MODE_DEFAULT, Ordering::Relaxed);
+    }
+
+    /// Benchmark usable strategies on first use for each input size. Previously tuned results are discarded.
+    #[cfg(feature = "std")]
+    pub fn enable_auto_tuning(&self) {
+        for tuned in self.tuned.iter() {
+            tuned.store(NOT_TUNED, Ordering::Relaxed);
+        }
+        self.mode.store(MODE_AUTO_TUNE, Ordering::Relaxed);
+    }
+
+    pub fn is_auto_tuning(&self) -> bool {
+        self.mode.load(Ordering::Relaxed) == MODE_AUTO_TUNE
+    }
+
+    /// Strategy, which auto-tuner found fastest for inputs of `len` elements
+    pub fn tuned_strategy(&self, len: usize) -> Option<MsmStrategy> {
+        MsmStrategy::from_u8(self.tuned[size_class(len)].load(Ordering::Relaxed))
+    }
+
+    /// Computes MSM of `len` elements by calling `run` with selected strategy. Fixed strategies, for which
+    /// `is_usable` returns false, are replaced with `default`.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    pub fn run<T>(
+        &self,
+        len: usize,
+        is_usable: impl Fn(MsmStrategy) -> bool,
+        default: MsmStrategy,
+        mut run: impl FnMut(MsmStrategy) -> T,
+    ) -> T {
+        match self.mode.load(Ordering::Relaxed) {
+            #[cfg(feature = "std")]
+            MODE_AUTO_TUNE => self.run_tuned(len, is_usable, default, run),
+            mode => match MsmStrategy::from_u8(mode) {
+                Some(strategy) if is_usable(strategy) => run(strategy),
+                _ => run(default),
+            },
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn run_tuned<T>(
+        &self,
+        len: usize,
+        is_usable: impl Fn(MsmStrategy) -> bool,
+        default: MsmStrategy,
+        mut run: impl FnMut(MsmStrategy) -> T,
+    ) -> T {
+        let tuned = &self.tuned[size_class(len)];
+        if let Some(strategy) = MsmStrategy::from_u8(tuned.load(Ordering::Relaxed)) {
+            if is_usable(strategy) {
+                return run(strategy);
+            }
+        }
+
+        // All strategies compute the same point, so result of the fastest one is returned
+        let mut fastest = None;
+        for strategy in MsmStrategy::ALL {
+            if strategy != default && !is_usable(strategy) {
+                continue;
+            }
+
+            let start = std::time::Instant::now();
+            let result = run(strategy);
+            let elapsed = start.elapsed();
+            if fastest
+                .as_ref()
+                .map_or(true, |(fastest_elapsed, _, _)| elapsed < *fastest_elapsed)
+            {
+                fastest = Some((elapsed, strategy, result));
+            }
+        }
+
+        let (_, strategy, result) = fastest.expect("default strategy is always run");
+        tuned.store(strategy as u8, Ordering::Relaxed);
+        result
+    }
+}
+
+impl Default for MsmSelector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for MsmSelector {
+    fn clone(&self) -> Self {
+        Self {
+            mode: AtomicU8::new(self.mode.load(Ordering::Relaxed)),
+            tuned: core::array::from_fn(|i| AtomicU8::new(self.tuned[i].load(Ordering::Relaxed))),
+        }
+    }
+}
+
+fn size_class(len: usize) -> usize {
+    (usize::BITS - len.leading_zeros()) as usize
+}
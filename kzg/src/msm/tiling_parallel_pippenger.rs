@@ -3,19 +3,25 @@ use core::{
     sync::atomic::{AtomicUsize, Ordering},
 };
 
-use alloc::sync::Arc;
-use std::sync::{mpsc::channel, Barrier};
+use std::sync::mpsc::channel;
+
+use rayon::prelude::*;
 
 use crate::{G1Affine, G1Fp, G1GetFp, Scalar256, G1};
 
 use super::{
+    buckets::{BucketAccumulation, Buckets},
     cell::Cell,
     parallel_pippenger_utils::breakdown,
-    pippenger_utils::{pippenger_window_size, P1XYZZ},
-    thread_pool::{da_pool, ThreadPoolExt},
-    tiling_pippenger_ops::{p1s_tile_pippenger_pub, tiling_pippenger},
+    pippenger_utils::{pippenger_window_size, SCALAR_BITS},
+    tiling_pippenger_ops::{
+        p1s_tile_pippenger_pub, tiling_pippenger_with_accumulation, tiling_pippenger_with_bits,
+    },
 };
 
+/// Points are not split between threads into chunks smaller than this
+const MIN_CHUNK_POINTS: usize = 128;
+
 struct Tile {
     x: usize,
     dx: usize,
@@ -27,8 +33,7 @@ pub fn parallel_affine_conv<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp> +
     points: &[TG1],
 ) -> Vec<TG1Affine> {
     let npoints = points.len();
-    let pool = da_pool();
-    let ncpus = pool.max_count();
+    let ncpus = rayon::current_num_threads();
     if ncpus < 2 || npoints < 768 {
         return TG1Affine::into_affines(points);
     }
@@ -41,53 +46,111 @@ pub fn parallel_affine_conv<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp> +
 
     let mut nslices = (npoints + 511) / 512;
     nslices = core::cmp::min(nslices, ncpus);
-    let wg = Arc::new((Barrier::new(2), AtomicUsize::new(nslices)));
 
     let (mut delta, mut rem) = (npoints / nslices + 1, Wrapping(npoints % nslices));
-    let mut x = 0usize;
-    while x < npoints {
-        delta -= (rem == Wrapping(0)) as usize;
-        rem -= Wrapping(1);
+    let mut out = &mut ret[..];
+    let mut inp = points;
+    rayon::scope(|scope| {
+        while !inp.is_empty() {
+            delta -= (rem == Wrapping(0)) as usize;
+            rem -= Wrapping(1);
+
+            let (out_slice, out_rest) = out.split_at_mut(delta);
+            let (inp_slice, inp_rest) = inp.split_at(delta);
+            out = out_rest;
+            inp = inp_rest;
+
+            scope.spawn(move |_| TG1Affine::into_affines_loc(out_slice, inp_slice));
+        }
+    });
 
-        let out = &mut ret[x..x + delta];
-        let inp = &points[x..x + delta];
+    ret
+}
 
-        x += delta;
+pub fn tiling_parallel_pippenger<
+    TG1: G1 + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[Scalar256],
+) -> TG1 {
+    tiling_parallel_pippenger_impl(points, scalars, None)
+}
 
-        let wg = wg.clone();
-        pool.joined_execute(move || {
-            TG1Affine::into_affines_loc(out, inp);
-            if wg.1.fetch_sub(1, Ordering::AcqRel) == 1 {
-                wg.0.wait();
-            }
-        });
+pub fn tiling_parallel_pippenger_with_accumulation<
+    TG1: G1 + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[Scalar256],
+    accumulation: BucketAccumulation,
+) -> TG1 {
+    tiling_parallel_pippenger_impl(points, scalars, Some(accumulation))
+}
+
+/// Same as [`tiling_parallel_pippenger`], but all scalars must be less than `2^nbits`. Such scalars have only a few
+/// windows, so points are split between threads instead of windows.
+pub fn tiling_parallel_pippenger_with_bits<
+    TG1: G1 + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[Scalar256],
+    nbits: usize,
+) -> TG1 {
+    if nbits >= SCALAR_BITS {
+        return tiling_parallel_pippenger(points, scalars);
     }
-    wg.0.wait();
 
-    ret
+    let npoints = core::cmp::min(points.len(), scalars.len());
+    let nchunks = core::cmp::min(rayon::current_num_threads(), npoints / MIN_CHUNK_POINTS);
+    if nchunks < 2 {
+        return tiling_pippenger_with_bits(&points[..npoints], &scalars[..npoints], nbits);
+    }
+
+    (0..nchunks)
+        .into_par_iter()
+        .map(|i| {
+            let range = (i * npoints / nchunks)..((i + 1) * npoints / nchunks);
+            tiling_pippenger_with_bits::<TG1, TG1Fp, TG1Affine>(
+                &points[range.clone()],
+                &scalars[range],
+                nbits,
+            )
+        })
+        .reduce(TG1::identity, |a, b| a.add_or_dbl(&b))
 }
 
-pub fn tiling_parallel_pippenger<
+/// When `accumulation` is `None`, it is chosen by the number of points in a single tile
+fn tiling_parallel_pippenger_impl<
     TG1: G1 + G1GetFp<TG1Fp>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
     mut points: &[TG1Affine],
     scalars: &[Scalar256],
+    accumulation: Option<BucketAccumulation>,
 ) -> TG1 {
     if scalars.len() < points.len() {
         points = &points[0..scalars.len()];
     }
     let npoints = points.len();
 
-    let pool = da_pool();
-    let ncpus = pool.max_count();
+    let ncpus = rayon::current_num_threads();
 
     if ncpus < 2 || npoints < 32 {
-        return tiling_pippenger(points, scalars);
+        let accumulation = accumulation.unwrap_or_else(|| {
+            BucketAccumulation::default_for(npoints, pippenger_window_size(npoints))
+        });
+        return tiling_pippenger_with_accumulation(points, scalars, SCALAR_BITS, accumulation);
     }
 
     let (nx, ny, window) = breakdown(pippenger_window_size(npoints), ncpus);
+    let accumulation =
+        accumulation.unwrap_or_else(|| BucketAccumulation::default_for(npoints / nx, window));
 
     // |grid[]| holds "coordinates" and place for result
     let mut grid: Vec<(Tile, Cell<TG1>)> = Vec::with_capacity(nx * ny);
@@ -123,41 +186,41 @@ pub fn tiling_parallel_pippenger<
 
     let mut row_sync: Vec<AtomicUsize> = Vec::with_capacity(ny);
     row_sync.resize_with(ny, Default::default);
-    let row_sync = Arc::new(row_sync);
-    let counter = Arc::new(AtomicUsize::new(0));
+    let row_sync = &row_sync[..];
+    let counter = &AtomicUsize::new(0);
     let (tx, rx) = channel();
     let n_workers = core::cmp::min(ncpus, total);
-    for _ in 0..n_workers {
-        let tx = tx.clone();
-        let counter = counter.clone();
-        let row_sync = row_sync.clone();
-
-        pool.joined_execute(move || {
-            let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
-            loop {
-                let work = counter.fetch_add(1, Ordering::Relaxed);
-                if work >= total {
-                    break;
-                }
-
-                let x = grid[work].0.x;
-                let y = grid[work].0.y;
-                let dx = grid[work].0.dx;
-
-                p1s_tile_pippenger_pub(
-                    grid[work].1.as_mut(),
-                    &points[x..(x + dx)],
-                    &scalars[x..],
-                    &mut buckets,
-                    y,
-                    window,
-                );
-                if row_sync[y / window].fetch_add(1, Ordering::AcqRel) == nx - 1 {
-                    tx.send(y).expect("disaster");
+    rayon::scope(|scope| {
+        for _ in 0..n_workers {
+            let tx = tx.clone();
+
+            scope.spawn(move |_| {
+                let mut buckets = Buckets::new(accumulation, window);
+                loop {
+                    let work = counter.fetch_add(1, Ordering::Relaxed);
+                    if work >= total {
+                        break;
+                    }
+
+                    let x = grid[work].0.x;
+                    let y = grid[work].0.y;
+                    let dx = grid[work].0.dx;
+
+                    p1s_tile_pippenger_pub(
+                        grid[work].1.as_mut(),
+                        &points[x..(x + dx)],
+                        &scalars[x..],
+                        &mut buckets,
+                        y,
+                        window,
+                    );
+                    if row_sync[y / window].fetch_add(1, Ordering::AcqRel) == nx - 1 {
+                        tx.send(y).expect("disaster");
+                    }
                 }
-            }
-        });
-    }
+            });
+        }
+    });
 
     let mut ret = <TG1>::default();
     let mut rows = vec![false; ny];
@@ -1,50 +1,21 @@
 use crate::{G1Affine, G1Fp, G1GetFp, Scalar256, G1};
 
-use alloc::vec;
-
+use super::buckets::{BucketAccumulation, Buckets};
 use super::pippenger_utils::{
-    booth_decode, booth_encode, get_wval_limb, is_zero, p1_dadd, p1_to_jacobian,
-    pippenger_window_size, type_is_zero, type_zero, P1XYZZ,
+    booth_encode, get_wval_limb, is_zero, pippenger_window_size_for_bits, SCALAR_BITS,
 };
 
-fn p1_integrate_buckets<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp>(
-    out: &mut TG1,
-    buckets: &mut [P1XYZZ<TFp>],
-    wbits: usize,
-) {
-    let mut n = (1usize << wbits) - 1;
-    let mut ret = buckets[n];
-    let mut acc = buckets[n];
-
-    type_zero(&mut buckets[n]);
-    loop {
-        if n == 0 {
-            break;
-        }
-        n -= 1;
-
-        if type_is_zero(&buckets[n]) == 0 {
-            p1_dadd(&mut acc, &buckets[n]);
-            type_zero(&mut buckets[n]);
-        }
-        p1_dadd(&mut ret, &acc);
-    }
-
-    p1_to_jacobian(out, &ret);
-}
-
 #[allow(clippy::too_many_arguments)]
 pub fn p1s_tile_pippenger_pub<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp>>(
     ret: &mut TG1,
     points: &[TG1Affine],
     scalars: &[Scalar256],
-    buckets: &mut [P1XYZZ<TFp>],
+    buckets: &mut Buckets<TG1, TFp, TG1Affine>,
     bit0: usize,
     window: usize,
 ) {
-    const NBITS: usize = 255;
-    let (wbits, cbits) = if bit0 + window > NBITS {
-        let wbits = NBITS - bit0;
+    let (wbits, cbits) = if bit0 + window > SCALAR_BITS {
+        let wbits = SCALAR_BITS - bit0;
         (wbits, wbits + 1)
     } else {
         (window, window)
@@ -58,7 +29,7 @@ pub fn p1s_tile_pippenger<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine
     ret: &mut TG1,
     points: &[TG1Affine],
     scalars: &[Scalar256],
-    buckets: &mut [P1XYZZ<TFp>],
+    buckets: &mut Buckets<TG1, TFp, TG1Affine>,
     bit0: usize,
     wbits: usize,
     cbits: usize,
@@ -97,7 +68,7 @@ pub fn p1s_tile_pippenger<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine
     let mut wnxt = booth_encode(wnxt, cbits);
 
     // Move first point to corresponding bucket
-    booth_decode(buckets, wval, cbits, point);
+    buckets.booth_decode(wval, cbits, point);
 
     // Last point will be calculated separately, so decrementing point count
     let npoints = points.len() - 1;
@@ -122,26 +93,59 @@ pub fn p1s_tile_pippenger<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine
 
         // Move point to corresponding bucket (add or subtract from bucket)
         // `wval` contains encoded bucket index, as well as sign, which shows if point should be subtracted or added to bucket
-        booth_decode(buckets, wval, cbits, point);
+        buckets.booth_decode(wval, cbits, point);
     }
     // Get last point
     let point = &points[npoints];
     // Move point to bucket
-    booth_decode(buckets, wnxt, cbits, point);
+    buckets.booth_decode(wnxt, cbits, point);
     // Integrate buckets - multiply point in each bucket by scalar and sum all results
-    p1_integrate_buckets(ret, buckets, cbits - 1);
+    buckets.integrate(ret, cbits - 1);
 }
 
 pub fn tiling_pippenger<TG1: G1 + G1GetFp<TG1Fp>, TG1Fp: G1Fp, TG1Affine: G1Affine<TG1, TG1Fp>>(
     points: &[TG1Affine],
     scalars: &[Scalar256],
 ) -> TG1 {
-    let window = pippenger_window_size(points.len());
-    let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
+    tiling_pippenger_with_bits(points, scalars, SCALAR_BITS)
+}
+
+/// Same as [`tiling_pippenger`], but all scalars must be less than `2^nbits`, so that windows above `nbits` are
+/// skipped
+pub fn tiling_pippenger_with_bits<
+    TG1: G1 + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[Scalar256],
+    nbits: usize,
+) -> TG1 {
+    let window = pippenger_window_size_for_bits(points.len(), nbits);
+    tiling_pippenger_with_accumulation(
+        points,
+        scalars,
+        nbits,
+        BucketAccumulation::default_for(points.len(), window),
+    )
+}
+
+pub fn tiling_pippenger_with_accumulation<
+    TG1: G1 + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[Scalar256],
+    nbits: usize,
+    accumulation: BucketAccumulation,
+) -> TG1 {
+    let window = pippenger_window_size_for_bits(points.len(), nbits);
+    let mut buckets = Buckets::new(accumulation, window);
 
-    let mut wbits: usize = 255 % window;
+    let mut wbits: usize = nbits % window;
     let mut cbits: usize = wbits + 1;
-    let mut bit0: usize = 255;
+    let mut bit0: usize = nbits;
     let mut tile = TG1::default();
 
     let mut ret = TG1::default();
@@ -0,0 +1,98 @@
+//! Thread pool, which parallel code paths run on.
+//!
+//! With `parallel` feature enabled, FFTs, multi-scalar multiplications and batch verification are split into
+//! rayon tasks. Every settings object carries a [`ThreadPoolConfig`], and functions, which take settings, run
+//! their work on the configured pool. By default, the global rayon pool is used. Functions without settings, such
+//! as plain FFTs, can be run on the configured pool with [`ThreadPoolConfig::install`].
+
+extern crate alloc;
+
+#[cfg(feature = "parallel")]
+use alloc::{format, sync::Arc};
+
+use crate::Error;
+
+#[derive(Debug, Clone, Default)]
+pub struct ThreadPoolConfig {
+    #[cfg(feature = "parallel")]
+    pool: Option<Arc<rayon::ThreadPool>>,
+}
+
+/// Configuration, which uses the global rayon pool
+pub static GLOBAL_THREAD_POOL: ThreadPoolConfig = ThreadPoolConfig::global();
+
+impl ThreadPoolConfig {
+    /// Use the global rayon pool
+    pub const fn global() -> Self {
+        Self {
+            #[cfg(feature = "parallel")]
+            pool: None,
+        }
+    }
+
+    /// Use pool owned by the caller
+    #[cfg(feature = "parallel")]
+    pub fn from_pool(pool: Arc<rayon::ThreadPool>) -> Self {
+        Self { pool: Some(pool) }
+    }
+
+    /// Use a new pool of `num_threads` threads. Zero means the global pool. Without `parallel` feature, work is
+    /// always done on the calling thread, so only zero and one are accepted.
+    pub fn with_num_threads(num_threads: usize) -> Result<Self, Error> {
+        if num_threads == 0 {
+            return Ok(Self::global());
+        }
+
+        #[cfg(feature = "parallel")]
+        {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .map_err(|err| {
+                    Error::ThreadPool(format!("Failed to build thread pool: {}", err))
+                })?;
+
+            Ok(Self::from_pool(Arc::new(pool)))
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            if num_threads == 1 {
+                Ok(Self::global())
+            } else {
+                Err(Error::FeatureNotEnabled("parallel"))
+            }
+        }
+    }
+
+    /// Number of threads, which work is split between
+    pub fn num_threads(&self) -> usize {
+        #[cfg(feature = "parallel")]
+        {
+            match &self.pool {
+                Some(pool) => pool.current_num_threads(),
+                None => rayon::current_num_threads(),
+            }
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            1
+        }
+    }
+
+    /// Runs `op` so that parallel code paths called from it use this pool
+    #[cfg(feature = "parallel")]
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        match &self.pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
+    /// Runs `op` so that parallel code paths called from it use this pool
+    #[cfg(not(feature = "parallel"))]
+    pub fn install<R>(&self, op: impl FnOnce() -> R) -> R {
+        op()
+    }
+}
@@ -0,0 +1,411 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use blst::{
+    blst_p1_affine, blst_p1_affine_compress, blst_p1_affine_is_inf, blst_p1_affine_serialize,
+    blst_p1_deserialize, blst_p1_uncompress, blst_p2_affine, blst_p2_affine_compress,
+    blst_p2_affine_serialize, blst_p2_deserialize, blst_p2_uncompress, BLST_ERROR,
+};
+
+use crate::eip_4844::{
+    is_valid_blob_width, load_trusted_setup_string, BYTES_PER_G1, BYTES_PER_G2,
+    TRUSTED_SETUP_NUM_G2_POINTS,
+};
+use crate::{Error, G1Fp, G1GetFp, G1, G2};
+
+/// Magic bytes, which start every trusted setup in binary format
+pub const TRUSTED_SETUP_BINARY_MAGIC: [u8; 8] = *b"KZGSETUP";
+pub const TRUSTED_SETUP_BINARY_VERSION: u32 = 1;
+
+const BINARY_HEADER_SIZE: usize = 8 + 4 + 4 + 8 + 8;
+const BINARY_FLAG_UNCOMPRESSED: u32 = 1;
+const BYTES_PER_G1_UNCOMPRESSED: usize = 2 * BYTES_PER_G1;
+const BYTES_PER_G2_UNCOMPRESSED: usize = 2 * BYTES_PER_G2;
+
+/// Mainnet trusted setup, pre-parsed into uncompressed binary format
+#[cfg(feature = "embedded-mainnet-setup")]
+pub const MAINNET_TRUSTED_SETUP: &[u8] = include_bytes!("trusted_setup_mainnet.bin");
+
+const JSON_G1_KEY: &str = "g1_lagrange";
+const JSON_G2_KEY: &str = "g2_monomial";
+
+/// Supported trusted setup file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustedSetupFormat {
+    /// c-kzg text format: point counts, followed by hex-encoded points
+    Text,
+    /// Ethereum KZG ceremony JSON output, with `g1_lagrange` and `g2_monomial` arrays
+    Json,
+    /// Point counts, followed by compressed points
+    Binary,
+    /// Point counts, followed by uncompressed points. Loading it skips subgroup checks of G1 points, so it must
+    /// only be used for trusted local files
+    BinaryUncompressed,
+}
+
+impl TrustedSetupFormat {
+    /// Guess format of trusted setup file from its contents
+    pub fn detect(contents: &[u8]) -> Self {
+        if contents.starts_with(&TRUSTED_SETUP_BINARY_MAGIC) {
+            let flags = contents
+                .get(12..16)
+                .map(|flags| u32::from_le_bytes(flags.try_into().unwrap()))
+                .unwrap_or(0);
+
+            if flags & BINARY_FLAG_UNCOMPRESSED != 0 {
+                Self::BinaryUncompressed
+            } else {
+                Self::Binary
+            }
+        } else if contents.trim_ascii_start().starts_with(b"{") {
+            Self::Json
+        } else {
+            Self::Text
+        }
+    }
+}
+
+/// Parse trusted setup in ceremony JSON format. Returns compressed G1 and G2 points, same as
+/// [`load_trusted_setup_string`].
+pub fn load_trusted_setup_json(contents: &str) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let g1_bytes = scan_json_points(contents, JSON_G1_KEY, BYTES_PER_G1)?;
+    let g2_bytes = scan_json_points(contents, JSON_G2_KEY, BYTES_PER_G2)?;
+
+    if !is_valid_blob_width(g1_bytes.len() / BYTES_PER_G1) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G1 points",
+        )));
+    }
+
+    if g2_bytes.len() / BYTES_PER_G2 != TRUSTED_SETUP_NUM_G2_POINTS {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G2 points",
+        )));
+    }
+
+    Ok((g1_bytes, g2_bytes))
+}
+
+fn scan_json_points(contents: &str, key: &str, point_size: usize) -> Result<Vec<u8>, Error> {
+    let format_error = || Error::InvalidTrustedSetup(format!("Invalid JSON array `{}`", key));
+
+    let key_start = contents
+        .find(&format!("\"{}\"", key))
+        .ok_or_else(|| Error::InvalidTrustedSetup(format!("Missing JSON key `{}`", key)))?;
+    let mut rest = contents[key_start + key.len() + 2..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(format_error)?
+        .trim_start()
+        .strip_prefix('[')
+        .ok_or_else(format_error)?;
+
+    let mut out = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.starts_with(']') {
+            return Ok(out);
+        }
+
+        let after_quote = rest.strip_prefix('"').ok_or_else(format_error)?;
+        let end = after_quote.find('"').ok_or_else(format_error)?;
+        // Decoded bytewise, so that non-ASCII characters are rejected instead of splitting them
+        let hex = after_quote[..end].as_bytes();
+        let hex = hex.strip_prefix(b"0x").unwrap_or(hex);
+        if hex.len() != 2 * point_size {
+            return Err(format_error());
+        }
+
+        for pair in hex.chunks_exact(2) {
+            let high = hex_digit(pair[0]).ok_or_else(format_error)?;
+            let low = hex_digit(pair[1]).ok_or_else(format_error)?;
+            out.push((high << 4) | low);
+        }
+
+        rest = after_quote[end + 1..].trim_start();
+        if let Some(after) = rest.strip_prefix(',') {
+            rest = after;
+        } else if !rest.starts_with(']') {
+            return Err(format_error());
+        }
+    }
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Read trusted setup in any supported format. Returns compressed G1 and G2 points, which can be passed
+/// to [`crate::eip_4844::load_trusted_setup_rust`] or [`write_trusted_setup`].
+pub fn read_trusted_setup(contents: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    match TrustedSetupFormat::detect(contents) {
+        TrustedSetupFormat::Text => load_trusted_setup_string(to_str(contents)?),
+        TrustedSetupFormat::Json => load_trusted_setup_json(to_str(contents)?),
+        TrustedSetupFormat::Binary => {
+            let (g1_bytes, g2_bytes) = split_binary(contents)?;
+            Ok((g1_bytes.to_vec(), g2_bytes.to_vec()))
+        }
+        TrustedSetupFormat::BinaryUncompressed => {
+            let (g1_bytes, g2_bytes) = split_binary(contents)?;
+            let g1_compressed = g1_bytes
+                .chunks_exact(BYTES_PER_G1_UNCOMPRESSED)
+                .map(|bytes| deserialize_g1(bytes).map(|point| compress_g1(&point)))
+                .collect::<Result<Vec<_>, _>>()?;
+            let g2_compressed = g2_bytes
+                .chunks_exact(BYTES_PER_G2_UNCOMPRESSED)
+                .map(|bytes| deserialize_g2(bytes).map(|point| compress_g2(&point)))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((g1_compressed.concat(), g2_compressed.concat()))
+        }
+    }
+}
+
+/// Parse trusted setup in any supported format into points, ready to be passed to
+/// [`crate::eip_4844::load_trusted_setup_values_rust`].
+#[allow(clippy::type_complexity)]
+pub fn load_trusted_setup_points<TG1: G1 + G1GetFp<TG1Fp>, TG1Fp: G1Fp, TG2: G2>(
+    contents: &[u8],
+) -> Result<(Vec<TG1>, Vec<TG2>), Error> {
+    let (g1_values, g2_bytes) =
+        if TrustedSetupFormat::detect(contents) == TrustedSetupFormat::BinaryUncompressed {
+            let (g1_bytes, g2_bytes) = split_binary(contents)?;
+
+            // Points are only checked to be on curve, subgroup checks are skipped
+            let g1_values = g1_bytes
+                .chunks_exact(BYTES_PER_G1_UNCOMPRESSED)
+                .map(|bytes| deserialize_g1(bytes).map(|point| g1_from_affine(&point)))
+                .collect::<Result<Vec<TG1>, Error>>()?;
+
+            // There are only few G2 points, so they are still checked through compressed form
+            let g2_bytes = g2_bytes
+                .chunks_exact(BYTES_PER_G2_UNCOMPRESSED)
+                .map(|bytes| deserialize_g2(bytes).map(|point| compress_g2(&point)))
+                .collect::<Result<Vec<_>, _>>()?
+                .concat();
+
+            (g1_values, g2_bytes)
+        } else {
+            let (g1_bytes, g2_bytes) = read_trusted_setup(contents)?;
+            let g1_values = g1_bytes
+                .chunks(BYTES_PER_G1)
+                .map(TG1::from_bytes)
+                .collect::<Result<Vec<TG1>, Error>>()?;
+
+            (g1_values, g2_bytes)
+        };
+
+    let g2_values = g2_bytes
+        .chunks(BYTES_PER_G2)
+        .map(TG2::from_bytes)
+        .collect::<Result<Vec<TG2>, Error>>()?;
+
+    Ok((g1_values, g2_values))
+}
+
+/// Serialize compressed G1 and G2 points (as returned by [`read_trusted_setup`]) into given format
+pub fn write_trusted_setup(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+    format: TrustedSetupFormat,
+) -> Result<Vec<u8>, Error> {
+    let num_g1_points = g1_bytes.len() / BYTES_PER_G1;
+    if g1_bytes.len() % BYTES_PER_G1 != 0 || !is_valid_blob_width(num_g1_points) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G1 points",
+        )));
+    }
+
+    let num_g2_points = g2_bytes.len() / BYTES_PER_G2;
+    if g2_bytes.len() % BYTES_PER_G2 != 0 || num_g2_points != TRUSTED_SETUP_NUM_G2_POINTS {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G2 points",
+        )));
+    }
+
+    match format {
+        TrustedSetupFormat::Text => {
+            let mut out = format!("{}\n{}\n", num_g1_points, num_g2_points);
+            for point in g1_bytes
+                .chunks(BYTES_PER_G1)
+                .chain(g2_bytes.chunks(BYTES_PER_G2))
+            {
+                out.push_str(&to_hex(point));
+                out.push('\n');
+            }
+            Ok(out.into_bytes())
+        }
+        TrustedSetupFormat::Json => {
+            let to_json_array = |bytes: &[u8], point_size: usize| {
+                bytes
+                    .chunks(point_size)
+                    .map(|point| format!("\"0x{}\"", to_hex(point)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let out = format!(
+                "{{\"{}\": [{}], \"{}\": [{}]}}\n",
+                JSON_G1_KEY,
+                to_json_array(g1_bytes, BYTES_PER_G1),
+                JSON_G2_KEY,
+                to_json_array(g2_bytes, BYTES_PER_G2),
+            );
+            Ok(out.into_bytes())
+        }
+        TrustedSetupFormat::Binary => {
+            let mut out = binary_header(num_g1_points, num_g2_points, 0);
+            out.extend_from_slice(g1_bytes);
+            out.extend_from_slice(g2_bytes);
+            Ok(out)
+        }
+        TrustedSetupFormat::BinaryUncompressed => {
+            let mut out = binary_header(num_g1_points, num_g2_points, BINARY_FLAG_UNCOMPRESSED);
+            for bytes in g1_bytes.chunks(BYTES_PER_G1) {
+                let mut point = blst_p1_affine::default();
+                check_blst_error(unsafe { blst_p1_uncompress(&mut point, bytes.as_ptr()) })?;
+                let mut serialized = [0u8; BYTES_PER_G1_UNCOMPRESSED];
+                unsafe { blst_p1_affine_serialize(serialized.as_mut_ptr(), &point) };
+                out.extend_from_slice(&serialized);
+            }
+            for bytes in g2_bytes.chunks(BYTES_PER_G2) {
+                let mut point = blst_p2_affine::default();
+                check_blst_error(unsafe { blst_p2_uncompress(&mut point, bytes.as_ptr()) })?;
+                let mut serialized = [0u8; BYTES_PER_G2_UNCOMPRESSED];
+                unsafe { blst_p2_affine_serialize(serialized.as_mut_ptr(), &point) };
+                out.extend_from_slice(&serialized);
+            }
+            Ok(out)
+        }
+    }
+}
+
+fn binary_header(num_g1_points: usize, num_g2_points: usize, flags: u32) -> Vec<u8> {
+    let mut out = Vec::with_capacity(BINARY_HEADER_SIZE);
+    out.extend_from_slice(&TRUSTED_SETUP_BINARY_MAGIC);
+    out.extend_from_slice(&TRUSTED_SETUP_BINARY_VERSION.to_le_bytes());
+    out.extend_from_slice(&flags.to_le_bytes());
+    out.extend_from_slice(&(num_g1_points as u64).to_le_bytes());
+    out.extend_from_slice(&(num_g2_points as u64).to_le_bytes());
+    out
+}
+
+/// Split binary trusted setup into G1 and G2 point bytes, validating its header
+fn split_binary(contents: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    if contents.len() < BINARY_HEADER_SIZE || !contents.starts_with(&TRUSTED_SETUP_BINARY_MAGIC) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid binary trusted setup header",
+        )));
+    }
+
+    let read_u32 =
+        |offset: usize| u32::from_le_bytes(contents[offset..offset + 4].try_into().unwrap());
+    let read_u64 =
+        |offset: usize| u64::from_le_bytes(contents[offset..offset + 8].try_into().unwrap());
+
+    if read_u32(8) != TRUSTED_SETUP_BINARY_VERSION {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Unsupported binary trusted setup version",
+        )));
+    }
+
+    let (g1_size, g2_size) = if read_u32(12) & BINARY_FLAG_UNCOMPRESSED != 0 {
+        (BYTES_PER_G1_UNCOMPRESSED, BYTES_PER_G2_UNCOMPRESSED)
+    } else {
+        (BYTES_PER_G1, BYTES_PER_G2)
+    };
+
+    let num_g1_points = read_u64(16) as usize;
+    if !is_valid_blob_width(num_g1_points) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G1 points",
+        )));
+    }
+
+    let num_g2_points = read_u64(24) as usize;
+    if num_g2_points != TRUSTED_SETUP_NUM_G2_POINTS {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G2 points",
+        )));
+    }
+
+    let points = &contents[BINARY_HEADER_SIZE..];
+    if num_g1_points
+        .checked_mul(g1_size)
+        .and_then(|size| size.checked_add(num_g2_points * g2_size))
+        != Some(points.len())
+    {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Binary trusted setup size does not match its header",
+        )));
+    }
+
+    Ok(points.split_at(num_g1_points * g1_size))
+}
+
+fn deserialize_g1(bytes: &[u8]) -> Result<blst_p1_affine, Error> {
+    let mut point = blst_p1_affine::default();
+    check_blst_error(unsafe { blst_p1_deserialize(&mut point, bytes.as_ptr()) })?;
+    Ok(point)
+}
+
+fn deserialize_g2(bytes: &[u8]) -> Result<blst_p2_affine, Error> {
+    let mut point = blst_p2_affine::default();
+    check_blst_error(unsafe { blst_p2_deserialize(&mut point, bytes.as_ptr()) })?;
+    Ok(point)
+}
+
+fn compress_g1(point: &blst_p1_affine) -> [u8; BYTES_PER_G1] {
+    let mut out = [0u8; BYTES_PER_G1];
+    unsafe { blst_p1_affine_compress(out.as_mut_ptr(), point) };
+    out
+}
+
+fn compress_g2(point: &blst_p2_affine) -> [u8; BYTES_PER_G2] {
+    let mut out = [0u8; BYTES_PER_G2];
+    unsafe { blst_p2_affine_compress(out.as_mut_ptr(), point) };
+    out
+}
+
+/// Convert affine point to backend point, both using Montgomery form of coordinates
+fn g1_from_affine<TG1: G1 + G1GetFp<TG1Fp>, TG1Fp: G1Fp>(point: &blst_p1_affine) -> TG1 {
+    if unsafe { blst_p1_affine_is_inf(point) } {
+        return TG1::identity();
+    }
+
+    let mut out = TG1::generator();
+    *out.x_mut() = TG1Fp::from_underlying_arr(&point.x.l);
+    *out.y_mut() = TG1Fp::from_underlying_arr(&point.y.l);
+    *out.z_mut() = TG1Fp::one();
+    out
+}
+
+fn check_blst_error(err: BLST_ERROR) -> Result<(), Error> {
+    if err == BLST_ERROR::BLST_SUCCESS {
+        Ok(())
+    } else {
+        Err(Error::InvalidPoint(format!(
+            "Failed to deserialize trusted setup point: {:?}",
+            err
+        )))
+    }
+}
+
+fn to_str(contents: &[u8]) -> Result<&str, Error> {
+    core::str::from_utf8(contents)
+        .map_err(|_| Error::InvalidTrustedSetup(String::from("Trusted setup is not valid UTF-8")))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push_str(&format!("{:02x}", byte));
+    }
+    out
+}
@@ -1,9 +1,9 @@
 use crate::data_types::g1::is_valid_order;
-use crate::data_types::{fr::Fr, g1::G1};
+use crate::data_types::{fr::Fr, g1::G1, g2::G2, gt::GT};
 use crate::fk20_fft::{G1_GENERATOR, G1_NEGATIVE_GENERATOR};
-use crate::mcl_methods::set_eth_serialization;
+use crate::mcl_methods::{final_exp, miller_loop, set_eth_serialization};
 use kzg::eip_4844::BYTES_PER_G1;
-use kzg::{G1Mul, G1 as CommonG1};
+use kzg::{G1Mul, MultiPairing, G1 as CommonG1};
 
 impl CommonG1 for G1 {
     fn identity() -> Self {
@@ -100,3 +100,23 @@ impl G1Mul<Fr> for G1 {
         g1
     }
 }
+
+impl MultiPairing<G1, G2> for G1 {
+    fn multi_pairing_is_one(ps: &[G1], qs: &[G2]) -> bool {
+        if ps.len() != qs.len() {
+            return false;
+        }
+
+        let mut product = GT::from_int(1);
+        for (p, q) in ps.iter().zip(qs) {
+            let mut loop_result = GT::default();
+            miller_loop(&mut loop_result, p, q);
+            let previous = product.clone();
+            GT::mul(&mut product, &previous, &loop_result);
+        }
+
+        let mut result = GT::default();
+        final_exp(&mut result, &product);
+        result.is_one()
+    }
+}
@@ -0,0 +1,54 @@
+//! FFTs, erasure extension and recovery over field elements, which are concatenated 32-byte big-endian values.
+
+use kzg::backend::Backend;
+use kzg::eip_4844::BYTES_PER_FIELD_ELEMENT;
+use kzg::{Error, FFTFr, Fr, Poly, PolyRecover, DAS};
+
+pub fn fft_fr<B: Backend>(
+    fs: &B::FFTSettings,
+    data: &[u8],
+    inverse: bool,
+) -> Result<Vec<u8>, Error> {
+    let data = read_field_elements::<B::Fr>(data)?;
+    let result = fs.fft_fr(&data, inverse)?;
+    Ok(write_field_elements(&result))
+}
+
+/// Takes even-indexed evaluations over a domain twice as large, returns odd-indexed ones
+pub fn das_fft_extension<B: Backend>(fs: &B::FFTSettings, evens: &[u8]) -> Result<Vec<u8>, Error> {
+    let evens = read_field_elements::<B::Fr>(evens)?;
+    let odds = fs.das_fft_extension(&evens)?;
+    Ok(write_field_elements(&odds))
+}
+
+/// Recovers all evaluations, where `None` marks a missing sample
+pub fn recover_poly_from_samples<B: Backend>(
+    fs: &B::FFTSettings,
+    samples: &[Option<&[u8]>],
+) -> Result<Vec<u8>, Error> {
+    let samples = samples
+        .iter()
+        .map(|sample| sample.map(B::Fr::from_bytes).transpose())
+        .collect::<Result<Vec<_>, Error>>()?;
+    let recovered = B::Poly::recover_poly_from_samples(&samples, fs)?;
+    Ok(write_field_elements(recovered.get_coeffs()))
+}
+
+fn read_field_elements<F: Fr>(bytes: &[u8]) -> Result<Vec<F>, Error> {
+    if bytes.len() % BYTES_PER_FIELD_ELEMENT != 0 {
+        return Err(Error::InvalidLength(format!(
+            "Field elements are expected to be {} bytes each, got {} bytes",
+            BYTES_PER_FIELD_ELEMENT,
+            bytes.len()
+        )));
+    }
+
+    bytes
+        .chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(F::from_bytes)
+        .collect()
+}
+
+fn write_field_elements<F: Fr>(values: &[F]) -> Vec<u8> {
+    values.iter().flat_map(|value| value.to_bytes()).collect()
+}
@@ -0,0 +1,321 @@
+//! Python bindings, where every input and output is `bytes`:
+//!
+//! ```python
+//! import rust_kzg
+//!
+//! s = rust_kzg.load_trusted_setup("trusted_setup.txt")
+//! commitment = rust_kzg.blob_to_kzg_commitment(blob, s)
+//!
+//! fs = rust_kzg.FFTSettings(8)
+//! odds = rust_kzg.das_fft_extension(evens, fs)
+//! ```
+//!
+//! Backend is chosen with a feature of the same name, `BACKEND` of the module tells which one was built. Every
+//! backend exports c-kzg-4844 symbols, so only one of them can be linked into the module.
+
+mod fft;
+
+use kzg::backend::{Backend, DynKzg, KzgContext};
+use kzg::eip_4844::{
+    Bytes32, Bytes48, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT,
+    BYTES_PER_PROOF,
+};
+use kzg::{Error, FFTSettings as _};
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+#[cfg(not(any(
+    feature = "blst",
+    feature = "arkworks",
+    feature = "arkworks3",
+    feature = "zkcrypto",
+    feature = "constantine"
+)))]
+compile_error!("One backend feature has to be enabled");
+
+#[cfg(any(
+    all(
+        feature = "blst",
+        any(
+            feature = "arkworks",
+            feature = "arkworks3",
+            feature = "zkcrypto",
+            feature = "constantine"
+        )
+    ),
+    all(
+        feature = "arkworks",
+        any(feature = "arkworks3", feature = "zkcrypto", feature = "constantine")
+    ),
+    all(
+        feature = "arkworks3",
+        any(feature = "zkcrypto", feature = "constantine")
+    ),
+    all(feature = "zkcrypto", feature = "constantine")
+))]
+compile_error!("Only one backend feature can be enabled");
+
+#[cfg(feature = "blst")]
+type SelectedBackend = rust_kzg_blst::eip_4844::BlstBackend;
+#[cfg(feature = "arkworks")]
+type SelectedBackend = rust_kzg_arkworks::eip_4844::ArkBackend;
+#[cfg(feature = "arkworks3")]
+type SelectedBackend = rust_kzg_arkworks3::eip_4844::ArkBackend;
+#[cfg(feature = "zkcrypto")]
+type SelectedBackend = rust_kzg_zkcrypto::eip_4844::ZBackend;
+#[cfg(feature = "constantine")]
+type SelectedBackend = rust_kzg_constantine::eip_4844::CtBackend;
+
+/// Invalid inputs raise `ValueError`, everything else raises `RuntimeError`
+fn to_py_err(err: Error) -> PyErr {
+    match err {
+        Error::InvalidTrustedSetup(_)
+        | Error::FeatureNotEnabled(_)
+        | Error::ThreadPool(_)
+        | Error::Internal(_) => {
+            PyRuntimeError::new_err(err.to_string())
+        }
+        _ => PyValueError::new_err(err.to_string()),
+    }
+}
+
+fn to_array<const N: usize>(bytes: &[u8], name: &str) -> PyResult<[u8; N]> {
+    bytes.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "Invalid {} length: expected {} bytes, got {}",
+            name,
+            N,
+            bytes.len()
+        ))
+    })
+}
+
+fn to_bytes32(bytes: &[u8], name: &str) -> PyResult<Bytes32> {
+    to_array::<BYTES_PER_FIELD_ELEMENT>(bytes, name).map(|bytes| Bytes32 { bytes })
+}
+
+fn to_bytes48(bytes: &[u8], name: &str) -> PyResult<Bytes48> {
+    to_array::<BYTES_PER_COMMITMENT>(bytes, name).map(|bytes| Bytes48 { bytes })
+}
+
+fn to_proof(bytes: &[u8]) -> PyResult<Bytes48> {
+    to_array::<BYTES_PER_PROOF>(bytes, "proof").map(|bytes| Bytes48 { bytes })
+}
+
+/// Trusted setup, together with precomputation tables
+#[pyclass(frozen)]
+struct KZGSettings {
+    kzg: KzgContext<SelectedBackend>,
+}
+
+#[pymethods]
+impl KZGSettings {
+    #[getter]
+    fn field_elements_per_blob(&self) -> usize {
+        self.kzg.field_elements_per_blob()
+    }
+}
+
+/// Roots of unity for domains of up to `2^max_scale` elements
+#[pyclass(frozen)]
+struct FFTSettings {
+    fs: <SelectedBackend as Backend>::FFTSettings,
+}
+
+#[pymethods]
+impl FFTSettings {
+    #[new]
+    fn new(max_scale: usize) -> PyResult<Self> {
+        let fs = <SelectedBackend as Backend>::FFTSettings::new(max_scale).map_err(to_py_err)?;
+        Ok(Self { fs })
+    }
+
+    #[getter]
+    fn max_width(&self) -> usize {
+        self.fs.get_max_width()
+    }
+}
+
+/// Loads trusted setup from file, in any format supported by the backend
+#[pyfunction]
+fn load_trusted_setup(py: Python<'_>, path: &str) -> PyResult<KZGSettings> {
+    let kzg = py
+        .allow_threads(|| KzgContext::load_trusted_setup_file(path))
+        .map_err(to_py_err)?;
+    Ok(KZGSettings { kzg })
+}
+
+/// Loads trusted setup from its contents, in any format supported by the backend
+#[pyfunction]
+fn load_trusted_setup_bytes(py: Python<'_>, contents: &[u8]) -> PyResult<KZGSettings> {
+    let kzg = py
+        .allow_threads(|| KzgContext::load_trusted_setup(contents))
+        .map_err(to_py_err)?;
+    Ok(KZGSettings { kzg })
+}
+
+#[pyfunction]
+fn blob_to_kzg_commitment<'py>(
+    py: Python<'py>,
+    blob: &[u8],
+    s: &KZGSettings,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let commitment = py
+        .allow_threads(|| DynKzg::blob_to_kzg_commitment(&s.kzg, blob))
+        .map_err(to_py_err)?;
+    Ok(PyBytes::new_bound(py, &commitment.bytes))
+}
+
+/// Returns proof, together with evaluation of blob polynomial at `z`
+#[pyfunction]
+fn compute_kzg_proof<'py>(
+    py: Python<'py>,
+    blob: &[u8],
+    z: &[u8],
+    s: &KZGSettings,
+) -> PyResult<(Bound<'py, PyBytes>, Bound<'py, PyBytes>)> {
+    let z = to_bytes32(z, "z")?;
+    let (proof, y) = py
+        .allow_threads(|| DynKzg::compute_kzg_proof(&s.kzg, blob, &z))
+        .map_err(to_py_err)?;
+    Ok((
+        PyBytes::new_bound(py, &proof.bytes),
+        PyBytes::new_bound(py, &y.bytes),
+    ))
+}
+
+#[pyfunction]
+fn compute_blob_kzg_proof<'py>(
+    py: Python<'py>,
+    blob: &[u8],
+    commitment: &[u8],
+    s: &KZGSettings,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let commitment = to_bytes48(commitment, "commitment")?;
+    let proof = py
+        .allow_threads(|| DynKzg::compute_blob_kzg_proof(&s.kzg, blob, &commitment))
+        .map_err(to_py_err)?;
+    Ok(PyBytes::new_bound(py, &proof.bytes))
+}
+
+#[pyfunction]
+fn verify_kzg_proof(
+    py: Python<'_>,
+    commitment: &[u8],
+    z: &[u8],
+    y: &[u8],
+    proof: &[u8],
+    s: &KZGSettings,
+) -> PyResult<bool> {
+    let commitment = to_bytes48(commitment, "commitment")?;
+    let z = to_bytes32(z, "z")?;
+    let y = to_bytes32(y, "y")?;
+    let proof = to_proof(proof)?;
+    py.allow_threads(|| DynKzg::verify_kzg_proof(&s.kzg, &commitment, &z, &y, &proof))
+        .map_err(to_py_err)
+}
+
+#[pyfunction]
+fn verify_blob_kzg_proof(
+    py: Python<'_>,
+    blob: &[u8],
+    commitment: &[u8],
+    proof: &[u8],
+    s: &KZGSettings,
+) -> PyResult<bool> {
+    let commitment = to_bytes48(commitment, "commitment")?;
+    let proof = to_proof(proof)?;
+    py.allow_threads(|| DynKzg::verify_blob_kzg_proof(&s.kzg, blob, &commitment, &proof))
+        .map_err(to_py_err)
+}
+
+#[pyfunction]
+fn verify_blob_kzg_proof_batch<'py>(
+    py: Python<'py>,
+    blobs: Vec<Bound<'py, PyBytes>>,
+    commitments: Vec<Bound<'py, PyBytes>>,
+    proofs: Vec<Bound<'py, PyBytes>>,
+    s: &KZGSettings,
+) -> PyResult<bool> {
+    let blobs = blobs.iter().map(|blob| blob.as_bytes()).collect::<Vec<_>>();
+    let commitments = commitments
+        .iter()
+        .map(|commitment| to_bytes48(commitment.as_bytes(), "commitment"))
+        .collect::<PyResult<Vec<_>>>()?;
+    let proofs = proofs
+        .iter()
+        .map(|proof| to_proof(proof.as_bytes()))
+        .collect::<PyResult<Vec<_>>>()?;
+    py.allow_threads(|| DynKzg::verify_blob_kzg_proof_batch(&s.kzg, &blobs, &commitments, &proofs))
+        .map_err(to_py_err)
+}
+
+/// Forward, or inverse, FFT of concatenated field elements
+#[pyfunction]
+fn fft_fr<'py>(
+    py: Python<'py>,
+    data: &[u8],
+    inverse: bool,
+    fs: &FFTSettings,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let result = py
+        .allow_threads(|| fft::fft_fr::<SelectedBackend>(&fs.fs, data, inverse))
+        .map_err(to_py_err)?;
+    Ok(PyBytes::new_bound(py, &result))
+}
+
+/// Takes `n` even-indexed evaluations of a polynomial of degree less than `n` over a domain of `2 * n` elements,
+/// returns `n` odd-indexed evaluations
+#[pyfunction]
+fn das_fft_extension<'py>(
+    py: Python<'py>,
+    evens: &[u8],
+    fs: &FFTSettings,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let odds = py
+        .allow_threads(|| fft::das_fft_extension::<SelectedBackend>(&fs.fs, evens))
+        .map_err(to_py_err)?;
+    Ok(PyBytes::new_bound(py, &odds))
+}
+
+/// Recovers all evaluations from a list of samples, where missing samples are `None`. At least half of samples
+/// have to be known
+#[pyfunction]
+fn recover_poly_from_samples<'py>(
+    py: Python<'py>,
+    samples: Vec<Option<Bound<'py, PyBytes>>>,
+    fs: &FFTSettings,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let samples = samples
+        .iter()
+        .map(|sample| sample.as_ref().map(|sample| sample.as_bytes()))
+        .collect::<Vec<_>>();
+    let recovered = py
+        .allow_threads(|| fft::recover_poly_from_samples::<SelectedBackend>(&fs.fs, &samples))
+        .map_err(to_py_err)?;
+    Ok(PyBytes::new_bound(py, &recovered))
+}
+
+#[pymodule]
+fn rust_kzg(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("BACKEND", SelectedBackend::NAME)?;
+    m.add("BYTES_PER_BLOB", BYTES_PER_BLOB)?;
+    m.add("BYTES_PER_COMMITMENT", BYTES_PER_COMMITMENT)?;
+    m.add("BYTES_PER_FIELD_ELEMENT", BYTES_PER_FIELD_ELEMENT)?;
+    m.add("BYTES_PER_PROOF", BYTES_PER_PROOF)?;
+    m.add_class::<KZGSettings>()?;
+    m.add_class::<FFTSettings>()?;
+    m.add_function(wrap_pyfunction!(load_trusted_setup, m)?)?;
+    m.add_function(wrap_pyfunction!(load_trusted_setup_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(blob_to_kzg_commitment, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_kzg_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_blob_kzg_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_kzg_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_blob_kzg_proof, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_blob_kzg_proof_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(fft_fr, m)?)?;
+    m.add_function(wrap_pyfunction!(das_fft_extension, m)?)?;
+    m.add_function(wrap_pyfunction!(recover_poly_from_samples, m)?)?;
+    Ok(())
+}
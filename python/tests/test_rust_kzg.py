@@ -0,0 +1,193 @@
+import glob
+import os
+import random
+import unittest
+
+import yaml
+
+import rust_kzg
+
+KZG_BENCH_DIR = os.path.join(os.path.dirname(__file__), "..", "..", "kzg-bench", "src")
+TRUSTED_SETUP_PATH = os.path.join(KZG_BENCH_DIR, "trusted_setup.txt")
+TEST_VECTORS_DIR = os.path.join(KZG_BENCH_DIR, "test_vectors")
+
+# 2^254, every value below it is a canonical field element
+FIELD_ELEMENT_BOUND = 1 << 254
+
+
+def load_test_vectors(name):
+    paths = sorted(glob.glob(os.path.join(TEST_VECTORS_DIR, name, "*", "*", "data.yaml")))
+    assert paths, "No test vectors found for " + name
+    for path in paths:
+        with open(path, "r") as f:
+            yield os.path.basename(os.path.dirname(path)), yaml.safe_load(f)
+
+
+def from_hex(value):
+    return bytes.fromhex(value[2:])
+
+
+def field_elements(values):
+    return b"".join(value.to_bytes(32, "big") for value in values)
+
+
+def split_field_elements(data):
+    return [data[i : i + 32] for i in range(0, len(data), 32)]
+
+
+def random_field_elements(n):
+    return field_elements(random.randrange(FIELD_ELEMENT_BOUND) for _ in range(n))
+
+
+class TestEip4844(unittest.TestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls.s = rust_kzg.load_trusted_setup(TRUSTED_SETUP_PATH)
+
+    def check(self, name, call, parse_output):
+        for case, test in load_test_vectors(name):
+            with self.subTest(case=case):
+                if test["output"] is None:
+                    with self.assertRaises(ValueError):
+                        call(test["input"])
+                else:
+                    self.assertEqual(call(test["input"]), parse_output(test["output"]))
+
+    def test_load_trusted_setup(self):
+        self.assertEqual(self.s.field_elements_per_blob, 4096)
+        with open(TRUSTED_SETUP_PATH, "rb") as f:
+            s = rust_kzg.load_trusted_setup_bytes(f.read())
+        self.assertEqual(s.field_elements_per_blob, self.s.field_elements_per_blob)
+        with self.assertRaises(RuntimeError):
+            rust_kzg.load_trusted_setup_bytes(b"")
+
+    def test_blob_to_kzg_commitment(self):
+        self.check(
+            "blob_to_kzg_commitment",
+            lambda input: rust_kzg.blob_to_kzg_commitment(from_hex(input["blob"]), self.s),
+            from_hex,
+        )
+
+    def test_compute_kzg_proof(self):
+        self.check(
+            "compute_kzg_proof",
+            lambda input: rust_kzg.compute_kzg_proof(
+                from_hex(input["blob"]), from_hex(input["z"]), self.s
+            ),
+            lambda output: tuple(from_hex(value) for value in output),
+        )
+
+    def test_compute_blob_kzg_proof(self):
+        self.check(
+            "compute_blob_kzg_proof",
+            lambda input: rust_kzg.compute_blob_kzg_proof(
+                from_hex(input["blob"]), from_hex(input["commitment"]), self.s
+            ),
+            from_hex,
+        )
+
+    def test_verify_kzg_proof(self):
+        self.check(
+            "verify_kzg_proof",
+            lambda input: rust_kzg.verify_kzg_proof(
+                from_hex(input["commitment"]),
+                from_hex(input["z"]),
+                from_hex(input["y"]),
+                from_hex(input["proof"]),
+                self.s,
+            ),
+            bool,
+        )
+
+    def test_verify_blob_kzg_proof(self):
+        self.check(
+            "verify_blob_kzg_proof",
+            lambda input: rust_kzg.verify_blob_kzg_proof(
+                from_hex(input["blob"]),
+                from_hex(input["commitment"]),
+                from_hex(input["proof"]),
+                self.s,
+            ),
+            bool,
+        )
+
+    def test_verify_blob_kzg_proof_batch(self):
+        self.check(
+            "verify_blob_kzg_proof_batch",
+            lambda input: rust_kzg.verify_blob_kzg_proof_batch(
+                [from_hex(blob) for blob in input["blobs"]],
+                [from_hex(commitment) for commitment in input["commitments"]],
+                [from_hex(proof) for proof in input["proofs"]],
+                self.s,
+            ),
+            bool,
+        )
+
+    def test_invalid_lengths(self):
+        blob = bytes(rust_kzg.BYTES_PER_BLOB)
+        with self.assertRaises(ValueError):
+            rust_kzg.blob_to_kzg_commitment(blob[1:], self.s)
+        with self.assertRaises(ValueError):
+            rust_kzg.compute_kzg_proof(blob, bytes(31), self.s)
+
+
+class TestFFT(unittest.TestCase):
+    def test_max_width(self):
+        self.assertEqual(rust_kzg.FFTSettings(4).max_width, 16)
+        with self.assertRaises(ValueError):
+            rust_kzg.FFTSettings(64)
+
+    def test_fft_fr_roundtrip(self):
+        fs = rust_kzg.FFTSettings(8)
+        data = random_field_elements(256)
+        evaluations = rust_kzg.fft_fr(data, False, fs)
+        self.assertNotEqual(evaluations, data)
+        self.assertEqual(rust_kzg.fft_fr(evaluations, True, fs), data)
+        with self.assertRaises(ValueError):
+            rust_kzg.fft_fr(data[1:], False, fs)
+
+    def test_das_fft_extension_known(self):
+        # Same values as das_extension_test_known of kzg-bench, limbs are little-endian
+        expected_limbs = [
+            [0xA0C43757DB972D7D, 0x79D15A1E0677962C, 0xF678865C0C95FA6A, 0x4E85FD4814F96825],
+            [0xAD9F844939F2705D, 0x319E440C9F3B0325, 0x4CBD29A60E160A28, 0x665961D85D90C4C0],
+            [0x5F3AC8A72468D28B, 0xEDE949E28383C5D2, 0xAF6F84DD8708D8C9, 0x2567AA0B14A41521],
+            [0x25ABE312B96AADAD, 0x4ABF043F091FF417, 0x43824B53E09536DB, 0x195DBE06A28CA227],
+            [0x5F3AC8A72468D28B, 0xEDE949E28383C5D2, 0xAF6F84DD8708D8C9, 0x2567AA0B14A41521],
+            [0xAD9F844939F2705D, 0x319E440C9F3B0325, 0x4CBD29A60E160A28, 0x665961D85D90C4C0],
+            [0xA0C43757DB972D7D, 0x79D15A1E0677962C, 0xF678865C0C95FA6A, 0x4E85FD4814F96825],
+            [0x7F171458D2B071A9, 0xD185BBB2A46CBD9B, 0xA41AAB0D02886E80, 0x01CACCEEF58CCEE9],
+        ]
+        expected = field_elements(
+            sum(limb << (64 * i) for i, limb in enumerate(limbs)) for limbs in expected_limbs
+        )
+
+        fs = rust_kzg.FFTSettings(4)
+        self.assertEqual(rust_kzg.das_fft_extension(field_elements(range(8)), fs), expected)
+
+    def test_das_fft_extension_random(self):
+        fs = rust_kzg.FFTSettings(8)
+        evens = split_field_elements(random_field_elements(128))
+        odds = split_field_elements(rust_kzg.das_fft_extension(b"".join(evens), fs))
+
+        # Upper half of coefficients of interpolated polynomial is zero
+        data = b"".join(value for pair in zip(evens, odds) for value in pair)
+        coeffs = rust_kzg.fft_fr(data, True, fs)
+        self.assertEqual(coeffs[128 * 32 :], bytes(128 * 32))
+
+    def test_recover_poly_from_samples(self):
+        fs = rust_kzg.FFTSettings(8)
+        coeffs = random_field_elements(128) + bytes(128 * 32)
+        data = split_field_elements(rust_kzg.fft_fr(coeffs, False, fs))
+
+        missing = set(random.sample(range(256), 128))
+        samples = [None if i in missing else value for i, value in enumerate(data)]
+        self.assertEqual(rust_kzg.recover_poly_from_samples(samples, fs), b"".join(data))
+
+        samples[next(i for i, value in enumerate(samples) if value is not None)] = None
+        with self.assertRaises(ValueError):
+            rust_kzg.recover_poly_from_samples(samples, fs)
+
+
+if __name__ == "__main__":
+    unittest.main()
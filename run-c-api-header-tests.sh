@@ -0,0 +1,82 @@
+#!/bin/bash
+
+set -e
+
+print_msg () {
+  echo "[*]" "$1"
+}
+
+###################### parallel & backend configuration ######################
+
+parallel=false
+backend="unknown"
+
+while [[ -n $# ]]; do
+  case $1 in
+    -p|--parallel)
+      parallel=true
+      ;;
+    blst|arkworks|arkworks3|mcl|zkcrypto|constantine)
+      backend="$1"
+      ;;
+    *)
+      break
+      ;;
+  esac
+  shift
+done
+
+if [ "$backend" == "unknown" ]; then
+  echo "Unknown backend: $backend"
+  exit 1
+fi
+
+root=$(pwd)
+header_dir="$root/kzg/include"
+
+###################### checking declarations ######################
+
+print_msg "Comparing rust_kzg.h with exported functions"
+exported=$(grep -o "fn kzg_v1_[a-z0-9_]*\|fn kzg_abi_version" kzg/src/c_api.rs | sed "s/^fn //" | sort -u)
+declared=$(grep -o "kzg_v1_[a-z0-9_]*(\|kzg_abi_version(" "$header_dir/rust_kzg.h" | tr -d "(" | sort -u)
+tested=$(grep -o "(any_fn)[a-z0-9_]*" "$header_dir/test_rust_kzg.c" | sed "s/^(any_fn)//" | sort -u)
+
+if [ "$exported" != "$declared" ]; then
+  echo "FAIL: rust_kzg.h does not declare exactly the functions of export_c_api!"
+  diff <(echo "$exported") <(echo "$declared") || true
+  exit 1
+fi
+
+if [ "$exported" != "$tested" ]; then
+  echo "FAIL: test_rust_kzg.c does not reference every function of rust_kzg.h"
+  diff <(echo "$exported") <(echo "$tested") || true
+  exit 1
+fi
+
+###################### building static lib ######################
+
+print_msg "Compiling rust-kzg-$backend"
+cd "$backend"
+
+if [[ "$parallel" = true ]]; then
+  print_msg "Using parallel version"
+  cargo rustc --release --crate-type=staticlib --features=parallel
+else
+  print_msg "Using non-parallel version"
+  cargo rustc --release --crate-type=staticlib
+fi
+
+cd "$root"
+
+###################### compiling & running test ######################
+
+print_msg "Compiling test_rust_kzg.c against rust_kzg.h"
+build_dir=$(mktemp -d)
+trap 'rm -rf "$build_dir"' EXIT
+
+${CC:-cc} -std=c11 -Wall -Wextra -Werror -I "$header_dir" \
+  "$header_dir/test_rust_kzg.c" "target/release/librust_kzg_$backend.a" \
+  -lpthread -ldl -lm -o "$build_dir/test_rust_kzg"
+
+print_msg "Running test_rust_kzg"
+"$build_dir/test_rust_kzg" kzg-bench/src/trusted_setup.txt
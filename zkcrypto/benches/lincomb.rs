@@ -1,16 +1,20 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use kzg_bench::benches::lincomb::bench_g1_lincomb;
+use kzg_bench::benches::lincomb::{bench_g1_lincomb, bench_g1_lincomb_blob};
 use rust_kzg_zkcrypto::fft_g1::g1_linear_combination;
-use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1};
+use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1ProjAddAffine, ZG1};
 
 fn bench_g1_lincomb_(c: &mut Criterion) {
     bench_g1_lincomb::<ZFr, ZG1, ZFp, ZG1Affine>(c, &g1_linear_combination);
 }
 
+fn bench_g1_lincomb_blob_(c: &mut Criterion) {
+    bench_g1_lincomb_blob::<ZFr, ZG1, ZFp, ZG1Affine, ZG1ProjAddAffine>(c);
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_g1_lincomb_
+    targets = bench_g1_lincomb_, bench_g1_lincomb_blob_
 }
 
 criterion_main!(benches);
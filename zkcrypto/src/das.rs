@@ -1,7 +1,13 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::ZFr as BlstFr;
+use core::cmp::Ordering;
+use kzg::Error;
 use kzg::{Fr, DAS};
-use std::cmp::Ordering;
 
 impl FFTSettings {
     fn das_fft_extension_stride(&self, ab: &mut [BlstFr], stride: usize) {
@@ -57,17 +63,19 @@ impl FFTSettings {
 }
 
 impl DAS<BlstFr> for FFTSettings {
-    fn das_fft_extension(&self, vals: &[BlstFr]) -> Result<Vec<BlstFr>, String> {
+    fn das_fft_extension(&self, vals: &[BlstFr]) -> Result<Vec<BlstFr>, Error> {
         if vals.is_empty() {
-            return Err(String::from("vals can not be empty"));
+            return Err(Error::BadArgs(String::from("vals can not be empty")));
         }
         if !vals.len().is_power_of_two() {
-            return Err(String::from("vals lenght has to be power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "vals lenght has to be power of 2",
+            )));
         }
         if vals.len() * 2 > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "vals lenght * 2 has to equal or less than FFTSetings max width",
-            ));
+            )));
         }
 
         let mut vals = vals.to_vec();
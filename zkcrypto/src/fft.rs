@@ -1,14 +1,25 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::ZFr as BlstFr;
+use kzg::Error;
 use kzg::{FFTFr, Fr as FFr};
 
 impl FFTFr<BlstFr> for FFTSettings {
-    fn fft_fr(&self, data: &[BlstFr], inverse: bool) -> Result<Vec<BlstFr>, String> {
+    fn fft_fr(&self, data: &[BlstFr], inverse: bool) -> Result<Vec<BlstFr>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from("data length is longer than allowed max width"));
+            return Err(Error::BadArgs(String::from(
+                "data length is longer than allowed max width",
+            )));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("data length is not power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "data length is not power of 2",
+            )));
         }
 
         let stride = self.max_width / data.len();
@@ -1,11 +1,18 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate::consts::G1_GENERATOR;
 use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1};
 use crate::multiscalar_mul::msm_variable_base;
+use core::ops::MulAssign;
 use kzg::msm::precompute::PrecomputationTable;
+use kzg::Error;
 use kzg::{Fr as KzgFr, G1Mul};
 use kzg::{FFTG1, G1};
-use std::ops::MulAssign;
 
 #[warn(unused_variables)]
 pub fn g1_linear_combination(
@@ -31,12 +38,16 @@ pub fn make_data(data: usize) -> Vec<ZG1> {
 }
 
 impl FFTG1<ZG1> for FFTSettings {
-    fn fft_g1(&self, data: &[ZG1], inverse: bool) -> Result<Vec<ZG1>, String> {
+    fn fft_g1(&self, data: &[ZG1], inverse: bool) -> Result<Vec<ZG1>, Error> {
         if data.len() > self.max_width {
-            return Err(String::from("data length is longer than allowed max width"));
+            return Err(Error::BadArgs(String::from(
+                "data length is longer than allowed max width",
+            )));
         }
         if !data.len().is_power_of_two() {
-            return Err(String::from("data length is not power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "data length is not power of 2",
+            )));
         }
 
         let stride: usize = self.max_width / data.len();
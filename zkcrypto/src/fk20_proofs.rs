@@ -1,8 +1,15 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate::consts::G1_IDENTITY;
 use crate::kzg_proofs::{FFTSettings, KZGSettings};
 use crate::kzg_types::{ZFp, ZFr as BlstFr, ZG1Affine, ZG1, ZG2};
 use crate::poly::PolyData;
 use kzg::common_utils::reverse_bit_order;
+use kzg::Error;
 use kzg::{FFTFr, FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, Poly, FFTG1, G1};
 
 #[cfg(feature = "parallel")]
@@ -27,19 +34,21 @@ pub struct KzgFK20MultiSettings {
 impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp, ZG1Affine>
     for KzgFK20SingleSettings
 {
-    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, Error> {
         let n = n2 / 2;
 
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be equal or greater than 2",
+            )));
         }
 
         let mut x = Vec::new();
@@ -60,17 +69,17 @@ impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZF
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ZG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ZG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
 
         let mut out = fk20_single_da_opt(p, self).unwrap();
@@ -78,7 +87,7 @@ impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZF
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ZG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ZG1>, Error> {
         fk20_single_da_opt(p, self)
     }
 }
@@ -86,26 +95,32 @@ impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZF
 impl FK20MultiSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp, ZG1Affine>
     for KzgFK20MultiSettings
 {
-    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, Error> {
         if n2 > ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n2.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
         if n2 < 2 {
-            return Err(String::from("n2 must be equal or greater than 2"));
+            return Err(Error::BadArgs(String::from(
+                "n2 must be equal or greater than 2",
+            )));
         }
         if chunk_len > n2 / 2 {
-            return Err(String::from("chunk_len must be equal or less than n2/2"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be equal or less than n2/2",
+            )));
         }
         if !chunk_len.is_power_of_two() {
-            return Err(String::from("chunk_len must be power of 2"));
+            return Err(Error::BadArgs(String::from("chunk_len must be power of 2")));
         }
         if chunk_len == 0 {
-            return Err(String::from("chunk_len must be greater than 0"));
+            return Err(Error::BadArgs(String::from(
+                "chunk_len must be greater than 0",
+            )));
         }
 
         let n = n2 / 2;
@@ -146,17 +161,17 @@ impl FK20MultiSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp
         })
     }
 
-    fn data_availability(&self, p: &PolyData) -> Result<Vec<ZG1>, String> {
+    fn data_availability(&self, p: &PolyData) -> Result<Vec<ZG1>, Error> {
         let n = p.len();
         let n2 = n * 2;
 
         if n2 > self.ks.fs.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "n2 must be equal or less than kzg settings max width",
-            ));
+            )));
         }
         if !n.is_power_of_two() {
-            return Err(String::from("n2 must be power of 2"));
+            return Err(Error::BadArgs(String::from("n2 must be power of 2")));
         }
 
         let mut out = fk20_multi_da_opt(p, self).unwrap();
@@ -164,22 +179,22 @@ impl FK20MultiSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp
         Ok(out)
     }
 
-    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ZG1>, String> {
+    fn data_availability_optimized(&self, p: &PolyData) -> Result<Vec<ZG1>, Error> {
         fk20_multi_da_opt(p, self)
     }
 }
 
-fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ZG1>, String> {
+fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ZG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "n2 must be equal or less than kzg settings max width",
-        ));
+        )));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::BadArgs(String::from("n2 must be power of 2")));
     }
 
     let outlen = 2 * p.len();
@@ -190,17 +205,17 @@ fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ZG
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ZG1>, String> {
+fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ZG1>, Error> {
     let n = p.len();
     let n2 = n * 2;
 
     if n2 > fk.ks.fs.max_width {
-        return Err(String::from(
+        return Err(Error::BadArgs(String::from(
             "n2 must be equal or less than kzg settings max width",
-        ));
+        )));
     }
     if !n.is_power_of_two() {
-        return Err(String::from("n2 must be power of 2"));
+        return Err(Error::BadArgs(String::from("n2 must be power of 2")));
     }
 
     let n = n2 / 2;
@@ -234,7 +249,7 @@ fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ZG1>
     fk.ks.fs.fft_g1(&h, false)
 }
 
-fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, String> {
+fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, Error> {
     toeplitz_coeffs_stride(p, 0, 1, outlen)
 }
 
@@ -243,18 +258,22 @@ fn toeplitz_coeffs_stride(
     offset: usize,
     stride: usize,
     outlen: usize,
-) -> Result<PolyData, String> {
+) -> Result<PolyData, Error> {
     let n = poly.len();
 
     if stride == 0 {
-        return Err(String::from("stride must be greater than 0"));
+        return Err(Error::BadArgs(String::from(
+            "stride must be greater than 0",
+        )));
     }
 
     let k = n / stride;
     let k2 = k * 2;
 
     if outlen < k2 {
-        return Err(String::from("outlen must be equal or greater than k2"));
+        return Err(Error::BadArgs(String::from(
+            "outlen must be equal or greater than k2",
+        )));
     }
 
     let mut out = PolyData::new(outlen);
@@ -272,7 +291,7 @@ fn toeplitz_coeffs_stride(
     Ok(out)
 }
 
-fn toeplitz_part_1(x: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, String> {
+fn toeplitz_part_1(x: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, Error> {
     let n = x.len();
     let n2 = n * 2;
 
@@ -290,7 +309,7 @@ fn toeplitz_part_2(
     toeplitz_coeffs: &PolyData,
     x_ext_fft: &[ZG1],
     fs: &FFTSettings,
-) -> Result<Vec<ZG1>, String> {
+) -> Result<Vec<ZG1>, Error> {
     let toeplitz_coeffs_fft = fs.fft_fr(&toeplitz_coeffs.coeffs, false).unwrap();
 
     #[cfg(feature = "parallel")]
@@ -312,7 +331,7 @@ fn toeplitz_part_2(
     }
 }
 
-fn toeplitz_part_3(h_ext_fft: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, String> {
+fn toeplitz_part_3(h_ext_fft: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, Error> {
     let n = h_ext_fft.len() / 2;
     let mut out = fs.fft_g1(h_ext_fft, true).unwrap();
 
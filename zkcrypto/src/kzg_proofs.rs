@@ -1,4 +1,10 @@
 #![allow(non_camel_case_types)]
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate::consts::{G1_GENERATOR, G2_GENERATOR};
 use crate::kzg_types::{ZFp, ZFr, ZG1Affine};
 use crate::kzg_types::{ZFr as BlstFr, ZG1, ZG2};
@@ -6,10 +12,13 @@ use crate::poly::PolyData;
 use bls12_381::{
     multi_miller_loop, Fp12 as ZFp12, G1Affine, G2Affine, G2Prepared, MillerLoopResult,
 };
+use core::ops::{Add, Neg};
 use kzg::eip_4844::hash_to_bls_field;
 use kzg::msm::precompute::PrecomputationTable;
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 use kzg::{Fr as FrTrait, G1Mul, G2Mul};
-use std::ops::{Add, Neg};
+use once_cell::sync::OnceCell;
 
 #[derive(Debug, Clone)]
 pub struct FFTSettings {
@@ -20,19 +29,23 @@ pub struct FFTSettings {
     pub roots_of_unity: Vec<BlstFr>,
 }
 
-pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, String> {
+pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, Error> {
     let mut generated_powers = vec![BlstFr::one(), *root];
 
     while !(generated_powers.last().unwrap().is_one()) {
         if generated_powers.len() > width {
-            return Err(String::from("Root of unity multiplied for too long"));
+            return Err(Error::BadArgs(String::from(
+                "Root of unity multiplied for too long",
+            )));
         }
 
         generated_powers.push(generated_powers.last().unwrap().mul(root));
     }
 
     if generated_powers.len() != width + 1 {
-        return Err(String::from("Root of unity has invalid scale"));
+        return Err(Error::BadArgs(String::from(
+            "Root of unity has invalid scale",
+        )));
     }
 
     Ok(generated_powers)
@@ -44,6 +57,14 @@ pub struct KZGSettings {
     pub secret_g1: Vec<ZG1>,
     pub secret_g2: Vec<ZG2>,
     pub precomputation: Option<PrecomputationTable<ZFr, ZG1, ZFp, ZG1Affine>>,
+    /// G1 points in monomial form, used when `secret_g1` holds points in bit-reversed Lagrange form, as
+    /// loaded from a trusted setup. They are derived when settings are built. `None` means that `secret_g1`
+    /// already holds points in monomial form
+    pub secret_g1_monomial: Option<Vec<ZG1>>,
+    /// Prepared G2 generator and `secret_g2[1]`, derived on first verification
+    pub g2_prepared: OnceCell<(G2Prepared, G2Prepared)>,
+    /// Thread pool, which parallel work is run on
+    pub thread_pool: ThreadPoolConfig,
 }
 
 pub fn generate_trusted_setup(len: usize, secret: [u8; 32usize]) -> (Vec<ZG1>, Vec<ZG2>) {
@@ -105,3 +126,33 @@ pub fn pairings_verify(a1: &ZG1, a2: &ZG2, b1: &ZG1, b2: &ZG2) -> bool {
 
     ZFp12::eq(&ZFp12::one(), &new_point.0)
 }
+
+/// Same as [`pairings_verify`], but with prepared G2 points
+pub fn prepared_pairings_verify(a1: &ZG1, a2: &G2Prepared, b1: &ZG1, b2: &G2Prepared) -> bool {
+    let aa1 = G1Affine::from(a1.proj.neg());
+    let bb1 = G1Affine::from(b1.proj);
+
+    let gt_point = multi_miller_loop(&[(&aa1, a2), (&bb1, b2)]).final_exponentiation();
+
+    ZFp12::eq(&ZFp12::one(), &gt_point.0)
+}
+
+pub fn multi_pairings_verify(ps: &[ZG1], qs: &[ZG2]) -> bool {
+    if ps.len() != qs.len() {
+        return false;
+    }
+
+    let ps_affine = ps
+        .iter()
+        .map(|p| G1Affine::from(p.proj))
+        .collect::<Vec<_>>();
+    let qs_prepared = qs
+        .iter()
+        .map(|q| G2Prepared::from(G2Affine::from(q.proj)))
+        .collect::<Vec<_>>();
+    let terms = ps_affine.iter().zip(qs_prepared.iter()).collect::<Vec<_>>();
+
+    let gt_point = multi_miller_loop(&terms).final_exponentiation();
+
+    ZFp12::eq(&ZFp12::one(), &gt_point.0)
+}
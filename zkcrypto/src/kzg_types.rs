@@ -1,28 +1,47 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate::consts::{
     G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR, G2_GENERATOR, G2_NEGATIVE_GENERATOR,
     SCALE2_ROOT_OF_UNITY,
 };
 use crate::fft_g1::g1_linear_combination;
 use crate::kzg_proofs::{
-    expand_root_of_unity, pairings_verify, FFTSettings as ZFFTSettings, KZGSettings as ZKZGSettings,
+    expand_root_of_unity, multi_pairings_verify, pairings_verify, prepared_pairings_verify,
+    FFTSettings as ZFFTSettings, KZGSettings as ZKZGSettings,
 };
 use crate::poly::PolyData;
+#[cfg(feature = "blst")]
 use crate::utils::{
     blst_fr_into_pc_fr, blst_p1_into_pc_g1projective, blst_p2_into_pc_g2projective,
     pc_fr_into_blst_fr, pc_g1projective_into_blst_p1, pc_g2projective_into_blst_p2,
 };
-use bls12_381::{Fp, G1Affine, G1Projective, G2Affine, G2Projective, Scalar, MODULUS, R2};
+use bls12_381::{
+    Fp, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Scalar, MODULUS, R2,
+};
+#[cfg(feature = "blst")]
 use blst::{blst_fr, blst_p1};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 use ff::Field;
 use kzg::common_utils::reverse_bit_order;
-use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::eip_4844::{
+    eval_form_to_coeff_form, g1_lagrange_brp_to_monomial, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1,
+    BYTES_PER_G2,
+};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
+use kzg::thread_pool::ThreadPoolConfig;
+use kzg::Error;
 use kzg::G1Affine as G1AffineTrait;
 use kzg::{
     FFTFr, FFTSettings, Fr as KzgFr, G1Fp, G1GetFp, G1LinComb, G1Mul, G1ProjAddAffine, G2Mul,
-    KZGSettings, PairingVerify, Poly, Scalar256, G1, G2,
+    KZGSettings, MultiPairing, PairingVerify, Poly, Scalar256, G1, G2,
 };
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use once_cell::sync::OnceCell;
 
 use ff::derive::sbb;
 use subtle::{Choice, ConstantTimeEq, CtOption};
@@ -45,11 +64,13 @@ pub struct ZFr {
 }
 
 impl ZFr {
+    #[cfg(feature = "blst")]
     pub fn from_blst_fr(fr: blst_fr) -> Self {
         Self {
             fr: blst_fr_into_pc_fr(fr),
         }
     }
+    #[cfg(feature = "blst")]
     pub fn to_blst_fr(&self) -> blst_fr {
         pc_fr_into_blst_fr(self.fr)
     }
@@ -85,15 +106,15 @@ impl KzgFr for ZFr {
         Self { fr: rusult }
     }
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut tmp = Scalar([0, 0, 0, 0]);
@@ -117,22 +138,22 @@ impl KzgFr for ZFr {
 
                 let is_zero: bool = tmp2.is_zero().into();
                 if !is_zero && !bigint_check_mod_256(&tmp2.0) {
-                    return Err("Invalid scalar".to_string());
+                    return Err(Error::NonCanonicalFieldElement);
                 }
 
                 tmp2 *= &R2;
                 Ok(Self { fr: tmp2 })
             })
     }
-    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_FIELD_ELEMENT,
                     bytes.len()
-                )
+                ))
             })
             .map(|bytes: &[u8; BYTES_PER_FIELD_ELEMENT]| {
                 let mut tmp = Scalar([0, 0, 0, 0]);
@@ -159,7 +180,7 @@ impl KzgFr for ZFr {
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -274,9 +295,9 @@ impl KzgFr for ZFr {
         out
     }
 
-    fn div(&self, b: &Self) -> Result<Self, String> {
+    fn div(&self, b: &Self) -> Result<Self, Error> {
         if <ZFr>::is_zero(b) {
-            return Err("Cannot divide by zero".to_string());
+            return Err(Error::BadArgs("Cannot divide by zero".to_string()));
         }
         let tmp = b.eucl_inverse();
         let out = self.mul(&tmp);
@@ -341,6 +362,10 @@ impl G1Fp for ZFp {
         Self(Fp(*arr))
     }
 
+    fn to_underlying_arr(&self) -> [u64; 6] {
+        self.0 .0
+    }
+
     fn neg_assign(&mut self) {
         self.0 = self.0.neg();
     }
@@ -364,12 +389,14 @@ pub struct ZG1 {
 }
 
 impl ZG1 {
+    #[cfg(feature = "blst")]
     pub const fn from_blst_p1(p1: blst_p1) -> Self {
         Self {
             proj: blst_p1_into_pc_g1projective(&p1),
         }
     }
 
+    #[cfg(feature = "blst")]
     pub const fn to_blst_p1(&self) -> blst_p1 {
         pc_g1projective_into_blst_p1(self.proj)
     }
@@ -392,6 +419,7 @@ impl ZG1 {
     }
 }
 
+#[cfg(feature = "blst")]
 impl From<blst_p1> for ZG1 {
     fn from(p1: blst_p1) -> Self {
         let proj = blst_p1_into_pc_g1projective(&p1);
@@ -421,26 +449,28 @@ impl G1 for ZG1 {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G1,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G1]| {
                 let affine: CtOption<G1Affine> = G1Affine::from_compressed(bytes);
                 match affine.into() {
                     Some(x) => Ok(ZG1::affine_to_projective(x)),
-                    None => Err("Failed to deserialize G1: Affine not available".to_string()),
+                    None => Err(Error::InvalidPoint(
+                        "Failed to deserialize G1: Affine not available".to_string(),
+                    )),
                 }
             })
     }
 
-    fn from_hex(hex: &str) -> Result<Self, String> {
+    fn from_hex(hex: &str) -> Result<Self, Error> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
     }
@@ -670,12 +700,19 @@ impl PairingVerify<ZG1, ZG2> for ZG1 {
     }
 }
 
+impl MultiPairing<ZG1, ZG2> for ZG1 {
+    fn multi_pairing_is_one(ps: &[ZG1], qs: &[ZG2]) -> bool {
+        multi_pairings_verify(ps, qs)
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct ZG2 {
     pub proj: G2Projective,
 }
 
 impl ZG2 {
+    #[cfg(feature = "blst")]
     pub const fn from_blst_p2(p2: blst::blst_p2) -> Self {
         Self {
             proj: blst_p2_into_pc_g2projective(&p2),
@@ -684,6 +721,7 @@ impl ZG2 {
     pub const fn from_g2_projective(proj: G2Projective) -> Self {
         Self { proj }
     }
+    #[cfg(feature = "blst")]
     pub const fn to_blst_p2(&self) -> blst::blst_p2 {
         pc_g2projective_into_blst_p2(self.proj)
     }
@@ -699,15 +737,15 @@ impl G2 for ZG2 {
     }
 
     #[allow(clippy::bind_instead_of_map)]
-    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         bytes
             .try_into()
             .map_err(|_| {
-                format!(
+                Error::InvalidLength(format!(
                     "Invalid byte length. Expected {}, got {}",
                     BYTES_PER_G2,
                     bytes.len()
-                )
+                ))
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
                 let affine = G2Affine::from_compressed(bytes).unwrap();
@@ -765,11 +803,11 @@ impl Default for ZFFTSettings {
 }
 
 impl FFTSettings<ZFr> for ZFFTSettings {
-    fn new(scale: usize) -> Result<Self, String> {
+    fn new(scale: usize) -> Result<Self, Error> {
         if scale >= SCALE2_ROOT_OF_UNITY.len() {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Scale is expected to be within root of unity matrix row size",
-            ));
+            )));
         }
 
         // max_width = 2 ^ max_scale
@@ -824,24 +862,68 @@ impl FFTSettings<ZFr> for ZFFTSettings {
     }
 }
 
+impl ZKZGSettings {
+    /// Build settings from G1 points in bit-reversed Lagrange form, as loaded from a trusted setup, deriving
+    /// their monomial form. Fails with [`Error::InvalidTrustedSetup`] if it can't be derived
+    pub fn from_lagrange_brp(
+        secret_g1: Vec<ZG1>,
+        secret_g2: Vec<ZG2>,
+        fs: ZFFTSettings,
+        precomputation: Option<PrecomputationTable<ZFr, ZG1, ZFp, ZG1Affine>>,
+    ) -> Result<Self, Error> {
+        let secret_g1_monomial =
+            g1_lagrange_brp_to_monomial::<ZFr, ZG1, ZFFTSettings>(&secret_g1, &fs)?;
+
+        Ok(Self {
+            fs,
+            secret_g1,
+            secret_g2,
+            precomputation,
+            secret_g1_monomial: Some(secret_g1_monomial),
+            g2_prepared: OnceCell::new(),
+            thread_pool: ThreadPoolConfig::default(),
+        })
+    }
+}
+
 impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZGSettings {
     fn new(
         secret_g1: &[ZG1],
         secret_g2: &[ZG2],
         _length: usize,
         fft_settings: &ZFFTSettings,
-    ) -> Result<ZKZGSettings, String> {
+    ) -> Result<ZKZGSettings, Error> {
         Ok(Self {
             secret_g1: secret_g1.to_vec(),
             secret_g2: secret_g2.to_vec(),
             fs: fft_settings.clone(),
-            precomputation: precompute(secret_g1).ok().flatten(),
+            secret_g1_monomial: None,
+            g2_prepared: OnceCell::new(),
+            thread_pool: ThreadPoolConfig::default(),
+            precomputation: precompute(secret_g1).ok(),
         })
     }
 
-    fn commit_to_poly(&self, p: &PolyData) -> Result<ZG1, String> {
+    fn new_from_lagrange(
+        secret_g1: &[ZG1],
+        secret_g2: &[ZG2],
+        length: usize,
+        fft_settings: &ZFFTSettings,
+    ) -> Result<Self, Error> {
+        let settings = Self::new(secret_g1, secret_g2, length, fft_settings)?;
+        Self::from_lagrange_brp(
+            settings.secret_g1,
+            settings.secret_g2,
+            settings.fs,
+            settings.precomputation,
+        )
+    }
+
+    fn commit_to_poly(&self, p: &PolyData) -> Result<ZG1, Error> {
         if p.coeffs.len() > self.secret_g1.len() {
-            return Err(String::from("Polynomial is longer than secret g1"));
+            return Err(Error::BadArgs(String::from(
+                "Polynomial is longer than secret g1",
+            )));
         }
 
         let mut out = ZG1::default();
@@ -850,9 +932,42 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         Ok(out)
     }
 
-    fn compute_proof_single(&self, p: &PolyData, x: &ZFr) -> Result<ZG1, String> {
+    fn commit_coeff_form(&self, p: &PolyData) -> Result<ZG1, Error> {
+        if self.secret_g1_monomial.is_none() {
+            return self.commit_to_poly(p);
+        }
+
+        let g1_monomial = self.get_g1_monomial();
+        if p.coeffs.len() > g1_monomial.len() {
+            return Err(Error::BadArgs(String::from(
+                "Polynomial is longer than secret g1",
+            )));
+        }
+
+        let mut out = ZG1::default();
+        g1_linear_combination(&mut out, g1_monomial, &p.coeffs, p.coeffs.len(), None);
+
+        Ok(out)
+    }
+
+    fn commit_eval_form(&self, p: &PolyData) -> Result<ZG1, Error> {
+        if self.secret_g1_monomial.is_none() {
+            let coeffs = eval_form_to_coeff_form(&p.coeffs, &self.fs)?;
+            return self.commit_coeff_form(&PolyData { coeffs });
+        }
+
+        if p.coeffs.len() != self.secret_g1.len() {
+            return Err(Error::InvalidLength(String::from(
+                "Number of evaluations must match secret g1",
+            )));
+        }
+
+        self.commit_to_poly(p)
+    }
+
+    fn compute_proof_single(&self, p: &PolyData, x: &ZFr) -> Result<ZG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::BadArgs(String::from("Polynomial must not be empty")));
         }
 
         // `-(x0^n)`, where `n` is `1`
@@ -867,31 +982,27 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         }
 
         let q = PolyData { coeffs: out_coeffs };
-        let ret = self.commit_to_poly(&q)?;
+        let ret = self.commit_coeff_form(&q)?;
         Ok(ret)
     }
 
-    fn check_proof_single(&self, com: &ZG1, proof: &ZG1, x: &ZFr, y: &ZFr) -> Result<bool, String> {
-        let x_g2 = G2_GENERATOR.mul(x);
-        let s_minus_x: ZG2 = self.secret_g2[1].sub(&x_g2);
+    fn check_proof_single(&self, com: &ZG1, proof: &ZG1, x: &ZFr, y: &ZFr) -> Result<bool, Error> {
+        // e(C - [y], G2) == e(proof, [s - x]G2) is checked as
+        // e(proof, [s]G2) == e(C - [y] + [x]proof, G2), which keeps both G2 points fixed
         let y_g1 = G1_GENERATOR.mul(y);
-        let commitment_minus_y: ZG1 = com.sub(&y_g1);
+        let x_proof = proof.mul(x);
+        let commitment_minus_y_plus_x_proof = com.sub(&y_g1).add_or_dbl(&x_proof);
 
-        Ok(pairings_verify(
-            &commitment_minus_y,
-            &G2_GENERATOR,
-            proof,
-            &s_minus_x,
-        ))
+        Ok(self.verify_pairing_with_g2_secret(proof, &commitment_minus_y_plus_x_proof))
     }
 
-    fn compute_proof_multi(&self, p: &PolyData, x: &ZFr, n: usize) -> Result<ZG1, String> {
+    fn compute_proof_multi(&self, p: &PolyData, x: &ZFr, n: usize) -> Result<ZG1, Error> {
         if p.coeffs.is_empty() {
-            return Err(String::from("Polynomial must not be empty"));
+            return Err(Error::BadArgs(String::from("Polynomial must not be empty")));
         }
 
         if !n.is_power_of_two() {
-            return Err(String::from("n must be a power of two"));
+            return Err(Error::BadArgs(String::from("n must be a power of two")));
         }
 
         // Construct x^n - x0^n = (x - x0.w^0)(x - x0.w^1)...(x - x0.w^(n-1))
@@ -917,7 +1028,7 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         // Calculate q = p / (x^n - x0^n)
         // let q = p.div(&divisor).unwrap();
         let q = new_polina.div(&divisor)?;
-        let ret = self.commit_to_poly(&q)?;
+        let ret = self.commit_coeff_form(&q)?;
         Ok(ret)
     }
 
@@ -928,9 +1039,9 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         x: &ZFr,
         ys: &[ZFr],
         n: usize,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, Error> {
         if !n.is_power_of_two() {
-            return Err(String::from("n is not a power of two"));
+            return Err(Error::BadArgs(String::from("n is not a power of two")));
         }
 
         // Interpolate at a coset.
@@ -954,7 +1065,7 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         let xn_minus_yn = self.secret_g2[n].sub(&xn2);
 
         // [interpolation_polynomial(s)]_1
-        let is1 = self.commit_to_poly(&interp).unwrap();
+        let is1 = self.commit_coeff_form(&interp).unwrap();
 
         // [commitment - interpolation_polynomial(s)]_1 = [commit]_1 - [interpolation_polynomial(s)]_1
         let commit_minus_interp = com.sub(&is1);
@@ -979,11 +1090,45 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         &self.secret_g1
     }
 
+    fn get_g1_monomial(&self) -> &[ZG1] {
+        self.secret_g1_monomial
+            .as_deref()
+            .unwrap_or(&self.secret_g1)
+    }
+
     fn get_g2_secret(&self) -> &[ZG2] {
         &self.secret_g2
     }
 
+    fn get_field_elements_per_blob(&self) -> usize {
+        // Settings built for verification only hold no G1 points
+        if self.secret_g1.is_empty() {
+            self.fs.roots_of_unity.len()
+        } else {
+            self.secret_g1.len()
+        }
+    }
+
+    fn verify_pairing_with_g2_secret(&self, a: &ZG1, b: &ZG1) -> bool {
+        let (g2_generator, g2_secret) = self.g2_prepared.get_or_init(|| {
+            (
+                G2Prepared::from(G2Affine::from(G2_GENERATOR.proj)),
+                G2Prepared::from(G2Affine::from(self.secret_g2[1].proj)),
+            )
+        });
+
+        prepared_pairings_verify(a, g2_secret, b, g2_generator)
+    }
+
     fn get_precomputation(&self) -> Option<&PrecomputationTable<ZFr, ZG1, ZFp, ZG1Affine>> {
         self.precomputation.as_ref()
     }
+
+    fn get_thread_pool(&self) -> &ThreadPoolConfig {
+        &self.thread_pool
+    }
+
+    fn set_thread_pool(&mut self, thread_pool: ThreadPoolConfig) {
+        self.thread_pool = thread_pool;
+    }
 }
@@ -1,16 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "blst")]
 pub type Pairing = blst::Pairing;
+#[cfg(feature = "blst")]
 pub type Fp = blst::blst_fp;
+#[cfg(feature = "blst")]
 pub type Fp12 = blst::blst_fp12;
+#[cfg(feature = "blst")]
 pub type Fp6 = blst::blst_fp6;
+#[cfg(feature = "blst")]
 pub type Fr = blst::blst_fr;
+#[cfg(feature = "blst")]
 pub type P1 = blst::blst_p1;
+#[cfg(feature = "blst")]
 pub type P1Affine = blst::blst_p1_affine;
+#[cfg(feature = "blst")]
 pub type P2 = blst::blst_p2;
+#[cfg(feature = "blst")]
 pub type P2Affine = blst::blst_p2_affine;
+#[cfg(feature = "blst")]
 pub type Scalar = blst::blst_scalar;
+#[cfg(feature = "blst")]
 pub type Uniq = blst::blst_uniq;
 pub mod consts;
 pub mod das;
+#[cfg(feature = "blst")]
 pub mod eip_4844;
 pub mod fft;
 pub mod fft_g1;
@@ -20,5 +34,8 @@ pub mod kzg_types;
 mod multiscalar_mul;
 pub mod poly;
 pub mod recover;
+#[cfg(feature = "blst")]
 pub mod utils;
+#[cfg(feature = "verify-only")]
+pub mod verify;
 pub mod zero_poly;
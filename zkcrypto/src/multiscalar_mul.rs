@@ -1,7 +1,10 @@
 //! Multiscalar multiplication implementation using pippenger algorithm.
 // use dusk_bytes::Serializable;
 
-// use alloc::vec::*;
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
 
 use crate::kzg_types::{ZFr, ZG1};
 use bls12_381::{G1Projective, Scalar};
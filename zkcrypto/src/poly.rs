@@ -8,6 +8,7 @@ use crate::consts::SCALE_FACTOR;
 use crate::kzg_proofs::FFTSettings as ZFFTSettings;
 use crate::kzg_types::ZFr;
 use kzg::common_utils::{log2_pow2, log2_u64, next_pow_of_2};
+use kzg::Error;
 use kzg::{FFTFr, FFTSettings, FFTSettingsPoly, Fr, Poly};
 
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
@@ -81,15 +82,19 @@ impl Poly<ZFr> for PolyData {
     }
 
     // TODO: analyze how algo works
-    fn inverse(&mut self, output_len: usize) -> Result<Self, String> {
+    fn inverse(&mut self, output_len: usize) -> Result<Self, Error> {
         if output_len == 0 {
-            return Err(String::from("Can't produce a zero-length result"));
+            return Err(Error::BadArgs(String::from(
+                "Can't produce a zero-length result",
+            )));
         } else if self.coeffs.is_empty() {
-            return Err(String::from("Can't inverse a zero-length poly"));
+            return Err(Error::BadArgs(String::from(
+                "Can't inverse a zero-length poly",
+            )));
         } else if self.coeffs[0].is_zero() {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "First coefficient of polynomial mustn't be zero",
-            ));
+            )));
         }
 
         let mut ret = PolyData {
@@ -140,13 +145,15 @@ impl Poly<ZFr> for PolyData {
         }
 
         if d + 1 != output_len {
-            return Err(String::from("D + 1 must be equal to output_len"));
+            return Err(Error::BadArgs(String::from(
+                "D + 1 must be equal to output_len",
+            )));
         }
 
         Ok(ret)
     }
 
-    fn div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.len() >= self.len() || divisor.len() < 128 {
             // Tunable parameter
             self.long_div(divisor)
@@ -155,11 +162,13 @@ impl Poly<ZFr> for PolyData {
         }
     }
 
-    fn long_div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn long_div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.coeffs.is_empty() {
-            return Err(String::from("Can't divide by zero"));
+            return Err(Error::BadArgs(String::from("Can't divide by zero")));
         } else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-            return Err(String::from("Highest coefficient must be non-zero"));
+            return Err(Error::BadArgs(String::from(
+                "Highest coefficient must be non-zero",
+            )));
         }
 
         let out_length = self.poly_quotient_length(divisor);
@@ -211,11 +220,13 @@ impl Poly<ZFr> for PolyData {
         }
     }
 
-    fn fast_div(&mut self, divisor: &Self) -> Result<Self, String> {
+    fn fast_div(&mut self, divisor: &Self) -> Result<Self, Error> {
         if divisor.coeffs.is_empty() {
-            return Err(String::from("Cant divide by zero"));
+            return Err(Error::BadArgs(String::from("Cant divide by zero")));
         } else if divisor.coeffs[divisor.coeffs.len() - 1].is_zero() {
-            return Err(String::from("Highest coefficient must be non-zero"));
+            return Err(Error::BadArgs(String::from(
+                "Highest coefficient must be non-zero",
+            )));
         }
 
         let m: usize = self.len() - 1;
@@ -247,7 +258,7 @@ impl Poly<ZFr> for PolyData {
         Ok(out)
     }
 
-    fn mul_direct(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    fn mul_direct(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         if self.len() == 0 || multiplier.len() == 0 {
             return Ok(PolyData::new(0));
         }
@@ -281,7 +292,7 @@ impl FFTSettingsPoly<ZFr, PolyData, ZFFTSettings> for ZFFTSettings {
         b: &PolyData,
         len: usize,
         _fs: Option<&ZFFTSettings>,
-    ) -> Result<PolyData, String> {
+    ) -> Result<PolyData, Error> {
         b.mul_fft(a, len)
     }
 }
@@ -324,7 +335,7 @@ impl PolyData {
         ret
     }
 
-    pub fn flip(&self) -> Result<PolyData, String> {
+    pub fn flip(&self) -> Result<PolyData, Error> {
         let mut ret = PolyData {
             coeffs: vec![ZFr::default(); self.len()],
         };
@@ -335,7 +346,7 @@ impl PolyData {
         Ok(ret)
     }
 
-    pub fn mul_fft(&self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    pub fn mul_fft(&self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         let length = next_pow_of_2(self.len() + multiplier.len() - 1);
 
         let scale = log2_pow2(length);
@@ -393,7 +404,7 @@ impl PolyData {
         Ok(ret)
     }
 
-    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, Error> {
         if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
             // Tunable parameter
             self.mul_direct(multiplier, output_len)
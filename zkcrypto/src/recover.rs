@@ -1,8 +1,15 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate::consts::SCALE_FACTOR;
 use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::ZFr as BlstFr;
 use crate::poly::PolyData;
 
+use kzg::Error;
 use kzg::{FFTFr, Fr, Poly, PolyRecover, ZeroPoly};
 
 #[cfg(feature = "parallel")]
@@ -85,9 +92,11 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
     fn recover_poly_coeffs_from_samples(
         samples: &[Option<BlstFr>],
         fs: &FFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         if !samples.len().is_power_of_two() {
-            return Err(String::from("samples lenght has to be power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "samples lenght has to be power of 2",
+            )));
         }
 
         let mut missing = Vec::new();
@@ -99,9 +108,9 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
         }
 
         if missing.len() > samples.len() / 2 {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Impossible to recover, too many shards are missing",
-            ));
+            )));
         }
 
         // Calculate `Z_r,I`
@@ -111,7 +120,9 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
         // Check all is well
         for (i, item) in zero_eval.iter().enumerate().take(samples.len()) {
             if samples[i].is_none() != item.is_zero() {
-                return Err(String::from("sample and item are both zero"));
+                return Err(Error::BadArgs(String::from(
+                    "sample and item are both zero",
+                )));
             }
         }
 
@@ -212,7 +223,7 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
     fn recover_poly_from_samples(
         samples: &[Option<BlstFr>],
         fs: &FFTSettings,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         let reconstructed_poly = Self::recover_poly_coeffs_from_samples(samples, fs)?;
 
         // The evaluation polynomial for D(x) is the reconstructed data:
@@ -223,9 +234,9 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
         // Check all is well
         for (i, sample) in samples.iter().enumerate() {
             if !sample.is_none() && !out.get_coeff_at(i).equals(&sample.unwrap()) {
-                return Err(String::from(
+                return Err(Error::BadArgs(String::from(
                     "sample is zero and out coeff at i is not equals to sample",
-                ));
+                )));
             }
         }
         Ok(out)
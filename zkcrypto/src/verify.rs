@@ -0,0 +1,122 @@
+//! EIP-4844 proof verification for `no_std` guests, such as RISC-V zkVM programs.
+//!
+//! Verification uses only G2 points of the trusted setup and roots of unity of the blob domain, so settings built
+//! here hold neither G1 points, nor precomputation tables, nor full FFT settings.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bls12_381::{G2Affine, G2Prepared};
+use kzg::common_utils::reverse_bit_order;
+use kzg::eip_4844::{is_valid_blob_width, BYTES_PER_G2, TRUSTED_SETUP_NUM_G2_POINTS};
+use kzg::{Error, Fr, G2};
+use once_cell::sync::OnceCell;
+
+use crate::consts::{G2_GENERATOR, SCALE2_ROOT_OF_UNITY};
+use crate::kzg_proofs::{expand_root_of_unity, FFTSettings, KZGSettings};
+use crate::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
+use crate::poly::PolyData;
+
+/// Build settings, which can only verify proofs, from compressed G2 points of trusted setup. Blobs are expected to
+/// hold `field_elements_per_blob` elements.
+pub fn load_verifying_settings(
+    g2_bytes: &[u8],
+    field_elements_per_blob: usize,
+) -> Result<KZGSettings, Error> {
+    if !is_valid_blob_width(field_elements_per_blob) {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of field elements per blob",
+        )));
+    }
+
+    if g2_bytes.len() != TRUSTED_SETUP_NUM_G2_POINTS * BYTES_PER_G2 {
+        return Err(Error::InvalidTrustedSetup(String::from(
+            "Invalid number of G2 points",
+        )));
+    }
+
+    let secret_g2 = g2_bytes
+        .chunks(BYTES_PER_G2)
+        .map(ZG2::from_bytes)
+        .collect::<Result<Vec<ZG2>, Error>>()?;
+
+    let fs = roots_of_unity_settings(field_elements_per_blob)?;
+
+    // Verification would prepare these on first use, but without std it is better not to initialize lazily
+    let g2_prepared = (
+        G2Prepared::from(G2Affine::from(G2_GENERATOR.proj)),
+        G2Prepared::from(G2Affine::from(secret_g2[1].proj)),
+    );
+
+    Ok(KZGSettings {
+        fs,
+        secret_g1: Vec::new(),
+        secret_g2,
+        precomputation: None,
+        secret_g1_monomial: None,
+        g2_prepared: OnceCell::with_value(g2_prepared),
+        thread_pool: Default::default(),
+    })
+}
+
+/// FFT settings, which hold only roots of unity in bit-reversed order, as used to evaluate blobs
+fn roots_of_unity_settings(width: usize) -> Result<FFTSettings, Error> {
+    let scale = width.trailing_zeros() as usize;
+    if scale >= SCALE2_ROOT_OF_UNITY.len() {
+        return Err(Error::BadArgs(String::from(
+            "Scale is expected to be within root of unity matrix row size",
+        )));
+    }
+
+    let root_of_unity = ZFr::from_u64_arr(&SCALE2_ROOT_OF_UNITY[scale]);
+    let mut roots_of_unity = expand_root_of_unity(&root_of_unity, width)?;
+    roots_of_unity.pop();
+    reverse_bit_order(&mut roots_of_unity)?;
+
+    Ok(FFTSettings {
+        max_width: width,
+        root_of_unity,
+        expanded_roots_of_unity: Vec::new(),
+        reverse_roots_of_unity: Vec::new(),
+        roots_of_unity,
+    })
+}
+
+pub fn verify_kzg_proof_rust(
+    commitment: &ZG1,
+    z: &ZFr,
+    y: &ZFr,
+    proof: &ZG1,
+    s: &KZGSettings,
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_kzg_proof_rust::<
+        ZFr,
+        ZG1,
+        ZG2,
+        FFTSettings,
+        PolyData,
+        KZGSettings,
+        ZFp,
+        ZG1Affine,
+    >(commitment, z, y, proof, s)
+}
+
+pub fn verify_blob_kzg_proof_batch_rust(
+    blobs: &[Vec<ZFr>],
+    commitments_g1: &[ZG1],
+    proofs_g1: &[ZG1],
+    s: &KZGSettings,
+) -> Result<bool, Error> {
+    kzg::eip_4844::verify_blob_kzg_proof_batch_rust::<
+        ZFr,
+        ZG1,
+        ZG2,
+        FFTSettings,
+        PolyData,
+        KZGSettings,
+        ZFp,
+        ZG1Affine,
+    >(blobs, commitments_g1, proofs_g1, s)
+}
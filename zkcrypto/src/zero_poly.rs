@@ -1,12 +1,19 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::kzg_proofs::FFTSettings;
 use crate::kzg_types::ZFr as BlstFr;
 use crate::poly::PolyData;
 
+use core::cmp::{min, Ordering};
 use kzg::common_utils::next_pow_of_2;
+use kzg::Error;
 use kzg::{FFTFr, Fr, ZeroPoly};
-use std::cmp::{min, Ordering};
 
-pub(crate) fn pad_poly(poly: &PolyData, new_length: usize) -> Result<Vec<BlstFr>, String> {
+pub(crate) fn pad_poly(poly: &PolyData, new_length: usize) -> Result<Vec<BlstFr>, Error> {
     if new_length <= poly.coeffs.len() {
         return Ok(poly.coeffs.clone());
     }
@@ -25,10 +32,12 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         &self,
         indices: &[usize],
         stride: usize,
-    ) -> Result<PolyData, String> {
+    ) -> Result<PolyData, Error> {
         if indices.is_empty() {
             //  == 0
-            return Err(String::from("index array length mustnt be zero"));
+            return Err(Error::BadArgs(String::from(
+                "index array length mustnt be zero",
+            )));
         }
         let mut poly = PolyData {
             coeffs: vec![BlstFr::one(); indices.len() + 1],
@@ -54,14 +63,16 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         Ok(poly)
     }
     #[allow(clippy::needless_range_loop)]
-    fn reduce_partials(&self, len_out: usize, partials: &[PolyData]) -> Result<PolyData, String> {
+    fn reduce_partials(&self, len_out: usize, partials: &[PolyData]) -> Result<PolyData, Error> {
         let mut out_degree: usize = 0;
         for partial in partials {
             out_degree += partial.coeffs.len() - 1;
         }
 
         if out_degree + 1 > len_out {
-            return Err(String::from("Expected domain size to be a power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "Expected domain size to be a power of 2",
+            )));
         }
 
         let mut p_partial = pad_poly(&partials[0], len_out).unwrap();
@@ -89,7 +100,7 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         &self,
         length: usize,
         missing_indices: &[usize],
-    ) -> Result<(Vec<BlstFr>, PolyData), String> {
+    ) -> Result<(Vec<BlstFr>, PolyData), Error> {
         let zero_eval: Vec<BlstFr>;
         let mut zero_poly: PolyData;
 
@@ -100,13 +111,17 @@ impl ZeroPoly<BlstFr, PolyData> for FFTSettings {
         }
 
         if missing_indices.len() >= length {
-            return Err(String::from("Missing idxs greater than domain size"));
+            return Err(Error::BadArgs(String::from(
+                "Missing idxs greater than domain size",
+            )));
         } else if length > self.max_width {
-            return Err(String::from(
+            return Err(Error::BadArgs(String::from(
                 "Domain size greater than fft_settings.max_width",
-            ));
+            )));
         } else if !length.is_power_of_two() {
-            return Err(String::from("Domain size must be a power of 2"));
+            return Err(Error::BadArgs(String::from(
+                "Domain size must be a power of 2",
+            )));
         }
 
         let degree_of_partial = 256;
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use kzg::eip_4844::{blob_to_kzg_commitment_rust, bytes_to_blob};
+    use kzg::eip_7594::{
+        bytes_to_cell, compute_cells_and_kzg_proofs_rust, load_cell_kzg_settings_rust,
+        recover_cells_and_kzg_proofs_rust, verify_cell_kzg_proof_batch_rust,
+    };
+    use kzg_bench::tests::eip_7594::{
+        compute_recover_verify_cells_test, test_vectors_compute_cells_and_kzg_proofs,
+        test_vectors_recover_cells_and_kzg_proofs, test_vectors_verify_cell_kzg_proof_batch,
+    };
+    use rust_kzg_zkcrypto::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_zkcrypto::fk20_proofs::KzgFK20MultiSettings;
+    use rust_kzg_zkcrypto::kzg_proofs::KZGSettings;
+    use rust_kzg_zkcrypto::kzg_types::{ZFr, ZG1};
+
+    #[test]
+    pub fn compute_recover_verify_cells_test_() {
+        compute_recover_verify_cells_test::<ZFr, ZG1, KZGSettings, KzgFK20MultiSettings>(
+            &load_trusted_setup_filename_rust,
+            &load_cell_kzg_settings_rust,
+            &bytes_to_blob,
+            &blob_to_kzg_commitment_rust,
+            &compute_cells_and_kzg_proofs_rust,
+            &recover_cells_and_kzg_proofs_rust,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn test_vectors_compute_cells_and_kzg_proofs_() {
+        test_vectors_compute_cells_and_kzg_proofs::<ZFr, ZG1, KZGSettings, KzgFK20MultiSettings>(
+            &load_trusted_setup_filename_rust,
+            &load_cell_kzg_settings_rust,
+            &bytes_to_blob,
+            &compute_cells_and_kzg_proofs_rust,
+        );
+    }
+
+    #[test]
+    pub fn test_vectors_verify_cell_kzg_proof_batch_() {
+        test_vectors_verify_cell_kzg_proof_batch::<ZFr, ZG1, KZGSettings, KzgFK20MultiSettings>(
+            &load_trusted_setup_filename_rust,
+            &load_cell_kzg_settings_rust,
+            &bytes_to_cell,
+            &verify_cell_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn test_vectors_recover_cells_and_kzg_proofs_() {
+        test_vectors_recover_cells_and_kzg_proofs::<ZFr, ZG1, KZGSettings, KzgFK20MultiSettings>(
+            &load_trusted_setup_filename_rust,
+            &load_cell_kzg_settings_rust,
+            &bytes_to_cell,
+            &recover_cells_and_kzg_proofs_rust,
+        );
+    }
+}
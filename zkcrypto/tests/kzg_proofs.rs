@@ -2,6 +2,7 @@
 mod tests {
     use kzg_bench::tests::kzg_proofs::{
         commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        verify_pairing_with_g2_secret_works,
     };
     use rust_kzg_zkcrypto::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
     use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
@@ -14,6 +15,19 @@ mod tests {
         );
     }
     #[test]
+    fn verify_pairing_with_g2_secret_works_() {
+        verify_pairing_with_g2_secret_works::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ZFp,
+            ZG1Affine,
+        >(&generate_trusted_setup);
+    }
+    #[test]
     fn commit_to_nil_poly_() {
         commit_to_nil_poly::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
             &generate_trusted_setup,
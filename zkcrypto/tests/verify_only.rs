@@ -0,0 +1,57 @@
+#[cfg(all(test, feature = "verify-only"))]
+mod tests {
+    use kzg::eip_4844::{
+        blob_to_kzg_commitment_rust, bytes_to_blob, compute_blob_kzg_proof_rust,
+        compute_kzg_proof_rust, hash_to_bls_field,
+    };
+    use kzg::{Fr, G1, G2};
+    use kzg_bench::tests::eip_4844::generate_random_blob_bytes;
+    use kzg_bench::tests::utils::get_trusted_setup_path;
+    use rust_kzg_zkcrypto::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_zkcrypto::kzg_types::{ZFr, ZG1};
+    use rust_kzg_zkcrypto::verify::{
+        load_verifying_settings, verify_blob_kzg_proof_batch_rust, verify_kzg_proof_rust,
+    };
+
+    #[test]
+    pub fn verifying_settings_test() {
+        let mut rng = rand::thread_rng();
+        let ts = load_trusted_setup_filename_rust(get_trusted_setup_path().as_str()).unwrap();
+        let g2_bytes = ts
+            .secret_g2
+            .iter()
+            .flat_map(|g2| g2.to_bytes())
+            .collect::<Vec<u8>>();
+
+        let vs = load_verifying_settings(&g2_bytes, ts.secret_g1.len()).unwrap();
+        assert!(vs.secret_g1.is_empty());
+        assert!(vs.fs.expanded_roots_of_unity.is_empty());
+        assert_eq!(vs.fs.roots_of_unity, ts.fs.roots_of_unity);
+
+        let blobs = (0..3)
+            .map(|_| bytes_to_blob::<ZFr>(&generate_random_blob_bytes(&mut rng)).unwrap())
+            .collect::<Vec<_>>();
+        let commitments = blobs
+            .iter()
+            .map(|blob| blob_to_kzg_commitment_rust(blob, &ts).unwrap())
+            .collect::<Vec<ZG1>>();
+        let mut proofs = blobs
+            .iter()
+            .zip(commitments.iter())
+            .map(|(blob, commitment)| compute_blob_kzg_proof_rust(blob, commitment, &ts).unwrap())
+            .collect::<Vec<ZG1>>();
+
+        let z = hash_to_bls_field::<ZFr>(&[7; 32]);
+        let (proof, y) = compute_kzg_proof_rust(&blobs[0], &z, &ts).unwrap();
+        assert!(verify_kzg_proof_rust(&commitments[0], &z, &y, &proof, &vs).unwrap());
+        let wrong_y = y.add(&ZFr::one());
+        assert!(!verify_kzg_proof_rust(&commitments[0], &z, &wrong_y, &proof, &vs).unwrap());
+
+        assert!(verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &proofs, &vs).unwrap());
+        proofs[1] = proofs[1].add_or_dbl(&ZG1::generator());
+        assert!(!verify_blob_kzg_proof_batch_rust(&blobs, &commitments, &proofs, &vs).unwrap());
+
+        assert!(load_verifying_settings(&g2_bytes[..g2_bytes.len() - 1], 4096).is_err());
+        assert!(load_verifying_settings(&g2_bytes, 3).is_err());
+    }
+}